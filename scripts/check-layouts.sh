@@ -0,0 +1,11 @@
+#!/bin/sh
+# Checks the Layout Mirrors for 32-Bit and 64-Bit Windows
+#
+# The Assertions of `LayoutMirror` are evaluated for the Target that is built, so both Pointer Sizes have to be checked.
+# The Targets are installed with `rustup target add i686-pc-windows-msvc x86_64-pc-windows-msvc`.
+set -eu
+
+for target in i686-pc-windows-msvc x86_64-pc-windows-msvc; do
+    echo "Checking the Layouts for ${target}"
+    cargo check --workspace --all-targets --target "${target}"
+done
@@ -0,0 +1,194 @@
+//! # windings-err
+//!
+//! Decodes Win32 Error Codes, HRESULTs and NTSTATUS Codes with the embedded Catalogs of the Windings Crate.
+//!
+//! Codes can be given in Decimal, in Hex with a `0x` Prefix or as negative Numbers, like `5`, `0x887A0005` or `-2147467259`.
+//! Names like `DXGI_ERROR_DEVICE_REMOVED` are looked up in all Catalogs and their Code is decoded.
+//!
+//! As the Catalogs are embedded, this works without Windows and on every Host.
+
+#![allow(clippy::needless_return)]
+
+use std::env;
+use std::process;
+use windings::{HResult, NtStatus, Win32Error};
+use windings::hresult;
+use windings::ntstatus;
+
+/// Parses a Code in Decimal, Hex or negative Decimal
+///
+/// # Return
+///
+/// This Function returns the Bits of the Code, negative Numbers are interpreted as 32-Bit Two's Complement
+fn parse_code(argument: &str) -> Option<u32> {
+    if let Some(hex) = argument.strip_prefix("0x").or_else(|| argument.strip_prefix("0X")) {
+        return u32::from_str_radix(hex, 16).ok();
+    }
+    if argument.starts_with('-') {
+        return argument.parse::<i32>().ok().map(|value| value as u32);
+    }
+    return argument.parse::<u32>().ok();
+}
+
+/// Looks up a symbolic Name in the Catalogs of Win32 Error Codes, HRESULTs and NTSTATUS Codes
+fn lookup_name(name: &str) -> Option<u32> {
+    let name = name.to_ascii_uppercase();
+    if let Some(error) = Win32Error::from_name(&name) {
+        return Some(error.0);
+    }
+    if let Some(result) = HResult::from_name(&name) {
+        return Some(result.0 as u32);
+    }
+    return NtStatus::from_name(&name).map(|status| status.0 as u32);
+}
+
+/// Describes the Interpretation of a Code as a Win32 Error Code
+fn describe_win32(error: Win32Error) -> String {
+    return format!("Win32 error: {} (as HRESULT {:#010x})", error, HResult::from(error).0 as u32);
+}
+
+/// Describes the Interpretation of a Code as an HRESULT
+fn describe_hresult(result: HResult) -> String {
+    let severity = match result.severity() {
+        hresult::Severity::Success => "success",
+        hresult::Severity::Failure => "failure",
+    };
+    let facility = match result.facility_name() {
+        Some(name) => format!("{} ({:#x})", name, result.facility()),
+        None => format!("{:#x}", result.facility()),
+    };
+    return format!("HRESULT: {}, {}, facility {}, code {:#x}", result, severity, facility, result.code());
+}
+
+/// Describes the Interpretation of a Code as an NTSTATUS Code
+fn describe_ntstatus(status: NtStatus) -> String {
+    let severity = match status.severity() {
+        ntstatus::Severity::Success => "success",
+        ntstatus::Severity::Informational => "informational",
+        ntstatus::Severity::Warning => "warning",
+        ntstatus::Severity::Error => "error",
+    };
+    let customer = match status.is_customer() {
+        true => ", customer code",
+        false => "",
+    };
+    let win32_error = status.to_win32_error();
+    let mapped = match win32_error.name() {
+        Some(name) => format!("{} ({})", name, win32_error.0),
+        None => win32_error.0.to_string(),
+    };
+    return format!(
+        "NTSTATUS: {}, {}{}, facility {:#x}, code {:#x}, maps to {}",
+        status, severity, customer, status.facility(), status.code(), mapped
+    );
+}
+
+/// Describes the likely Interpretations of a Code
+///
+/// # Return
+///
+/// This Function returns the Lines describing the Code.
+/// Values that fit into 16 Bits are described as Win32 Error Codes.
+/// Larger Values and Values with a known Name are described as HRESULT and NTSTATUS Code.
+fn describe(code: u32) -> Vec<String> {
+    let mut lines = vec![format!("{:#010x} ({}, {})", code, code, code as i32)];
+    let small = code <= 0xFFFF;
+    let result = HResult(code as i32);
+    let status = NtStatus(code as i32);
+
+    if small {
+        lines.push(describe_win32(Win32Error(code)));
+    }
+    if !small || result.name().is_some() {
+        lines.push(describe_hresult(result));
+        if let Some(error) = result.win32_error().filter(|_| result.is_failure()) {
+            lines.push(format!("  wraps {}", describe_win32(error)));
+        }
+    }
+    if !small || status.name().is_some() {
+        lines.push(describe_ntstatus(status));
+    }
+    return lines;
+}
+
+fn main() {
+    let arguments: Vec<String> = env::args().skip(1).collect();
+    if arguments.is_empty() || arguments.iter().any(|argument| argument == "-h" || argument == "--help") {
+        eprintln!("usage: windings-err <code or name>...");
+        eprintln!();
+        eprintln!("Decodes Win32 error codes, HRESULTs and NTSTATUS codes.");
+        eprintln!("Codes can be decimal, hex with a 0x prefix or negative, like 5, 0x887A0005 or -2147467259.");
+        eprintln!("Names like DXGI_ERROR_DEVICE_REMOVED are looked up and their code is decoded.");
+        process::exit(2);
+    }
+
+    let mut failed = false;
+    for (index, argument) in arguments.iter().enumerate() {
+        let code = match parse_code(argument).or_else(|| lookup_name(argument)) {
+            Some(code) => code,
+            None => {
+                eprintln!("windings-err: `{}` is neither a code nor a known name", argument);
+                failed = true;
+                continue;
+            }
+        };
+        if index > 0 {
+            println!();
+        }
+        for line in describe(code) {
+            println!("{}", line);
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Tests for the Parsing and Description of Codes
+#[cfg(test)]
+mod test_windings_err {
+    use crate::{describe, lookup_name, parse_code};
+
+    /// Tests that Codes are parsed in Decimal, Hex and negative Decimal
+    #[test]
+    fn test_parse_code() {
+        assert_eq!(parse_code("5"), Some(5));
+        assert_eq!(parse_code("0x887A0005"), Some(0x887A0005));
+        assert_eq!(parse_code("0X887a0005"), Some(0x887A0005));
+        assert_eq!(parse_code("-2147467259"), Some(0x80004005));
+        assert_eq!(parse_code("E_FAIL"), None);
+        assert_eq!(parse_code("0x1FFFFFFFF"), None);
+    }
+
+    /// Tests that Names are looked up in all Catalogs
+    #[test]
+    fn test_lookup_name() {
+        assert_eq!(lookup_name("ERROR_ACCESS_DENIED"), Some(5));
+        assert_eq!(lookup_name("DXGI_ERROR_DEVICE_REMOVED"), Some(0x887A0005));
+        assert_eq!(lookup_name("status_stack_overflow"), Some(0xC00000FD));
+        assert_eq!(lookup_name("NOT_A_NAME"), None);
+    }
+
+    /// Tests that small Values are described as Win32 Error Codes only
+    #[test]
+    fn test_describe_win32() {
+        let lines = describe(5);
+        assert_eq!(lines, vec![
+            String::from("0x00000005 (5, 5)"),
+            String::from("Win32 error: ERROR_ACCESS_DENIED (5): Access is denied. (as HRESULT 0x80070005)"),
+        ]);
+    }
+
+    /// Tests that large Values are described as HRESULT and NTSTATUS Code
+    #[test]
+    fn test_describe_hresult() {
+        let lines = describe(0x80070005);
+        assert_eq!(lines[0], "0x80070005 (2147942405, -2147024891)");
+        assert_eq!(lines[1], "HRESULT: E_ACCESSDENIED (0x80070005): Access is denied., failure, facility FACILITY_WIN32 (0x7), code 0x5");
+        assert_eq!(lines[2], "  wraps Win32 error: ERROR_ACCESS_DENIED (5): Access is denied. (as HRESULT 0x80070005)");
+        assert_eq!(lines[3], "NTSTATUS: NTSTATUS 0x80070005, warning, facility 0x7, code 0x5, maps to ERROR_ACCESS_DENIED (5)");
+
+        let lines = describe(0xC00000FD);
+        assert_eq!(lines[2], "NTSTATUS: STATUS_STACK_OVERFLOW (0xc00000fd), error, facility 0x0, code 0xfd, maps to ERROR_STACK_OVERFLOW (1001)");
+    }
+}
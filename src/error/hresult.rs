@@ -0,0 +1,355 @@
+//! # HRESULTs
+//!
+//! The HRESULT Values returned by COM, DXGI and Windows Error Reporting Functions.
+//!
+//! An HRESULT is made up of a Severity Bit, a Facility describing the Source of the Value and a Code.
+//! HRESULTs of the `FACILITY_WIN32` Facility wrap a Win32 Error Code, which can be extracted with `HResult::win32_error`.
+//!
+//! The symbolic Names of the common `S_*`, `E_*`, `DXGI_ERROR_*` and `WER_*` Values are embedded in this Module.
+//!
+//! See the Documentation [here](https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-erref/0642cb2f-2075-4469-918c-4441e69c548a)
+
+use std::error;
+use std::fmt;
+use crate::error::win32_error::Win32Error;
+
+/// The Facility of HRESULTs wrapping a Win32 Error Code
+pub const FACILITY_WIN32: u16 = 7;
+
+/// An HRESULT Value
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HResult(pub i32);
+
+/// The Severity of an HRESULT
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Severity {
+    Success,
+    Failure,
+}
+
+/// The Names of the common Facilities, sorted by Facility
+const FACILITIES: &[(u16, &str)] = &[
+    (0, "FACILITY_NULL"),
+    (1, "FACILITY_RPC"),
+    (2, "FACILITY_DISPATCH"),
+    (3, "FACILITY_STORAGE"),
+    (4, "FACILITY_ITF"),
+    (7, "FACILITY_WIN32"),
+    (8, "FACILITY_WINDOWS"),
+    (10, "FACILITY_CONTROL"),
+    (27, "FACILITY_WINDOWS_ERROR_REPORTING"),
+    (0x87A, "FACILITY_DXGI"),
+];
+
+/// The embedded Catalog of known HRESULT Names, sorted by Value
+const CATALOG: &[(u32, &str)] = &[
+    (0x00000000, "S_OK"),
+    (0x00000001, "S_FALSE"),
+    (0x001B0000, "WER_S_REPORT_DEBUG"),
+    (0x001B0001, "WER_S_REPORT_UPLOADED"),
+    (0x001B0002, "WER_S_REPORT_QUEUED"),
+    (0x001B0003, "WER_S_DISABLED"),
+    (0x001B0004, "WER_S_SUSPENDED_UPLOAD"),
+    (0x001B0005, "WER_S_DISABLED_QUEUE"),
+    (0x001B0006, "WER_S_DISABLED_ARCHIVE"),
+    (0x001B0007, "WER_S_REPORT_ASYNC"),
+    (0x001B0008, "WER_S_IGNORE_ASSERT_INSTANCE"),
+    (0x001B0009, "WER_S_IGNORE_ALL_ASSERTS"),
+    (0x001B000A, "WER_S_ASSERT_CONTINUE"),
+    (0x001B000B, "WER_S_THROTTLED"),
+    (0x8000000A, "E_PENDING"),
+    (0x8000000B, "E_BOUNDS"),
+    (0x8000000E, "E_ILLEGAL_METHOD_CALL"),
+    (0x80004001, "E_NOTIMPL"),
+    (0x80004002, "E_NOINTERFACE"),
+    (0x80004003, "E_POINTER"),
+    (0x80004004, "E_ABORT"),
+    (0x80004005, "E_FAIL"),
+    (0x8000FFFF, "E_UNEXPECTED"),
+    (0x80070005, "E_ACCESSDENIED"),
+    (0x80070006, "E_HANDLE"),
+    (0x8007000E, "E_OUTOFMEMORY"),
+    (0x80070057, "E_INVALIDARG"),
+    (0x80070490, "WER_E_NOT_FOUND"),
+    (0x80070503, "WER_E_LENGTH_EXCEEDED"),
+    (0x8007139F, "WER_E_INVALID_STATE"),
+    (0x801B8000, "WER_E_CRASH_FAILURE"),
+    (0x801B8001, "WER_E_CANCELED"),
+    (0x801B8002, "WER_E_NETWORK_FAILURE"),
+    (0x801B8003, "WER_E_NOT_INITIALIZED"),
+    (0x801B8004, "WER_E_ALREADY_REPORTING"),
+    (0x801B8005, "WER_E_DUMP_THROTTLED"),
+    (0x887A0001, "DXGI_ERROR_INVALID_CALL"),
+    (0x887A0002, "DXGI_ERROR_NOT_FOUND"),
+    (0x887A0003, "DXGI_ERROR_MORE_DATA"),
+    (0x887A0004, "DXGI_ERROR_UNSUPPORTED"),
+    (0x887A0005, "DXGI_ERROR_DEVICE_REMOVED"),
+    (0x887A0006, "DXGI_ERROR_DEVICE_HUNG"),
+    (0x887A0007, "DXGI_ERROR_DEVICE_RESET"),
+    (0x887A000A, "DXGI_ERROR_WAS_STILL_DRAWING"),
+    (0x887A000B, "DXGI_ERROR_FRAME_STATISTICS_DISJOINT"),
+    (0x887A000C, "DXGI_ERROR_GRAPHICS_VIDPN_SOURCE_IN_USE"),
+    (0x887A0020, "DXGI_ERROR_DRIVER_INTERNAL_ERROR"),
+    (0x887A0021, "DXGI_ERROR_NONEXCLUSIVE"),
+    (0x887A0022, "DXGI_ERROR_NOT_CURRENTLY_AVAILABLE"),
+    (0x887A0023, "DXGI_ERROR_REMOTE_CLIENT_DISCONNECTED"),
+    (0x887A0024, "DXGI_ERROR_REMOTE_OUTOFMEMORY"),
+    (0x887A0025, "DXGI_ERROR_MODE_CHANGE_IN_PROGRESS"),
+    (0x887A0026, "DXGI_ERROR_ACCESS_LOST"),
+    (0x887A0027, "DXGI_ERROR_WAIT_TIMEOUT"),
+    (0x887A0028, "DXGI_ERROR_SESSION_DISCONNECTED"),
+    (0x887A0029, "DXGI_ERROR_RESTRICT_TO_OUTPUT_STALE"),
+    (0x887A002A, "DXGI_ERROR_CANNOT_PROTECT_CONTENT"),
+    (0x887A002B, "DXGI_ERROR_ACCESS_DENIED"),
+    (0x887A002C, "DXGI_ERROR_NAME_ALREADY_EXISTS"),
+    (0x887A002D, "DXGI_ERROR_SDK_COMPONENT_MISSING"),
+];
+
+impl HResult {
+    /// Returns the Value of the HRESULT
+    #[inline]
+    pub fn value(&self) -> i32 {
+        return self.0;
+    }
+
+    /// Returns whether the HRESULT indicates Success, which is the Case for all non-negative Values
+    #[inline]
+    pub fn is_success(&self) -> bool {
+        return self.0 >= 0;
+    }
+
+    /// Returns whether the HRESULT indicates Failure, which is the Case for all negative Values
+    #[inline]
+    pub fn is_failure(&self) -> bool {
+        return self.0 < 0;
+    }
+
+    /// Returns the Severity of the HRESULT, the highest Bit of its Value
+    #[inline]
+    pub fn severity(&self) -> Severity {
+        return match self.is_failure() {
+            false => Severity::Success,
+            true => Severity::Failure,
+        };
+    }
+
+    /// Returns the Facility of the HRESULT, like the HRESULT_FACILITY Macro
+    #[inline]
+    pub fn facility(&self) -> u16 {
+        return ((self.0 as u32 >> 16) & 0x1FFF) as u16;
+    }
+
+    /// Returns the Name of the Facility of the HRESULT, like `FACILITY_WIN32`
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the Facility is not one of the common Facilities
+    pub fn facility_name(&self) -> Option<&'static str> {
+        return match FACILITIES.binary_search_by_key(&self.facility(), |entry| entry.0) {
+            Ok(index) => Some(FACILITIES[index].1),
+            Err(_) => None,
+        };
+    }
+
+    /// Returns the Code of the HRESULT, like the HRESULT_CODE Macro
+    #[inline]
+    pub fn code(&self) -> u16 {
+        return (self.0 as u32 & 0xFFFF) as u16;
+    }
+
+    /// Converts a Win32 Error Code into an HRESULT, like the HRESULT_FROM_WIN32 Macro
+    ///
+    /// # Note
+    ///
+    /// Like the Macro, this keeps Values that are already HRESULTs, which is the Case for `ERROR_SUCCESS` and Values with the highest Bit set.
+    #[inline]
+    pub fn from_win32(error: Win32Error) -> HResult {
+        if error.0 as i32 <= 0 {
+            return HResult(error.0 as i32);
+        }
+        return HResult(((error.0 & 0xFFFF) | ((FACILITY_WIN32 as u32) << 16) | 0x80000000) as i32);
+    }
+
+    /// Extracts the Win32 Error Code wrapped by the HRESULT
+    ///
+    /// # Return
+    ///
+    /// This Function returns the Error Code for failing HRESULTs of the `FACILITY_WIN32` Facility and `ERROR_SUCCESS` for `S_OK`.
+    /// It returns None for all other HRESULTs.
+    #[inline]
+    pub fn win32_error(&self) -> Option<Win32Error> {
+        if self.0 == 0 {
+            return Some(Win32Error(0));
+        }
+        return match self.is_failure() && self.facility() == FACILITY_WIN32 {
+            true => Some(Win32Error(self.code() as u32)),
+            false => None,
+        };
+    }
+
+    /// Looks up an HRESULT by its symbolic Name in the embedded Catalog
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The symbolic Name, like `DXGI_ERROR_DEVICE_REMOVED`
+    pub fn from_name(name: &str) -> Option<HResult> {
+        return CATALOG.iter().find(|entry| entry.1 == name).map(|entry| HResult(entry.0 as i32));
+    }
+
+    /// Returns the symbolic Name of the HRESULT, like `E_INVALIDARG`
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the HRESULT is not part of the embedded Catalog
+    pub fn name(&self) -> Option<&'static str> {
+        return match CATALOG.binary_search_by_key(&(self.0 as u32), |entry| entry.0) {
+            Ok(index) => Some(CATALOG[index].1),
+            Err(_) => None,
+        };
+    }
+
+    /// Converts the HRESULT into a Result, so that it can be propagated with `?`
+    ///
+    /// # Return
+    ///
+    /// This Function returns `Ok(())` for succeeding HRESULTs, including Values like `S_FALSE`, and the HRESULT for failing ones.
+    #[inline]
+    pub fn ok(self) -> Result<(), HResult> {
+        return match self.is_success() {
+            true => Ok(()),
+            false => Err(self),
+        };
+    }
+}
+
+impl fmt::Display for HResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{} ({:#010x})", name, self.0 as u32)?,
+            None => write!(f, "HRESULT {:#010x}", self.0 as u32)?,
+        }
+        // HRESULTs wrapping Win32 Error Codes are described by the Catalog of the Win32 Error Codes
+        if let Some(error) = self.win32_error() {
+            if self.is_failure() {
+                if let Some(message) = error.message() {
+                    write!(f, ": {}", message)?;
+                }
+            }
+        }
+        return Ok(());
+    }
+}
+
+impl error::Error for HResult {}
+
+impl From<i32> for HResult {
+    #[inline]
+    fn from(value: i32) -> HResult {
+        return HResult(value);
+    }
+}
+
+impl From<HResult> for i32 {
+    #[inline]
+    fn from(result: HResult) -> i32 {
+        return result.0;
+    }
+}
+
+impl From<Win32Error> for HResult {
+    #[inline]
+    fn from(error: Win32Error) -> HResult {
+        return HResult::from_win32(error);
+    }
+}
+
+/// Tests for the HResult Type and its Catalog
+#[cfg(test)]
+mod test_hresult {
+    use crate::error::hresult::{CATALOG, FACILITIES, FACILITY_WIN32, HResult, Severity};
+    use crate::error::win32_error::Win32Error;
+
+    const E_ACCESSDENIED: i32 = 0x80070005u32 as i32;
+    const DXGI_ERROR_DEVICE_REMOVED: i32 = 0x887A0005u32 as i32;
+
+    /// Tests that the Catalog is sorted without Duplicates, which the Lookup relies on
+    #[test]
+    fn test_catalog_sorted() {
+        for pair in CATALOG.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{} is not sorted before {}", pair[0].1, pair[1].1);
+        }
+        for pair in FACILITIES.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{} is not sorted before {}", pair[0].1, pair[1].1);
+        }
+    }
+
+    /// Tests the Decoding of Severity, Facility and Code
+    #[test]
+    fn test_decoding() {
+        let result = HResult(DXGI_ERROR_DEVICE_REMOVED);
+        assert_eq!(result.severity(), Severity::Failure);
+        assert_eq!(result.facility(), 0x87A);
+        assert_eq!(result.code(), 5);
+        assert_eq!(result.facility_name(), Some("FACILITY_DXGI"));
+
+        let result = HResult(1);
+        assert_eq!(result.severity(), Severity::Success);
+        assert_eq!(result.facility(), 0);
+        assert_eq!(result.code(), 1);
+    }
+
+    /// Tests the Conversion between Win32 Error Codes and HRESULTs in both Directions
+    #[test]
+    fn test_win32_interop() {
+        assert_eq!(HResult::from_win32(Win32Error(5)), HResult(E_ACCESSDENIED));
+        assert_eq!(HResult::from_win32(Win32Error(0)), HResult(0));
+        assert_eq!(HResult(E_ACCESSDENIED).facility(), FACILITY_WIN32);
+        assert_eq!(HResult(E_ACCESSDENIED).win32_error(), Some(Win32Error(5)));
+        assert_eq!(HResult(0).win32_error(), Some(Win32Error(0)));
+        assert_eq!(HResult(DXGI_ERROR_DEVICE_REMOVED).win32_error(), None);
+    }
+
+    /// Tests the Names and Display of known and unknown HRESULTs
+    #[test]
+    fn test_display() {
+        assert_eq!(HResult(DXGI_ERROR_DEVICE_REMOVED).to_string(), "DXGI_ERROR_DEVICE_REMOVED (0x887a0005)");
+        assert_eq!(HResult(E_ACCESSDENIED).to_string(), "E_ACCESSDENIED (0x80070005): Access is denied.");
+        assert_eq!(HResult::from_win32(Win32Error(2)).to_string(), "HRESULT 0x80070002: The system cannot find the file specified.");
+        assert_eq!(HResult(0x801B8006u32 as i32).name(), None);
+        assert_eq!(HResult(0).name(), Some("S_OK"));
+        assert_eq!(HResult::from_name("DXGI_ERROR_DEVICE_REMOVED"), Some(HResult(DXGI_ERROR_DEVICE_REMOVED)));
+        assert_eq!(HResult::from_name("E_UNKNOWN_NAME"), None);
+    }
+
+    /// Tests that only failing HRESULTs are Errors
+    #[test]
+    fn test_ok() {
+        assert_eq!(HResult(0).ok(), Ok(()));
+        assert_eq!(HResult(1).ok(), Ok(()));
+        assert_eq!(HResult(E_ACCESSDENIED).ok(), Err(HResult(E_ACCESSDENIED)));
+    }
+}
+
+/// Tests for the Catalog on Windows
+#[cfg(all(test, windows))]
+mod test_catalog {
+    use crate::error::hresult::{CATALOG, HResult};
+
+    /// Tests that the Values match the Definitions of the winerror.h Header in WINAPI
+    #[test]
+    fn test_match_winapi() {
+        use winapi::shared::winerror::*;
+
+        let values = [
+            (S_FALSE, "S_FALSE"), (E_NOTIMPL, "E_NOTIMPL"), (E_UNEXPECTED, "E_UNEXPECTED"), (E_INVALIDARG, "E_INVALIDARG"),
+            (WER_S_THROTTLED, "WER_S_THROTTLED"), (WER_E_DUMP_THROTTLED, "WER_E_DUMP_THROTTLED"),
+            (DXGI_ERROR_SDK_COMPONENT_MISSING, "DXGI_ERROR_SDK_COMPONENT_MISSING"),
+            (HRESULT_FROM_WIN32(ERROR_NOT_FOUND), "WER_E_NOT_FOUND"),
+            (HRESULT_FROM_WIN32(ERROR_INVALID_STATE), "WER_E_INVALID_STATE"),
+        ];
+        for (value, name) in values.iter() {
+            assert!(CATALOG.iter().any(|entry| entry.0 == *value as u32 && entry.1 == *name), "{} is not {:#010x}", name, value);
+        }
+        assert_eq!(HResult::from_win32(crate::Win32Error(ERROR_ACCESS_DENIED)).0, HRESULT_FROM_WIN32(ERROR_ACCESS_DENIED));
+    }
+}
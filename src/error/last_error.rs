@@ -0,0 +1,162 @@
+//! # Last Error Store
+//!
+//! The Source of the last Error Code, that the Success Policies attach to failing Wrappers.
+//!
+//! On Windows the Store is the last Error Code of the Thread, managed by GetLastError and SetLastError of kernel32.
+//! Test Builds on other Hosts use a thread-local Emulation instead, so Tests can script the Error Code of a failing Call
+//! and check the Error a Wrapper returns, without calling the API.
+//!
+//! The active Store is chosen at Compile Time by `ActiveLastError` and used by `success::check`.
+//! `success::check_with` takes any other Store implementing `LastErrorStore`.
+
+/// A Store of the last Error Code of the calling Thread
+#[cfg(any(windows, test))]
+pub(crate) trait LastErrorStore {
+    /// Returns the last Error Code of the calling Thread
+    fn get() -> u32;
+
+    /// Sets the last Error Code of the calling Thread
+    fn set(error_code: u32);
+}
+
+/// The last Error Code of the Thread, managed by Windows
+#[cfg(windows)]
+pub(crate) struct SystemLastError;
+
+#[cfg(windows)]
+impl LastErrorStore for SystemLastError {
+    #[inline]
+    fn get() -> u32 {
+        return crate::type_wrappers::error_handling_api::GetLastError();
+    }
+
+    #[inline]
+    fn set(error_code: u32) {
+        crate::type_wrappers::error_handling_api::SetLastError(error_code);
+    }
+}
+
+#[cfg(all(test, not(windows)))]
+thread_local! {
+    static EMULATED_LAST_ERROR: std::cell::Cell<u32> = const { std::cell::Cell::new(0) };
+}
+
+/// A thread-local Emulation of the last Error Code for Test Builds on other Hosts
+#[cfg(all(test, not(windows)))]
+pub(crate) struct EmulatedLastError;
+
+#[cfg(all(test, not(windows)))]
+impl LastErrorStore for EmulatedLastError {
+    #[inline]
+    fn get() -> u32 {
+        return EMULATED_LAST_ERROR.with(|error_code| error_code.get());
+    }
+
+    #[inline]
+    fn set(error_code: u32) {
+        EMULATED_LAST_ERROR.with(|error_code_cell| error_code_cell.set(error_code));
+    }
+}
+
+/// The Store used by all Wrappers of this Build
+#[cfg(windows)]
+pub(crate) type ActiveLastError = SystemLastError;
+
+/// The Store used by all Wrappers of this Build
+#[cfg(all(test, not(windows)))]
+pub(crate) type ActiveLastError = EmulatedLastError;
+
+/// Returns the last Error Code of the calling Thread from the active Store
+#[cfg(any(windows, test))]
+#[inline]
+pub(crate) fn last_error() -> u32 {
+    return ActiveLastError::get();
+}
+
+/// Sets the last Error Code of the calling Thread in the active Store
+#[cfg(any(windows, test))]
+#[inline]
+pub(crate) fn set_last_error(error_code: u32) {
+    ActiveLastError::set(error_code);
+}
+
+/// Tests for Wrappers that fail with a scripted last Error Code
+#[cfg(test)]
+mod test_last_error {
+    use std::cell::Cell;
+    use std::thread;
+    use windings_macros::ffi_wrap;
+    use crate::error::{Error, ErrorKind, Win32Error};
+    use crate::error::last_error::{last_error, set_last_error, LastErrorStore};
+    use crate::type_wrappers::type_conversion::success::{check_with, SentinelPolicy};
+
+    const ERROR_CLASS_ALREADY_EXISTS: u32 = 1410;
+
+    /// Stands in for the FFI Function, failing like RegisterClassExW for a Class that is already registered
+    #[allow(non_snake_case)]
+    unsafe extern "system" fn RegisterClassExW(_class: *const u16) -> u16 {
+        return 0;
+    }
+
+    /// Stands in for the FFI Function, succeeding with a Class Atom
+    #[allow(non_snake_case)]
+    unsafe extern "system" fn GlobalAddAtomW(_name: *const u16) -> u16 {
+        return 0xC001;
+    }
+
+    #[ffi_wrap(RegisterClassExW, success = sentinel(0))]
+    fn register_class(class: *const u16) -> Result<u16, Error>;
+
+    #[ffi_wrap(GlobalAddAtomW, success = sentinel(0))]
+    fn add_atom(name: *const u16) -> Result<u16, Error>;
+
+    thread_local! {
+        static SCRIPTED: Cell<u32> = const { Cell::new(0) };
+    }
+
+    /// A Store other than the active one, to check that Stores can be plugged into `check_with`
+    struct ScriptedLastError;
+
+    impl LastErrorStore for ScriptedLastError {
+        fn get() -> u32 {
+            return SCRIPTED.with(|error_code| error_code.get());
+        }
+
+        fn set(error_code: u32) {
+            SCRIPTED.with(|error_code_cell| error_code_cell.set(error_code));
+        }
+    }
+
+    /// Tests that a generated Wrapper returns the scripted Error Code of a failing Call
+    #[test]
+    fn test_scripted_failure() {
+        set_last_error(ERROR_CLASS_ALREADY_EXISTS);
+        let error = register_class(std::ptr::null()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Win32(Win32Error(ERROR_CLASS_ALREADY_EXISTS)));
+        assert_eq!(error.api(), Some("RegisterClassExW"));
+        assert_eq!(error.to_string(), "RegisterClassExW failed: ERROR_CLASS_ALREADY_EXISTS (1410): Class already exists.");
+        assert_eq!(add_atom(std::ptr::null()), Ok(0xC001));
+    }
+
+    /// Tests that the Success Policies read the last Error Code from the Store they are given
+    #[test]
+    fn test_plugged_store() {
+        set_last_error(5);
+        ScriptedLastError::set(ERROR_CLASS_ALREADY_EXISTS);
+        let error = check_with::<SentinelPolicy<0>, ScriptedLastError, _>("RegisterClassExW", 0u16).unwrap_err();
+        assert_eq!(error, Error::from(Win32Error(ERROR_CLASS_ALREADY_EXISTS)).with_api("RegisterClassExW"));
+    }
+
+    /// Tests that every Thread has its own last Error Code
+    #[test]
+    fn test_thread_local() {
+        set_last_error(ERROR_CLASS_ALREADY_EXISTS);
+        let other = thread::spawn(|| {
+            set_last_error(5);
+            return last_error();
+        }).join().unwrap();
+        assert_eq!(other, 5);
+        assert_eq!(last_error(), ERROR_CLASS_ALREADY_EXISTS);
+        assert_eq!(Win32Error::from_last_error(), Win32Error(ERROR_CLASS_ALREADY_EXISTS));
+    }
+}
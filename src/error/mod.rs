@@ -0,0 +1,245 @@
+//! # Error
+//!
+//! The Error Type returned by all fallible Wrappers of this Crate.
+//!
+//! The Win32 API reports Errors in several Ways: Error Codes fetched with GetLastError, HRESULTs and NTSTATUS Codes.
+//! Wrappers check the Results of the FFI Functions with the Policies of the `success` Module, which convert every Failure into this Type.
+//! Every Error also carries the Name of the API Function that failed, so Errors can be handled generically and still be traced back.
+
+use std::error;
+use std::fmt;
+use std::io;
+use crate::type_wrappers::type_conversion::code_page::CodePageError;
+use crate::type_wrappers::type_conversion::wide_string::WideStringError;
+
+/// The Win32 Error Code Type and its embedded Catalog of Messages
+pub mod win32_error;
+
+/// The HRESULT Type and its embedded Catalog of Names
+pub mod hresult;
+
+/// The NTSTATUS Type and its embedded Catalog of Names and Win32 Error Codes
+pub mod ntstatus;
+
+/// The Store of the last Error Code, emulated in Test Builds on other Hosts
+pub(crate) mod last_error;
+
+pub use win32_error::Win32Error;
+pub use hresult::HResult;
+pub use ntstatus::NtStatus;
+
+/// The Result Type returned by all fallible Functions of this Crate
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// What went wrong in a failing Function
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A Win32 Error Code, as returned by GetLastError
+    Win32(Win32Error),
+
+    /// A failing HRESULT
+    HResult(HResult),
+
+    /// A failing NTSTATUS Code
+    NtStatus(NtStatus),
+
+    /// An FFI Function returned a Null-Pointer without reporting an Error Code
+    NullPointer,
+
+    /// An Argument was rejected, with a Description of the Reason
+    InvalidArgument(&'static str),
+
+    /// A String Argument could not be converted for the FFI Function
+    InvalidString(WideStringError),
+
+    /// A String Argument could not be encoded into, or a String Result not decoded from an ANSI Code Page
+    CodePage(CodePageError),
+}
+
+/// Errors returned by all fallible Functions of this Crate
+///
+/// Besides the Kind of the Error, the Error holds the Name of the API Function that failed, if it is known.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Error {
+    kind: ErrorKind,
+    api: Option<&'static str>,
+}
+
+impl Error {
+    /// Creates an Error that is not attributed to an API Function
+    #[inline]
+    pub fn new(kind: ErrorKind) -> Error {
+        return Error { kind, api: None };
+    }
+
+    /// Attributes the Error to the API Function that failed
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - The Name of the API Function, like `RegisterClassExW`
+    #[inline]
+    pub fn with_api(self, api: &'static str) -> Error {
+        return Error { kind: self.kind, api: Some(api) };
+    }
+
+    /// Returns what went wrong
+    #[inline]
+    pub fn kind(&self) -> ErrorKind {
+        return self.kind;
+    }
+
+    /// Returns the Name of the API Function that failed, if it is known
+    #[inline]
+    pub fn api(&self) -> Option<&'static str> {
+        return self.api;
+    }
+
+    /// Returns the Win32 Error Code that describes the Error
+    ///
+    /// # Return
+    ///
+    /// This Function returns the Error Code of Win32 Errors, the wrapped Error Code of HRESULTs of the `FACILITY_WIN32` Facility
+    /// and the Error Code NTSTATUS Codes map to.
+    /// It returns None for all other Errors.
+    pub fn win32_error(&self) -> Option<Win32Error> {
+        return match self.kind {
+            ErrorKind::Win32(error) => Some(error),
+            ErrorKind::HResult(result) => result.win32_error(),
+            ErrorKind::NtStatus(status) => Some(status.to_win32_error()),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ErrorKind::Win32(error) => write!(f, "{}", error),
+            ErrorKind::HResult(result) => write!(f, "{}", result),
+            ErrorKind::NtStatus(status) => write!(f, "{}", status),
+            ErrorKind::NullPointer => write!(f, "unexpected null pointer"),
+            ErrorKind::InvalidArgument(reason) => write!(f, "invalid argument: {}", reason),
+            ErrorKind::InvalidString(error) => write!(f, "invalid string argument: {}", error),
+            ErrorKind::CodePage(error) => write!(f, "code page conversion failed: {}", error),
+        };
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self.api {
+            Some(api) => write!(f, "{} failed: {}", api, self.kind),
+            None => write!(f, "{}", self.kind),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match &self.kind {
+            ErrorKind::Win32(error) => Some(error),
+            ErrorKind::HResult(result) => Some(result),
+            ErrorKind::NtStatus(status) => Some(status),
+            ErrorKind::InvalidString(error) => Some(error),
+            ErrorKind::CodePage(error) => Some(error),
+            ErrorKind::NullPointer | ErrorKind::InvalidArgument(_) => None,
+        };
+    }
+}
+
+impl From<ErrorKind> for Error {
+    #[inline]
+    fn from(kind: ErrorKind) -> Error {
+        return Error::new(kind);
+    }
+}
+
+impl From<Win32Error> for Error {
+    #[inline]
+    fn from(error: Win32Error) -> Error {
+        return Error::new(ErrorKind::Win32(error));
+    }
+}
+
+impl From<HResult> for Error {
+    #[inline]
+    fn from(result: HResult) -> Error {
+        return Error::new(ErrorKind::HResult(result));
+    }
+}
+
+impl From<NtStatus> for Error {
+    #[inline]
+    fn from(status: NtStatus) -> Error {
+        return Error::new(ErrorKind::NtStatus(status));
+    }
+}
+
+impl From<WideStringError> for Error {
+    #[inline]
+    fn from(error: WideStringError) -> Error {
+        return Error::new(ErrorKind::InvalidString(error));
+    }
+}
+
+impl From<CodePageError> for Error {
+    #[inline]
+    fn from(error: CodePageError) -> Error {
+        return Error::new(ErrorKind::CodePage(error));
+    }
+}
+
+impl From<Error> for io::Error {
+    /// Converts the Error into an IO Error, keeping the Error as its Source
+    ///
+    /// # Note
+    ///
+    /// The Kind of the IO Error is the one of the Win32 Error Code describing the Error, see `Error::win32_error`.
+    /// Rejected Arguments and failed Code Page Conversions are `io::ErrorKind::InvalidInput`.
+    fn from(error: Error) -> io::Error {
+        let kind = match (error.win32_error(), error.kind) {
+            (Some(win32_error), _) => win32_error.kind(),
+            (None, ErrorKind::InvalidArgument(_)) | (None, ErrorKind::InvalidString(_))
+                | (None, ErrorKind::CodePage(_)) => io::ErrorKind::InvalidInput,
+            (None, _) => io::ErrorKind::Other,
+        };
+        return io::Error::new(kind, error);
+    }
+}
+
+/// Tests for the Error Type
+#[cfg(test)]
+mod test_error {
+    use std::io;
+    use crate::error::{Error, ErrorKind, HResult, NtStatus, Win32Error};
+    use crate::type_wrappers::type_conversion::code_page::CodePageError;
+
+    /// Tests that the failing API Function is part of the Message
+    #[test]
+    fn test_display_api() {
+        let error = Error::from(Win32Error(1410)).with_api("RegisterClassExW");
+        assert_eq!(error.to_string(), "RegisterClassExW failed: ERROR_CLASS_ALREADY_EXISTS (1410): Class already exists.");
+        assert_eq!(error.api(), Some("RegisterClassExW"));
+        assert_eq!(Error::new(ErrorKind::NullPointer).to_string(), "unexpected null pointer");
+    }
+
+    /// Tests that Errors of all Sources are described by Win32 Error Codes where possible
+    #[test]
+    fn test_win32_error() {
+        assert_eq!(Error::from(Win32Error(5)).win32_error(), Some(Win32Error(5)));
+        assert_eq!(Error::from(HResult(0x80070005u32 as i32)).win32_error(), Some(Win32Error(5)));
+        assert_eq!(Error::from(NtStatus(0xC0000022u32 as i32)).win32_error(), Some(Win32Error(5)));
+        assert_eq!(Error::from(HResult(0x887A0005u32 as i32)).win32_error(), None);
+        assert_eq!(Error::new(ErrorKind::InvalidArgument("empty")).win32_error(), None);
+    }
+
+    /// Tests the Conversion into IO Errors
+    #[test]
+    fn test_io_error() {
+        let error = io::Error::from(Error::from(NtStatus(0xC0000034u32 as i32)).with_api("NtOpenFile"));
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert_eq!(error.get_ref().and_then(|source| source.source()).map(|source| source.to_string()), Some(String::from("STATUS_OBJECT_NAME_NOT_FOUND (0xc0000034)")));
+        assert_eq!(io::Error::from(Error::new(ErrorKind::InvalidArgument("empty"))).kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(io::Error::from(Error::from(CodePageError::InteriorNul { position: 2 })).kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,351 @@
+//! # NTSTATUS Codes
+//!
+//! The NTSTATUS Codes returned by Native API Functions and used as Exception Codes,
+//! like `STATUS_ACCESS_VIOLATION` and `STATUS_STACK_OVERFLOW`.
+//!
+//! An NTSTATUS Code is made up of a two Bit Severity, a Customer Bit, a Facility and a Code.
+//! The symbolic Names of the common Codes and their Win32 Error Codes are embedded in this Module,
+//! so `NtStatus::to_win32_error` works like RtlNtStatusToDosError without calling into ntdll and on every Host.
+//!
+//! See the Documentation [here](https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-erref/87fba13e-bf06-450e-83b1-9241dc81e781)
+
+use std::error;
+use std::fmt;
+use crate::error::win32_error::Win32Error;
+
+/// The Facility of NTSTATUS Codes wrapping a Win32 Error Code
+pub const FACILITY_NTWIN32: u16 = 7;
+
+/// The Win32 Error Code of NTSTATUS Codes without a Win32 Equivalent
+pub const ERROR_MR_MID_NOT_FOUND: u32 = 317;
+
+/// An NTSTATUS Code
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NtStatus(pub i32);
+
+/// The Severity of an NTSTATUS Code, the two highest Bits of its Value
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Severity {
+    Success = 0,
+    Informational = 1,
+    Warning = 2,
+    Error = 3,
+}
+
+/// The embedded Catalog of known NTSTATUS Codes, sorted by Value
+///
+/// Every Entry holds the Value, the symbolic Name and the Win32 Error Code RtlNtStatusToDosError returns for it.
+/// Exception Codes without a Win32 Equivalent map to `ERROR_MR_MID_NOT_FOUND`, like they do in RtlNtStatusToDosError.
+const CATALOG: &[(u32, &str, u32)] = &[
+    (0x00000000, "STATUS_SUCCESS", 0),
+    (0x00000103, "STATUS_PENDING", 997),
+    (0x80000001, "STATUS_GUARD_PAGE_VIOLATION", ERROR_MR_MID_NOT_FOUND),
+    (0x80000002, "STATUS_DATATYPE_MISALIGNMENT", 998),
+    (0x80000003, "STATUS_BREAKPOINT", ERROR_MR_MID_NOT_FOUND),
+    (0x80000004, "STATUS_SINGLE_STEP", ERROR_MR_MID_NOT_FOUND),
+    (0x80000005, "STATUS_BUFFER_OVERFLOW", 234),
+    (0x80000006, "STATUS_NO_MORE_FILES", 18),
+    (0xC0000002, "STATUS_NOT_IMPLEMENTED", 1),
+    (0xC0000005, "STATUS_ACCESS_VIOLATION", 998),
+    (0xC0000006, "STATUS_IN_PAGE_ERROR", 999),
+    (0xC0000008, "STATUS_INVALID_HANDLE", 6),
+    (0xC000000D, "STATUS_INVALID_PARAMETER", 87),
+    (0xC0000010, "STATUS_INVALID_DEVICE_REQUEST", 1),
+    (0xC0000011, "STATUS_END_OF_FILE", 38),
+    (0xC0000017, "STATUS_NO_MEMORY", 8),
+    (0xC000001D, "STATUS_ILLEGAL_INSTRUCTION", ERROR_MR_MID_NOT_FOUND),
+    (0xC0000022, "STATUS_ACCESS_DENIED", 5),
+    (0xC0000023, "STATUS_BUFFER_TOO_SMALL", 122),
+    (0xC0000025, "STATUS_NONCONTINUABLE_EXCEPTION", ERROR_MR_MID_NOT_FOUND),
+    (0xC0000026, "STATUS_INVALID_DISPOSITION", ERROR_MR_MID_NOT_FOUND),
+    (0xC0000034, "STATUS_OBJECT_NAME_NOT_FOUND", 2),
+    (0xC0000035, "STATUS_OBJECT_NAME_COLLISION", 183),
+    (0xC000003A, "STATUS_OBJECT_PATH_NOT_FOUND", 3),
+    (0xC0000043, "STATUS_SHARING_VIOLATION", 32),
+    (0xC0000061, "STATUS_PRIVILEGE_NOT_HELD", 1314),
+    (0xC000007F, "STATUS_DISK_FULL", 112),
+    (0xC000008C, "STATUS_ARRAY_BOUNDS_EXCEEDED", ERROR_MR_MID_NOT_FOUND),
+    (0xC000008D, "STATUS_FLOAT_DENORMAL_OPERAND", ERROR_MR_MID_NOT_FOUND),
+    (0xC000008E, "STATUS_FLOAT_DIVIDE_BY_ZERO", ERROR_MR_MID_NOT_FOUND),
+    (0xC000008F, "STATUS_FLOAT_INEXACT_RESULT", ERROR_MR_MID_NOT_FOUND),
+    (0xC0000090, "STATUS_FLOAT_INVALID_OPERATION", ERROR_MR_MID_NOT_FOUND),
+    (0xC0000091, "STATUS_FLOAT_OVERFLOW", ERROR_MR_MID_NOT_FOUND),
+    (0xC0000092, "STATUS_FLOAT_STACK_CHECK", ERROR_MR_MID_NOT_FOUND),
+    (0xC0000093, "STATUS_FLOAT_UNDERFLOW", ERROR_MR_MID_NOT_FOUND),
+    (0xC0000094, "STATUS_INTEGER_DIVIDE_BY_ZERO", ERROR_MR_MID_NOT_FOUND),
+    (0xC0000095, "STATUS_INTEGER_OVERFLOW", 534),
+    (0xC0000096, "STATUS_PRIVILEGED_INSTRUCTION", ERROR_MR_MID_NOT_FOUND),
+    (0xC000009A, "STATUS_INSUFFICIENT_RESOURCES", 1450),
+    (0xC00000B5, "STATUS_IO_TIMEOUT", 121),
+    (0xC00000BB, "STATUS_NOT_SUPPORTED", 50),
+    (0xC00000FD, "STATUS_STACK_OVERFLOW", 1001),
+    (0xC0000101, "STATUS_DIRECTORY_NOT_EMPTY", 145),
+    (0xC0000120, "STATUS_CANCELLED", 995),
+    (0xC000013A, "STATUS_CONTROL_C_EXIT", ERROR_MR_MID_NOT_FOUND),
+    (0xC000014B, "STATUS_PIPE_BROKEN", 109),
+    (0xC0000225, "STATUS_NOT_FOUND", 1168),
+    (0xC0000374, "STATUS_HEAP_CORRUPTION", ERROR_MR_MID_NOT_FOUND),
+    (0xC0000409, "STATUS_STACK_BUFFER_OVERRUN", ERROR_MR_MID_NOT_FOUND),
+    (0xC0000602, "STATUS_FAIL_FAST_EXCEPTION", ERROR_MR_MID_NOT_FOUND),
+];
+
+impl NtStatus {
+    /// Returns the Value of the NTSTATUS Code
+    #[inline]
+    pub fn value(&self) -> i32 {
+        return self.0;
+    }
+
+    /// Returns the Severity of the NTSTATUS Code
+    #[inline]
+    pub fn severity(&self) -> Severity {
+        return match (self.0 as u32) >> 30 {
+            0 => Severity::Success,
+            1 => Severity::Informational,
+            2 => Severity::Warning,
+            _ => Severity::Error,
+        };
+    }
+
+    /// Returns whether the NTSTATUS Code indicates Success, which is the Case for Success and Informational Codes, like the NT_SUCCESS Macro
+    #[inline]
+    pub fn is_success(&self) -> bool {
+        return self.0 >= 0;
+    }
+
+    /// Returns whether the Customer Bit is set, which marks Codes defined by Applications instead of Microsoft
+    #[inline]
+    pub fn is_customer(&self) -> bool {
+        return (self.0 as u32) & 0x20000000 != 0;
+    }
+
+    /// Returns the Facility of the NTSTATUS Code
+    #[inline]
+    pub fn facility(&self) -> u16 {
+        return (((self.0 as u32) >> 16) & 0x0FFF) as u16;
+    }
+
+    /// Returns the Code of the NTSTATUS Code
+    #[inline]
+    pub fn code(&self) -> u16 {
+        return ((self.0 as u32) & 0xFFFF) as u16;
+    }
+
+    /// Looks up the Entry of the NTSTATUS Code in the embedded Catalog
+    fn entry(&self) -> Option<&'static (u32, &'static str, u32)> {
+        return match CATALOG.binary_search_by_key(&(self.0 as u32), |entry| entry.0) {
+            Ok(index) => Some(&CATALOG[index]),
+            Err(_) => None,
+        };
+    }
+
+    /// Looks up an NTSTATUS Code by its symbolic Name in the embedded Catalog
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The symbolic Name, like `STATUS_ACCESS_VIOLATION`
+    pub fn from_name(name: &str) -> Option<NtStatus> {
+        return CATALOG.iter().find(|entry| entry.1 == name).map(|entry| NtStatus(entry.0 as i32));
+    }
+
+    /// Returns the symbolic Name of the NTSTATUS Code, like `STATUS_ACCESS_VIOLATION`
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the NTSTATUS Code is not part of the embedded Catalog
+    #[inline]
+    pub fn name(&self) -> Option<&'static str> {
+        return self.entry().map(|entry| entry.1);
+    }
+
+    /// Converts the NTSTATUS Code into a Win32 Error Code, like the RtlNtStatusToDosError Function
+    ///
+    /// # Return
+    ///
+    /// This Function returns Codes with the Customer Bit unchanged,
+    /// the wrapped Error Code for Error Codes of the `FACILITY_NTWIN32` Facility and for Win32 Error Codes wrapped into HRESULTs,
+    /// the Error Code of the embedded Catalog for known Codes
+    /// and `ERROR_MR_MID_NOT_FOUND` for all other Codes.
+    ///
+    /// # Note
+    ///
+    /// The Catalog only contains the common Codes, RtlNtStatusToDosError knows a few more
+    pub fn to_win32_error(&self) -> Win32Error {
+        if self.is_customer() {
+            return Win32Error(self.0 as u32);
+        }
+        // RtlNtStatusToDosError also unwraps HRESULT_FROM_WIN32 Codes, which have the Warning Severity as NTSTATUS Codes
+        if (self.severity() == Severity::Error || self.severity() == Severity::Warning) && self.facility() == FACILITY_NTWIN32 {
+            return Win32Error(self.code() as u32);
+        }
+        return match self.entry() {
+            Some(entry) => Win32Error(entry.2),
+            None => Win32Error(ERROR_MR_MID_NOT_FOUND),
+        };
+    }
+}
+
+impl fmt::Display for NtStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self.name() {
+            Some(name) => write!(f, "{} ({:#010x})", name, self.0 as u32),
+            None => write!(f, "NTSTATUS {:#010x}", self.0 as u32),
+        };
+    }
+}
+
+impl error::Error for NtStatus {}
+
+impl From<i32> for NtStatus {
+    #[inline]
+    fn from(value: i32) -> NtStatus {
+        return NtStatus(value);
+    }
+}
+
+/// Exception Codes are passed as unsigned Values, like the ExceptionCode of EXCEPTION_RECORD
+impl From<u32> for NtStatus {
+    #[inline]
+    fn from(value: u32) -> NtStatus {
+        return NtStatus(value as i32);
+    }
+}
+
+impl From<NtStatus> for i32 {
+    #[inline]
+    fn from(status: NtStatus) -> i32 {
+        return status.0;
+    }
+}
+
+impl From<NtStatus> for u32 {
+    #[inline]
+    fn from(status: NtStatus) -> u32 {
+        return status.0 as u32;
+    }
+}
+
+impl From<NtStatus> for Win32Error {
+    #[inline]
+    fn from(status: NtStatus) -> Win32Error {
+        return status.to_win32_error();
+    }
+}
+
+/// Tests for the NtStatus Type and its Catalog
+#[cfg(test)]
+mod test_ntstatus {
+    use crate::error::ntstatus::{CATALOG, ERROR_MR_MID_NOT_FOUND, NtStatus, Severity};
+    use crate::error::win32_error::Win32Error;
+
+    const STATUS_ACCESS_VIOLATION: u32 = 0xC0000005;
+
+    /// Tests that the Catalog is sorted without Duplicates, which the Lookup relies on
+    #[test]
+    fn test_catalog_sorted() {
+        for pair in CATALOG.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{} is not sorted before {}", pair[0].1, pair[1].1);
+        }
+    }
+
+    /// Tests that every Win32 Error Code of the Catalog is part of the Win32 Error Catalog, so that mapped Codes can be displayed
+    #[test]
+    fn test_catalog_mapped_to_known() {
+        for (_, name, error) in CATALOG {
+            assert!(Win32Error(*error).name().is_some(), "{} maps to the unknown error {}", name, error);
+        }
+    }
+
+    /// Tests the Decoding of Severity, Customer Bit, Facility and Code
+    #[test]
+    fn test_decoding() {
+        let status = NtStatus::from(STATUS_ACCESS_VIOLATION);
+        assert_eq!(status.severity(), Severity::Error);
+        assert!(!status.is_success());
+        assert!(!status.is_customer());
+        assert_eq!(status.facility(), 0);
+        assert_eq!(status.code(), 5);
+
+        let status = NtStatus::from(0xE0434F4Du32);
+        assert_eq!(status.severity(), Severity::Error);
+        assert!(status.is_customer());
+        assert_eq!(status.facility(), 0x043);
+        assert_eq!(status.code(), 0x4F4D);
+
+        assert_eq!(NtStatus(0x40000000).severity(), Severity::Informational);
+        assert!(NtStatus(0x40000000).is_success());
+        assert_eq!(NtStatus::from(0x80000003u32).severity(), Severity::Warning);
+    }
+
+    /// Tests the Names and Display of known and unknown Codes
+    #[test]
+    fn test_display() {
+        assert_eq!(NtStatus::from(STATUS_ACCESS_VIOLATION).to_string(), "STATUS_ACCESS_VIOLATION (0xc0000005)");
+        assert_eq!(NtStatus::from(0xC00000FDu32).name(), Some("STATUS_STACK_OVERFLOW"));
+        assert_eq!(NtStatus::from(0xE0434F4Du32).to_string(), "NTSTATUS 0xe0434f4d");
+        assert_eq!(NtStatus::from_name("STATUS_ACCESS_VIOLATION"), Some(NtStatus::from(STATUS_ACCESS_VIOLATION)));
+        assert_eq!(NtStatus::from_name("STATUS_UNKNOWN_NAME"), None);
+    }
+
+    /// Tests the Conversion into Win32 Error Codes
+    #[test]
+    fn test_to_win32_error() {
+        assert_eq!(NtStatus(0).to_win32_error(), Win32Error(0));
+        assert_eq!(NtStatus::from(STATUS_ACCESS_VIOLATION).to_win32_error(), Win32Error(998));
+        assert_eq!(NtStatus::from(0xC0000034u32).to_win32_error(), Win32Error(2));
+        assert_eq!(NtStatus::from(0xC0000094u32).to_win32_error(), Win32Error(ERROR_MR_MID_NOT_FOUND));
+        // FACILITY_NTWIN32 wraps Win32 Error Codes
+        assert_eq!(NtStatus::from(0xC0070490u32).to_win32_error(), Win32Error(1168));
+    }
+
+    /// Tests that Customer Codes are kept and Win32 Error Codes wrapped into HRESULTs are unwrapped, like RtlNtStatusToDosError does
+    #[test]
+    fn test_to_win32_error_passthrough() {
+        assert_eq!(NtStatus::from(0xE0434F4Du32).to_win32_error(), Win32Error(0xE0434F4D));
+        assert_eq!(NtStatus::from(0xE0525354u32).to_win32_error(), Win32Error(0xE0525354));
+        assert_eq!(NtStatus::from(0x20000001u32).to_win32_error(), Win32Error(0x20000001));
+        assert_eq!(NtStatus::from(0x80070005u32).to_win32_error(), Win32Error(5));
+        assert_eq!(NtStatus::from(0x8007057Eu32).to_win32_error(), Win32Error(1406));
+    }
+}
+
+/// Tests for the Catalog on Windows
+#[cfg(all(test, windows))]
+mod test_catalog {
+    use crate::error::ntstatus::{CATALOG, NtStatus};
+
+    #[link(name = "ntdll")]
+    extern "system" {
+        fn RtlNtStatusToDosError(status: i32) -> u32;
+    }
+
+    /// Tests that the Win32 Error Codes of the Catalog match the ones of RtlNtStatusToDosError
+    #[test]
+    fn test_match_rtl_nt_status_to_dos_error() {
+        for (value, name, _) in CATALOG {
+            let status = NtStatus::from(*value);
+            let expected = unsafe { RtlNtStatusToDosError(status.0) };
+            assert_eq!(status.to_win32_error().0, expected, "{}", name);
+        }
+        for value in [0xE0525354u32, 0x80070005, 0xC0070490].iter() {
+            let status = NtStatus::from(*value);
+            let expected = unsafe { RtlNtStatusToDosError(status.0) };
+            assert_eq!(status.to_win32_error().0, expected, "{:#010x}", value);
+        }
+    }
+
+    /// Tests that the Values match the Definitions of the ntstatus.h Header in WINAPI
+    #[test]
+    fn test_match_winapi() {
+        use winapi::shared::ntstatus::*;
+
+        let values = [
+            (STATUS_ACCESS_VIOLATION, "STATUS_ACCESS_VIOLATION"), (STATUS_STACK_OVERFLOW, "STATUS_STACK_OVERFLOW"),
+            (STATUS_BREAKPOINT, "STATUS_BREAKPOINT"), (STATUS_HEAP_CORRUPTION, "STATUS_HEAP_CORRUPTION"),
+            (STATUS_FAIL_FAST_EXCEPTION, "STATUS_FAIL_FAST_EXCEPTION"), (STATUS_NOT_FOUND, "STATUS_NOT_FOUND"),
+        ];
+        for (value, name) in values.iter() {
+            assert!(CATALOG.iter().any(|entry| entry.0 == *value as u32 && entry.1 == *name), "{} is not {:#010x}", name, value);
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! # Win32 Error Codes
+//!
+//! The Error Codes of the winerror.h Header, as returned by GetLastError.
+//!
+//! The symbolic Names and English Messages of the common Error Codes are embedded in this Module,
+//! so Error Codes can be displayed without calling FormatMessage and on every Host.
+//! The Messages are the ones FormatMessage returns for the English Language.
+//!
+//! See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/debug/system-error-codes)
+
+use std::error;
+use std::fmt;
+use std::io;
+
+/// A Win32 Error Code
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Win32Error(pub u32);
+
+/// The embedded Catalog of Error Codes, sorted by Code
+const CATALOG: &[(u32, &str, &str)] = &[
+    (0, "ERROR_SUCCESS", "The operation completed successfully."),
+    (1, "ERROR_INVALID_FUNCTION", "Incorrect function."),
+    (2, "ERROR_FILE_NOT_FOUND", "The system cannot find the file specified."),
+    (3, "ERROR_PATH_NOT_FOUND", "The system cannot find the path specified."),
+    (4, "ERROR_TOO_MANY_OPEN_FILES", "The system cannot open the file."),
+    (5, "ERROR_ACCESS_DENIED", "Access is denied."),
+    (6, "ERROR_INVALID_HANDLE", "The handle is invalid."),
+    (8, "ERROR_NOT_ENOUGH_MEMORY", "Not enough memory resources are available to process this command."),
+    (12, "ERROR_INVALID_ACCESS", "The access code is invalid."),
+    (13, "ERROR_INVALID_DATA", "The data is invalid."),
+    (14, "ERROR_OUTOFMEMORY", "Not enough memory resources are available to complete this operation."),
+    (15, "ERROR_INVALID_DRIVE", "The system cannot find the drive specified."),
+    (17, "ERROR_NOT_SAME_DEVICE", "The system cannot move the file to a different disk drive."),
+    (18, "ERROR_NO_MORE_FILES", "There are no more files."),
+    (19, "ERROR_WRITE_PROTECT", "The media is write protected."),
+    (21, "ERROR_NOT_READY", "The device is not ready."),
+    (24, "ERROR_BAD_LENGTH", "The program issued a command but the command length is incorrect."),
+    (32, "ERROR_SHARING_VIOLATION", "The process cannot access the file because it is being used by another process."),
+    (33, "ERROR_LOCK_VIOLATION", "The process cannot access the file because another process has locked a portion of the file."),
+    (38, "ERROR_HANDLE_EOF", "Reached the end of the file."),
+    (50, "ERROR_NOT_SUPPORTED", "The request is not supported."),
+    (53, "ERROR_BAD_NETPATH", "The network path was not found."),
+    (80, "ERROR_FILE_EXISTS", "The file exists."),
+    (87, "ERROR_INVALID_PARAMETER", "The parameter is incorrect."),
+    (109, "ERROR_BROKEN_PIPE", "The pipe has been ended."),
+    (111, "ERROR_BUFFER_OVERFLOW", "The file name is too long."),
+    (112, "ERROR_DISK_FULL", "There is not enough space on the disk."),
+    (120, "ERROR_CALL_NOT_IMPLEMENTED", "This function is not supported on this system."),
+    (121, "ERROR_SEM_TIMEOUT", "The semaphore timeout period has expired."),
+    (122, "ERROR_INSUFFICIENT_BUFFER", "The data area passed to a system call is too small."),
+    (123, "ERROR_INVALID_NAME", "The filename, directory name, or volume label syntax is incorrect."),
+    (126, "ERROR_MOD_NOT_FOUND", "The specified module could not be found."),
+    (127, "ERROR_PROC_NOT_FOUND", "The specified procedure could not be found."),
+    (145, "ERROR_DIR_NOT_EMPTY", "The directory is not empty."),
+    (161, "ERROR_BAD_PATHNAME", "The specified path is invalid."),
+    (170, "ERROR_BUSY", "The requested resource is in use."),
+    (183, "ERROR_ALREADY_EXISTS", "Cannot create a file when that file already exists."),
+    (203, "ERROR_ENVVAR_NOT_FOUND", "The system could not find the environment option that was entered."),
+    (206, "ERROR_FILENAME_EXCED_RANGE", "The filename or extension is too long."),
+    (232, "ERROR_NO_DATA", "The pipe is being closed."),
+    (234, "ERROR_MORE_DATA", "More data is available."),
+    (258, "WAIT_TIMEOUT", "The wait operation timed out."),
+    (259, "ERROR_NO_MORE_ITEMS", "No more data is available."),
+    (267, "ERROR_DIRECTORY", "The directory name is invalid."),
+    (288, "ERROR_NOT_OWNER", "Attempt to release mutex not owned by caller."),
+    (317, "ERROR_MR_MID_NOT_FOUND", "The system cannot find message text for message number 0x%1 in the message file for %2."),
+    (487, "ERROR_INVALID_ADDRESS", "Attempt to access invalid address."),
+    (534, "ERROR_ARITHMETIC_OVERFLOW", "Arithmetic result exceeded 32 bits."),
+    (535, "ERROR_PIPE_CONNECTED", "There is a process on other end of the pipe."),
+    (740, "ERROR_ELEVATION_REQUIRED", "The requested operation requires elevation."),
+    (995, "ERROR_OPERATION_ABORTED", "The I/O operation has been aborted because of either a thread exit or an application request."),
+    (996, "ERROR_IO_INCOMPLETE", "Overlapped I/O event is not in a signaled state."),
+    (997, "ERROR_IO_PENDING", "Overlapped I/O operation is in progress."),
+    (998, "ERROR_NOACCESS", "Invalid access to memory location."),
+    (999, "ERROR_SWAPERROR", "Error performing inpage operation."),
+    (1001, "ERROR_STACK_OVERFLOW", "Recursion too deep; the stack overflowed."),
+    (1004, "ERROR_INVALID_FLAGS", "Invalid flags."),
+    (1113, "ERROR_NO_UNICODE_TRANSLATION", "No mapping for the Unicode character exists in the target multi-byte code page."),
+    (1168, "ERROR_NOT_FOUND", "Element not found."),
+    (1223, "ERROR_CANCELLED", "The operation was canceled by the user."),
+    (1247, "ERROR_ALREADY_INITIALIZED", "An attempt was made to perform an initialization operation when initialization has already been completed."),
+    (1314, "ERROR_PRIVILEGE_NOT_HELD", "A required privilege is not held by the client."),
+    (1400, "ERROR_INVALID_WINDOW_HANDLE", "Invalid window handle."),
+    (1407, "ERROR_CANNOT_FIND_WND_CLASS", "Cannot find window class."),
+    (1410, "ERROR_CLASS_ALREADY_EXISTS", "Class already exists."),
+    (1411, "ERROR_CLASS_DOES_NOT_EXIST", "Class does not exist."),
+    (1412, "ERROR_CLASS_HAS_WINDOWS", "Class still has open windows."),
+    (1413, "ERROR_INVALID_INDEX", "Invalid index."),
+    (1450, "ERROR_NO_SYSTEM_RESOURCES", "Insufficient system resources exist to complete the requested service."),
+    (1455, "ERROR_COMMITMENT_LIMIT", "The paging file is too small for this operation to complete."),
+    (1460, "ERROR_TIMEOUT", "This operation returned because the timeout period expired."),
+    (1784, "ERROR_INVALID_USER_BUFFER", "The supplied user buffer is not valid for the requested operation."),
+    (1813, "ERROR_RESOURCE_TYPE_NOT_FOUND", "The specified resource type cannot be found in the image file."),
+    (1814, "ERROR_RESOURCE_NAME_NOT_FOUND", "The specified resource name cannot be found in the image file."),
+    (5023, "ERROR_INVALID_STATE", "The group or resource is not in the correct state to perform the requested operation."),
+];
+
+impl Win32Error {
+    /// Returns the calling Threads last Error Code as a Win32Error
+    ///
+    /// # Note
+    ///
+    /// This has to be called directly after the failing FFI Function, before anything else can overwrite the last Error Code
+    #[cfg(any(windows, test))]
+    #[inline]
+    pub fn from_last_error() -> Win32Error {
+        return Win32Error(crate::error::last_error::last_error());
+    }
+
+    /// Returns the Error Code
+    #[inline]
+    pub fn code(&self) -> u32 {
+        return self.0;
+    }
+
+    /// Looks up the Entry of the Error Code in the embedded Catalog
+    fn entry(&self) -> Option<&'static (u32, &'static str, &'static str)> {
+        return match CATALOG.binary_search_by_key(&self.0, |entry| entry.0) {
+            Ok(index) => Some(&CATALOG[index]),
+            Err(_) => None,
+        };
+    }
+
+    /// Looks up an Error Code by its symbolic Name in the embedded Catalog
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The symbolic Name, like `ERROR_ACCESS_DENIED`
+    pub fn from_name(name: &str) -> Option<Win32Error> {
+        return CATALOG.iter().find(|entry| entry.1 == name).map(|entry| Win32Error(entry.0));
+    }
+
+    /// Returns the symbolic Name of the Error Code, like `ERROR_ACCESS_DENIED`
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the Error Code is not part of the embedded Catalog
+    #[inline]
+    pub fn name(&self) -> Option<&'static str> {
+        return self.entry().map(|entry| entry.1);
+    }
+
+    /// Returns the English Message of the Error Code, like `Access is denied.`
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the Error Code is not part of the embedded Catalog
+    #[inline]
+    pub fn message(&self) -> Option<&'static str> {
+        return self.entry().map(|entry| entry.2);
+    }
+
+    /// Returns the Kind of IO Error the Error Code corresponds to
+    ///
+    /// # Note
+    ///
+    /// The Mapping follows the one the Standard Library uses for OS Errors on Windows.
+    /// Error Codes without a corresponding Kind map to `io::ErrorKind::Other`.
+    pub fn kind(&self) -> io::ErrorKind {
+        return match self.0 {
+            2 | 3 => io::ErrorKind::NotFound,
+            5 | 1314 => io::ErrorKind::PermissionDenied,
+            8 | 14 => io::ErrorKind::OutOfMemory,
+            13 => io::ErrorKind::InvalidData,
+            38 => io::ErrorKind::UnexpectedEof,
+            50 | 120 => io::ErrorKind::Unsupported,
+            80 | 183 => io::ErrorKind::AlreadyExists,
+            87 => io::ErrorKind::InvalidInput,
+            109 | 232 => io::ErrorKind::BrokenPipe,
+            112 => io::ErrorKind::StorageFull,
+            123 | 161 | 206 => io::ErrorKind::InvalidFilename,
+            145 => io::ErrorKind::DirectoryNotEmpty,
+            170 => io::ErrorKind::ResourceBusy,
+            121 | 258 | 995 | 1460 => io::ErrorKind::TimedOut,
+            _ => io::ErrorKind::Other,
+        };
+    }
+}
+
+impl fmt::Display for Win32Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self.entry() {
+            Some((_, name, message)) => write!(f, "{} ({}): {}", name, self.0, message),
+            None => write!(f, "win32 error {} ({:#010x})", self.0, self.0),
+        };
+    }
+}
+
+impl error::Error for Win32Error {}
+
+impl From<u32> for Win32Error {
+    #[inline]
+    fn from(code: u32) -> Win32Error {
+        return Win32Error(code);
+    }
+}
+
+impl From<Win32Error> for u32 {
+    #[inline]
+    fn from(error: Win32Error) -> u32 {
+        return error.0;
+    }
+}
+
+impl From<Win32Error> for io::Error {
+    /// Converts the Error Code into an IO Error
+    ///
+    /// # Note
+    ///
+    /// On Windows the IO Error is a raw OS Error, so `raw_os_error` returns the Error Code.
+    /// On other Hosts the Error Code would be mistaken for an errno Value, so the Win32Error is wrapped instead.
+    #[inline]
+    fn from(error: Win32Error) -> io::Error {
+        #[cfg(windows)]
+        return io::Error::from_raw_os_error(error.0 as i32);
+        #[cfg(not(windows))]
+        return io::Error::new(error.kind(), error);
+    }
+}
+
+/// Tests for the Win32Error Type and its Catalog
+#[cfg(test)]
+mod test_win32_error {
+    use std::io;
+    use crate::error::win32_error::{CATALOG, Win32Error};
+
+    /// Tests that the Catalog is sorted without Duplicates, which the Lookup relies on
+    #[test]
+    fn test_catalog_sorted() {
+        for pair in CATALOG.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{} is not sorted before {}", pair[0].1, pair[1].1);
+        }
+    }
+
+    /// Tests that known Error Codes are displayed with their Name and Message
+    #[test]
+    fn test_display_known() {
+        assert_eq!(Win32Error(5).to_string(), "ERROR_ACCESS_DENIED (5): Access is denied.");
+        assert_eq!(Win32Error(1410).name(), Some("ERROR_CLASS_ALREADY_EXISTS"));
+        assert_eq!(Win32Error(87).message(), Some("The parameter is incorrect."));
+        assert_eq!(Win32Error::from_name("ERROR_CLASS_ALREADY_EXISTS"), Some(Win32Error(1410)));
+        assert_eq!(Win32Error::from_name("ERROR_UNKNOWN_NAME"), None);
+    }
+
+    /// Tests that unknown Error Codes are displayed with their Value
+    #[test]
+    fn test_display_unknown() {
+        assert_eq!(Win32Error(0xDEAD).to_string(), "win32 error 57005 (0x0000dead)");
+        assert_eq!(Win32Error(0xDEAD).name(), None);
+        assert_eq!(Win32Error(0xDEAD).message(), None);
+    }
+
+    /// Tests the Mapping to IO Error Kinds
+    #[test]
+    fn test_io_error_kind() {
+        assert_eq!(Win32Error(2).kind(), io::ErrorKind::NotFound);
+        assert_eq!(Win32Error(5).kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(Win32Error(183).kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(Win32Error(1410).kind(), io::ErrorKind::Other);
+        assert_eq!(io::Error::from(Win32Error(87)).kind(), io::ErrorKind::InvalidInput);
+    }
+}
+
+/// Tests for the Catalog on Windows
+#[cfg(all(test, windows))]
+mod test_catalog {
+    use crate::error::win32_error::CATALOG;
+
+    /// Tests that the Codes match the Definitions of the winerror.h Header in WINAPI
+    #[test]
+    fn test_match_winapi() {
+        use winapi::shared::winerror::*;
+
+        let codes = [
+            ERROR_ACCESS_DENIED, ERROR_INVALID_PARAMETER, ERROR_ALREADY_EXISTS, ERROR_CLASS_ALREADY_EXISTS,
+            ERROR_OPERATION_ABORTED, ERROR_NOT_FOUND, WAIT_TIMEOUT, ERROR_INVALID_STATE,
+        ];
+        let names = [
+            "ERROR_ACCESS_DENIED", "ERROR_INVALID_PARAMETER", "ERROR_ALREADY_EXISTS", "ERROR_CLASS_ALREADY_EXISTS",
+            "ERROR_OPERATION_ABORTED", "ERROR_NOT_FOUND", "WAIT_TIMEOUT", "ERROR_INVALID_STATE",
+        ];
+        for (code, name) in codes.iter().zip(names.iter()) {
+            assert!(CATALOG.iter().any(|entry| entry.0 == *code && entry.1 == *name), "{} is not {}", name, code);
+        }
+    }
+
+    /// Tests that the Messages match the ones of FormatMessage for the English Language
+    #[test]
+    fn test_match_format_message() {
+        use winapi::um::winbase::{FormatMessageW, FORMAT_MESSAGE_FROM_SYSTEM, FORMAT_MESSAGE_IGNORE_INSERTS};
+
+        for (code, name, message) in CATALOG {
+            let mut buffer = [0u16; 512];
+            let length = unsafe {
+                FormatMessageW(
+                    FORMAT_MESSAGE_FROM_SYSTEM | FORMAT_MESSAGE_IGNORE_INSERTS,
+                    std::ptr::null(), *code, 0x0409, buffer.as_mut_ptr(), buffer.len() as u32, std::ptr::null_mut()
+                )
+            };
+            // Skip Hosts without the English Messages
+            if length == 0 {
+                continue;
+            }
+            let system_message = String::from_utf16_lossy(&buffer[..length as usize]);
+            assert_eq!(system_message.trim_end(), *message, "{}", name);
+        }
+    }
+}
@@ -12,7 +12,16 @@
 //!
 //! As a final note, due to how things like FFI Callbacks may be wrapped, it may be necessary to use the Win32 API in ways that, if modified by an external library may lead to UB or worse.
 //! This shall be indicated as well as avoided at all costs.
+//!
+//! ## Building on other Hosts
+//!
+//! The Types of this Crate, like String Conversions, Enums, Bitflags and Error Types, build and can be tested on every Host.
+//! Only the Wrappers of the Win32 API Functions and the Types that can only be used with them require Windows and are behind `cfg(windows)`.
+
+#![allow(clippy::needless_return)]
 
+// Allows the Code generated by the windings-macros Crate to refer to this Crate as ::windings from inside of it
+extern crate self as windings;
 
 /// Wrapper Functions on top of WINAPI that convert Rust Types into FFI Types
 #[macro_use]
@@ -21,8 +30,30 @@ mod type_wrappers;
 /// The Actual Safe Wrappers for the Win32 API on top of the Type Wrappers
 mod safe_wrappers;
 
+/// The Error Type returned by fallible Wrappers
+mod error;
+
+pub use error::{Error, ErrorKind, HResult, NtStatus, Result, Win32Error};
+pub use error::hresult;
+pub use error::ntstatus;
+
 // Module Re-Exports
+#[cfg(all(windows, feature = "unknown"))]
 pub use safe_wrappers::unknown;
+#[cfg(all(windows, feature = "dxgi"))]
 pub use safe_wrappers::dxgi;
 
-pub use safe_wrappers::error_handling_api;
\ No newline at end of file
+pub use safe_wrappers::error_handling_api;
+pub use safe_wrappers::wer_api;
+pub use type_wrappers::exception;
+pub use type_wrappers::type_conversion::win_types;
+pub use type_wrappers::type_conversion::wide_string;
+pub use type_wrappers::type_conversion::wtf8;
+pub use type_wrappers::type_conversion::multi_string;
+pub use type_wrappers::type_conversion::code_page;
+pub use type_wrappers::type_conversion::win32_str;
+pub use type_wrappers::type_conversion::reflection;
+pub use type_wrappers::type_conversion::ffi_conversion;
+pub use type_wrappers::type_conversion::success;
+
+pub use windings_macros::{ffi_wrap, LayoutMirror};
@@ -1,6 +1,7 @@
-#![cfg(feature = "dxgi")]
 #![allow(non_snake_case,non_camel_case_types)]
 use crate::unknown::COMContainer;
+use crate::error::{Error, ErrorKind};
+use crate::type_wrappers::type_conversion::success::{check, HResultPolicy};
 
 //In the Documentation, there is a fourth Enum Member DXGI_ADAPTER_FLAG_FORCE_DWORD,
 // but since it is directly specified as not used and it is not defined as a constant in WINAPI,
@@ -11,7 +12,7 @@ CONST_TO_ENUM!(const_enum DXGI_ADAPTER_FLAG, winapi::shared::dxgi::DXGI_ADAPTER_
     DXGI_ADAPTER_FLAG_SOFTWARE = winapi::shared::dxgi::DXGI_ADAPTER_FLAG_SOFTWARE,
 });
 
-CONST_TO_ENUM!(const_enum DXGI_RESIDENCY, winapi::shared::dxgi::DXGI_RESIDENCY {
+CONST_TO_ENUM!(const_enum_open DXGI_RESIDENCY, winapi::shared::dxgi::DXGI_RESIDENCY {
     DXGI_RESIDENCY_FULLY_RESIDENT = winapi::shared::dxgi::DXGI_RESIDENCY_FULLY_RESIDENT,
     DXGI_RESIDENCY_RESIDENT_IN_SHARED_MEMORY = winapi::shared::dxgi::DXGI_RESIDENCY_RESIDENT_IN_SHARED_MEMORY,
     DXGI_RESIDENCY_EVICTED_TO_DISK = winapi::shared::dxgi::DXGI_RESIDENCY_EVICTED_TO_DISK,
@@ -20,7 +21,7 @@ CONST_TO_ENUM!(const_enum DXGI_RESIDENCY, winapi::shared::dxgi::DXGI_RESIDENCY {
 //This is not defined as a constant in Winapi, but appears to be a valid Option, so we define it here so we have it as a constant
 const DXGI_SWAP_CHAIN_FLAG_RESTRICTED_TO_ALL_HOLOGRAPHIC_DISPLAYS: winapi::shared::dxgi::DXGI_ADAPTER_FLAG = 12;
 
-CONST_TO_ENUM!(const_enum DXGI_SWAP_CHAIN_FLAG, winapi::shared::dxgi::DXGI_SWAP_CHAIN_FLAG {
+CONST_TO_ENUM!(const_enum_open DXGI_SWAP_CHAIN_FLAG, winapi::shared::dxgi::DXGI_SWAP_CHAIN_FLAG {
     DXGI_SWAP_CHAIN_FLAG_NONPREROTATED = winapi::shared::dxgi::DXGI_SWAP_CHAIN_FLAG_NONPREROTATED,
     DXGI_SWAP_CHAIN_FLAG_ALLOW_MODE_SWITCH = winapi::shared::dxgi::DXGI_SWAP_CHAIN_FLAG_ALLOW_MODE_SWITCH,
     DXGI_SWAP_CHAIN_FLAG_GDI_COMPATIBLE = winapi::shared::dxgi::DXGI_SWAP_CHAIN_FLAG_GDI_COMPATIBLE,
@@ -36,7 +37,7 @@ CONST_TO_ENUM!(const_enum DXGI_SWAP_CHAIN_FLAG, winapi::shared::dxgi::DXGI_SWAP_
     DXGI_SWAP_CHAIN_FLAG_RESTRICTED_TO_ALL_HOLOGRAPHIC_DISPLAYS = DXGI_SWAP_CHAIN_FLAG_RESTRICTED_TO_ALL_HOLOGRAPHIC_DISPLAYS,
 });
 
-CONST_TO_ENUM!(const_enum DXGI_SWAP_EFFECT, winapi::shared::dxgi::DXGI_SWAP_EFFECT {
+CONST_TO_ENUM!(const_enum_open DXGI_SWAP_EFFECT, winapi::shared::dxgi::DXGI_SWAP_EFFECT {
     DXGI_SWAP_EFFECT_DISCARD = winapi::shared::dxgi::DXGI_SWAP_EFFECT_DISCARD,
     DXGI_SWAP_EFFECT_FLIP_DISCARD = winapi::shared::dxgi::DXGI_SWAP_EFFECT_FLIP_DISCARD,
     DXGI_SWAP_EFFECT_FLIP_SEQUENTIAL = winapi::shared::dxgi::DXGI_SWAP_EFFECT_FLIP_SEQUENTIAL,
@@ -53,15 +54,10 @@ impl DXGI_FACTORY for winapi::shared::dxgi1_4::IDXGIFactory4 {}
 impl DXGI_FACTORY for winapi::shared::dxgi1_5::IDXGIFactory5 {}
 impl DXGI_FACTORY for winapi::shared::dxgi1_6::IDXGIFactory6 {}
 
-pub enum CreateFactoryError {
-    Nullptr,
-    Result(winapi::shared::winerror::HRESULT)
-}
-
-pub fn CreateDXGIFactory<FactoryType: DXGI_FACTORY, ContainerType: COMContainer<FactoryType>>() -> Result<ContainerType,CreateFactoryError> {
+pub fn CreateDXGIFactory<FactoryType: DXGI_FACTORY, ContainerType: COMContainer<FactoryType>>() -> Result<ContainerType, Error> {
     use winapi::ctypes::c_void;
     use winapi::shared::guiddef::{GUID,IID,REFIID};
-    use winapi::shared::winerror::{HRESULT,S_OK};
+    use winapi::shared::winerror::HRESULT;
     use winapi::_core::ptr::{null_mut};
 
     let guid: GUID = FactoryType::uuidof();
@@ -76,22 +72,16 @@ pub fn CreateDXGIFactory<FactoryType: DXGI_FACTORY, ContainerType: COMContainer<
         result = winapi::shared::dxgi::CreateDXGIFactory(riid, pp_factory);
     }
 
-    if result != S_OK
-    {
-        return Err(CreateFactoryError::Result(result));
-    }
-    if p_factory == null_mut() {
-        return Err(CreateFactoryError::Nullptr);
-    }
+    check::<HResultPolicy, _>("CreateDXGIFactory", result)?;
 
     let p_factory: *mut FactoryType = p_factory as *mut FactoryType;
-    return Ok(ContainerType::from_ptr(p_factory).unwrap());
+    return ContainerType::from_ptr(p_factory).ok_or_else(|| Error::new(ErrorKind::NullPointer).with_api("CreateDXGIFactory"));
 }
 
-pub fn CreateDXGIFactory1<FactoryType: DXGI_FACTORY, ContainerType: COMContainer<FactoryType>>() -> Result<ContainerType, CreateFactoryError>{
+pub fn CreateDXGIFactory1<FactoryType: DXGI_FACTORY, ContainerType: COMContainer<FactoryType>>() -> Result<ContainerType, Error> {
     use winapi::ctypes::c_void;
     use winapi::shared::guiddef::{GUID,IID,REFIID};
-    use winapi::shared::winerror::{HRESULT,S_OK};
+    use winapi::shared::winerror::HRESULT;
     use winapi::_core::ptr::{null_mut};
 
     let guid: GUID = FactoryType::uuidof();
@@ -106,16 +96,9 @@ pub fn CreateDXGIFactory1<FactoryType: DXGI_FACTORY, ContainerType: COMContainer
         result = winapi::shared::dxgi::CreateDXGIFactory1(riid, pp_factory);
     }
 
-    match result {
-        S_OK => {},
-        _ => return Err(CreateFactoryError::Result(result)),
-    };
-
-    if p_factory == null_mut() {
-        return Err(CreateFactoryError::Nullptr);
-    }
+    check::<HResultPolicy, _>("CreateDXGIFactory1", result)?;
 
     let p_factory: *mut FactoryType = p_factory as *mut FactoryType;
-    return Ok(ContainerType::from_ptr(p_factory).unwrap());
+    return ContainerType::from_ptr(p_factory).ok_or_else(|| Error::new(ErrorKind::NullPointer).with_api("CreateDXGIFactory1"));
 
 }
\ No newline at end of file
@@ -1,21 +1,986 @@
-use winapi::um::winnt::PVOID;
+// Re-Exports of Types that are available on every Host
+pub use crate::type_wrappers::error_handling_api::{ERROR_MODE, THREAD_ERROR_MODE, FilterDecision, HandlerDecision, PlacementPosition};
+pub use crate::type_wrappers::exception::{CustomException, ExceptionInfo, PanicReport, PANIC_EXCEPTION_CODE};
 
 // Re-Exports of Functions that only required Type Conversions
-pub use crate::type_wrappers::error_handling_api::{ERROR_MODE, THREAD_ERROR_MODE};
-pub use crate::type_wrappers::error_handling_api::FatalAppExitA;
-pub use crate::type_wrappers::error_handling_api::FatalAppExitW;
-pub use crate::type_wrappers::error_handling_api::GetThreadErrorMode;
-pub use crate::type_wrappers::error_handling_api::GetErrorMode;
-pub use crate::type_wrappers::error_handling_api::GetLastError;
-pub use crate::type_wrappers::error_handling_api::RaiseException;
-pub use crate::type_wrappers::error_handling_api::RaiseFailFastException;
-pub use crate::type_wrappers::error_handling_api::SetErrorMode;
-pub use crate::type_wrappers::error_handling_api::SetLastError;
-pub use crate::type_wrappers::error_handling_api::SetThreadErrorMode;
-pub use crate::type_wrappers::error_handling_api::SetUnhandledExceptionFilter;
-pub use crate::type_wrappers::error_handling_api::UnhandledExceptionFilter;
-use crate::type_wrappers::error_handling_api::{RemoveVectoredContinueHandler, RemoveVectoredExceptionHandler, PlacementPosition, AddVectoredExceptionHandler};
-use winapi::ctypes::c_void;
+#[cfg(windows)]
+pub use crate::type_wrappers::error_handling_api::{
+    FatalAppExitA,
+    FatalAppExitW,
+    fatal_app_exit,
+    fatal_app_exit_ansi,
+    GetThreadErrorMode,
+    GetErrorMode,
+    GetLastError,
+    RaiseException,
+    RaiseFailFastException,
+    SetErrorMode,
+    SetLastError,
+    SetThreadErrorMode,
+};
+#[cfg(windows)]
+use crate::type_wrappers::error_handling_api::{AddVectoredContinueHandler, AddVectoredExceptionHandler, RemoveVectoredContinueHandler, RemoveVectoredExceptionHandler};
+#[cfg(windows)]
+use crate::type_wrappers::error_handling_api::{SetUnhandledExceptionFilter, TopLevelFilterFunction, UnhandledExceptionFilter};
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::win_types::{LONG, PVOID};
+#[cfg(windows)]
+use crate::error::NtStatus;
+#[cfg(windows)]
+use crate::type_wrappers::exception::{Architecture, ExceptionRecord};
+#[cfg(windows)]
+use std::convert::TryFrom;
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::success::{check, BoolPolicy};
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, PoisonError, RwLock};
+use crate::error::{Error, ErrorKind};
+
+/// Access to an Error Mode, which Error Mode Guards replace and restore
+///
+/// The Error Mode is accessed as raw Bits, so Bits that the Bitflags do not know are restored as well.
+pub(crate) trait ErrorModeBackend {
+    /// The Bitflags of the Error Mode
+    type Mode: Copy;
+
+    /// Returns the Bits of the current Error Mode
+    fn get(&self) -> u32;
+
+    /// Sets the Bits of the Error Mode and returns the Bits of the previous Error Mode
+    fn set(&self, bits: u32) -> Result<u32, Error>;
+
+    /// Returns the Bits of the Bitflags
+    fn to_bits(mode: Self::Mode) -> u32;
+
+    /// Returns the Bitflags of the Bits, without the unknown Bits
+    fn from_bits_truncate(bits: u32) -> Self::Mode;
+}
+
+/// Replaces an Error Mode and restores the previous Error Mode when dropped
+#[cfg_attr(not(windows), allow(dead_code))]
+pub(crate) struct ModeGuard<B: ErrorModeBackend> {
+    backend: B,
+    // The raw previous Error Mode, so the Restore cannot fail on unknown Bits
+    previous: u32,
+}
+
+#[cfg_attr(not(windows), allow(dead_code))]
+impl<B: ErrorModeBackend> ModeGuard<B> {
+    /// Replaces the Error Mode
+    pub(crate) fn set(backend: B, mode: B::Mode) -> Result<ModeGuard<B>, Error> {
+        let previous = backend.set(B::to_bits(mode))?;
+        return Ok(ModeGuard { backend, previous });
+    }
+
+    /// Replaces the Error Mode with a Mode derived from the current Error Mode, keeping the unknown Bits of the current Error Mode
+    pub(crate) fn update<F: FnOnce(B::Mode) -> B::Mode>(backend: B, update: F) -> Result<ModeGuard<B>, Error> {
+        let current = backend.get();
+        let known = B::from_bits_truncate(current);
+        let unknown = current & !B::to_bits(known);
+        let previous = backend.set(B::to_bits(update(known)) | unknown)?;
+        return Ok(ModeGuard { backend, previous });
+    }
+
+    /// Returns the Error Mode that is restored when the Guard is dropped, without unknown Bits
+    #[inline]
+    pub(crate) fn previous(&self) -> B::Mode {
+        return B::from_bits_truncate(self.previous);
+    }
+}
+
+impl<B: ErrorModeBackend> Drop for ModeGuard<B> {
+    /// Restores the previous Error Mode, which also happens while unwinding from a Panic
+    fn drop(&mut self) {
+        // Panicking here would abort the Process while unwinding, the Mode stays changed instead
+        let _ = self.backend.set(self.previous);
+    }
+}
+
+/// The Error Mode of the Process
+#[cfg(windows)]
+struct ProcessErrorMode;
+
+#[cfg(windows)]
+impl ErrorModeBackend for ProcessErrorMode {
+    type Mode = ERROR_MODE;
+
+    fn get(&self) -> u32 {
+        return unsafe { winapi::um::errhandlingapi::GetErrorMode() };
+    }
+
+    fn set(&self, bits: u32) -> Result<u32, Error> {
+        return Ok(unsafe { winapi::um::errhandlingapi::SetErrorMode(bits) });
+    }
+
+    fn to_bits(mode: ERROR_MODE) -> u32 {
+        return mode.bits();
+    }
+
+    fn from_bits_truncate(bits: u32) -> ERROR_MODE {
+        return ERROR_MODE::from_bits_truncate(bits);
+    }
+}
+
+/// The Error Mode of the calling Thread
+#[cfg(windows)]
+struct ThreadErrorMode;
+
+#[cfg(windows)]
+impl ErrorModeBackend for ThreadErrorMode {
+    type Mode = THREAD_ERROR_MODE;
+
+    fn get(&self) -> u32 {
+        return unsafe { winapi::um::errhandlingapi::GetThreadErrorMode() };
+    }
+
+    fn set(&self, bits: u32) -> Result<u32, Error> {
+        let mut previous: u32 = 0;
+        let succeeded = unsafe { winapi::um::errhandlingapi::SetThreadErrorMode(bits, &mut previous) };
+        check::<BoolPolicy, _>("SetThreadErrorMode", succeeded)?;
+        return Ok(previous);
+    }
+
+    fn to_bits(mode: THREAD_ERROR_MODE) -> u32 {
+        return mode.bits();
+    }
+
+    fn from_bits_truncate(bits: u32) -> THREAD_ERROR_MODE {
+        return THREAD_ERROR_MODE::from_bits_truncate(bits);
+    }
+}
+
+/// Guard that changes the Error Mode of the Process for a Scope and restores the previous Error Mode when dropped
+///
+/// # Example
+///
+/// ```ignore
+/// // Probing a removable Drive without a Disk must not show a Message Box
+/// let _guard = ErrorModeGuard::insert(ERROR_MODE::NOOPENFILEERRORBOX | ERROR_MODE::FAILCRITICALERRORS);
+/// let present = Path::new("A:\\").exists();
+/// ```
+///
+/// # Note
+///
+/// The Error Mode is shared by all Threads of the Process, so Guards of different Threads can restore each others Modes.
+/// Prefer `ThreadErrorModeGuard` where the Thread Error Mode is sufficient.
+/// Nested Guards must be dropped in the reverse Order of their Creation, otherwise an outdated Mode is restored.
+#[cfg(windows)]
+pub struct ErrorModeGuard {
+    guard: ModeGuard<ProcessErrorMode>,
+}
+
+#[cfg(windows)]
+impl ErrorModeGuard {
+    /// Replaces the Error Mode of the Process
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The Error Mode that should be set
+    #[inline]
+    pub fn set(mode: ERROR_MODE) -> ErrorModeGuard {
+        return ErrorModeGuard { guard: ModeGuard::set(ProcessErrorMode, mode).expect("SetErrorMode cannot fail") };
+    }
+
+    /// Adds Flags to the Error Mode of the Process
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - The Flags that should be added to the current Error Mode
+    #[inline]
+    pub fn insert(flags: ERROR_MODE) -> ErrorModeGuard {
+        return ErrorModeGuard { guard: ModeGuard::update(ProcessErrorMode, |mode| mode | flags).expect("SetErrorMode cannot fail") };
+    }
+
+    /// Removes Flags from the Error Mode of the Process
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - The Flags that should be removed from the current Error Mode
+    #[inline]
+    pub fn remove(flags: ERROR_MODE) -> ErrorModeGuard {
+        return ErrorModeGuard { guard: ModeGuard::update(ProcessErrorMode, |mode| mode - flags).expect("SetErrorMode cannot fail") };
+    }
+
+    /// Returns the Error Mode that is restored when the Guard is dropped
+    #[inline]
+    pub fn previous(&self) -> ERROR_MODE {
+        return self.guard.previous();
+    }
+}
+
+/// Guard that changes the Error Mode of the calling Thread for a Scope and restores the previous Error Mode when dropped
+///
+/// # Note
+///
+/// The Guard cannot be sent to other Threads, since it has to restore the Mode of the Thread that created it.
+/// Nested Guards must be dropped in the reverse Order of their Creation, otherwise an outdated Mode is restored.
+#[cfg(windows)]
+pub struct ThreadErrorModeGuard {
+    guard: ModeGuard<ThreadErrorMode>,
+    // The Thread Error Mode belongs to the Thread that created the Guard
+    _thread: PhantomData<*const ()>,
+}
+
+#[cfg(windows)]
+impl ThreadErrorModeGuard {
+    /// Replaces the Error Mode of the calling Thread
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The Error Mode that should be set
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if the Error Mode could not be set
+    #[inline]
+    pub fn set(mode: THREAD_ERROR_MODE) -> Result<ThreadErrorModeGuard, Error> {
+        return Ok(ThreadErrorModeGuard { guard: ModeGuard::set(ThreadErrorMode, mode)?, _thread: PhantomData });
+    }
+
+    /// Adds Flags to the Error Mode of the calling Thread
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - The Flags that should be added to the current Error Mode
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if the Error Mode could not be set
+    #[inline]
+    pub fn insert(flags: THREAD_ERROR_MODE) -> Result<ThreadErrorModeGuard, Error> {
+        return Ok(ThreadErrorModeGuard { guard: ModeGuard::update(ThreadErrorMode, |mode| mode | flags)?, _thread: PhantomData });
+    }
+
+    /// Removes Flags from the Error Mode of the calling Thread
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - The Flags that should be removed from the current Error Mode
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if the Error Mode could not be set
+    #[inline]
+    pub fn remove(flags: THREAD_ERROR_MODE) -> Result<ThreadErrorModeGuard, Error> {
+        return Ok(ThreadErrorModeGuard { guard: ModeGuard::update(ThreadErrorMode, |mode| mode - flags)?, _thread: PhantomData });
+    }
+
+    /// Returns the Error Mode that is restored when the Guard is dropped
+    #[inline]
+    pub fn previous(&self) -> THREAD_ERROR_MODE {
+        return self.guard.previous();
+    }
+}
+
+thread_local! {
+    // The Number of Dispatches to Rust Closures running on this Thread
+    static DISPATCH_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Marks the calling Thread as dispatching an Exception to Rust Closures, as long as it lives
+///
+/// The Dispatches catch Panics of the Closures, since they must not unwind into the Code that raised the Exception.
+/// The Panic Hook of `install_panic_hook` does not raise Panics on marked Threads, so they reach these Guards.
+struct DispatchGuard;
+
+impl DispatchGuard {
+    /// Marks the calling Thread until the Guard is dropped
+    fn enter() -> DispatchGuard {
+        DISPATCH_DEPTH.with(|depth| depth.set(depth.get() + 1));
+        return DispatchGuard;
+    }
+
+    /// Returns whether the calling Thread is dispatching an Exception to Rust Closures
+    // Only the Panic Hook asks, which exists on Windows only
+    #[cfg_attr(not(windows), allow(dead_code))]
+    fn is_dispatching() -> bool {
+        return DISPATCH_DEPTH.with(|depth| depth.get() > 0);
+    }
+}
+
+impl Drop for DispatchGuard {
+    fn drop(&mut self) {
+        DISPATCH_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// A Rust Closure registered as Vectored Handler
+type Handler = Box<dyn Fn(&mut ExceptionInfo) -> HandlerDecision + Send + Sync>;
+
+/// A Rust Closure in the Registry, shared with its running Calls
+type SharedHandler = Arc<dyn Fn(&mut ExceptionInfo) -> HandlerDecision + Send + Sync>;
+
+/// A Rust Closure in the Registry and its running Calls
+struct RegisteredHandler {
+    state: Mutex<HandlerState>,
+    // Notified when the last running Call returned
+    idle: Condvar,
+}
+
+/// The Closure of a RegisteredHandler and the Number of its running Calls
+struct HandlerState {
+    // The Closure, taken out when it is removed, so Snapshots of running Dispatches skip it
+    handler: Option<SharedHandler>,
+    calls: usize,
+}
+
+impl RegisteredHandler {
+    /// Creates the Entry of a Closure
+    fn new(handler: Handler) -> RegisteredHandler {
+        return RegisteredHandler {
+            state: Mutex::new(HandlerState { handler: Some(Arc::from(handler)), calls: 0 }),
+            idle: Condvar::new(),
+        };
+    }
+
+    /// Calls the Closure, unless it was removed
+    ///
+    /// # Return
+    ///
+    /// This Function returns the Decision of the Closure, or `HandlerDecision::ContinueSearch` if it was removed or panicked
+    fn call(&self, info: &mut ExceptionInfo) -> HandlerDecision {
+        let handler = {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            let handler = match state.handler.as_ref() {
+                Some(handler) => Arc::clone(handler),
+                None => return HandlerDecision::ContinueSearch,
+            };
+            state.calls += 1;
+            handler
+        };
+        let decision = panic::catch_unwind(AssertUnwindSafe(|| handler(info))).unwrap_or(HandlerDecision::ContinueSearch);
+        // The Reference is released before the Call is finished, so the Closure is dropped by whoever removes it
+        drop(handler);
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.calls -= 1;
+        if state.calls == 0 {
+            self.idle.notify_all();
+        }
+        return decision;
+    }
+
+    /// Takes the Closure out, so it is not called anymore
+    ///
+    /// # Arguments
+    ///
+    /// * `wait_for_calls` - Whether to block until the running Calls of the Closure returned
+    fn take(&self, wait_for_calls: bool) -> Option<SharedHandler> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let handler = state.handler.take();
+        if wait_for_calls {
+            while state.calls > 0 {
+                state = self.idle.wait(state).unwrap_or_else(PoisonError::into_inner);
+            }
+        }
+        return handler;
+    }
+}
+
+/// Registry of the Rust Closures of one Kind of Vectored Handler
+///
+/// The Win32 API only accepts Function Pointers without a Context Argument as Vectored Handlers.
+/// Thus a single Trampoline Function per Kind is registered with the API while Closures are registered, which dispatches to the Closures of the Registry.
+///
+/// # Note
+///
+/// The Closures are called in the Order of their Placement among each other.
+/// Relative to Handlers registered outside of this Crate, all Closures share the Position the Trampoline was registered with, which is the Placement of the first Closure.
+pub(crate) struct HandlerRegistry {
+    handlers: RwLock<Vec<(u64, Arc<RegisteredHandler>)>>,
+    next_id: AtomicU64,
+    // The Handle of the registered Trampoline, or 0 if it is not registered
+    registration: Mutex<usize>,
+}
+
+impl HandlerRegistry {
+    /// Creates an empty Registry
+    pub(crate) const fn new() -> HandlerRegistry {
+        return HandlerRegistry {
+            handlers: RwLock::new(Vec::new()),
+            next_id: AtomicU64::new(1),
+            registration: Mutex::new(0),
+        };
+    }
+
+    /// Adds a Closure to the Registry
+    ///
+    /// # Arguments
+    ///
+    /// * `placement_position` - Specifies, where the Closure will be placed among the Closures of the Registry
+    /// * `handler` - The Closure that should be added
+    /// * `register` - Registers the Trampoline with the API, if it is not registered yet, and returns its Handle
+    ///
+    /// # Return
+    ///
+    /// This Function returns the Id of the Closure or the Error of `register`
+    pub(crate) fn add<R>(&self, placement_position: PlacementPosition, handler: Handler, register: R) -> Result<u64, Error> where R: FnOnce() -> Result<usize, Error> {
+        let mut registration = self.registration.lock().unwrap_or_else(PoisonError::into_inner);
+        if *registration == 0 {
+            *registration = register()?;
+        }
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let mut handlers = self.handlers.write().unwrap_or_else(PoisonError::into_inner);
+        match placement_position {
+            PlacementPosition::EmplaceFirst => handlers.insert(0, (id, Arc::new(RegisteredHandler::new(handler)))),
+            PlacementPosition::EmplaceLast => handlers.push((id, Arc::new(RegisteredHandler::new(handler)))),
+        }
+        return Ok(id);
+    }
+
+    /// Removes a Closure from the Registry
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The Id of the Closure
+    /// * `wait_for_calls` - Whether to block until the running Calls of the Closure on other Threads returned, which Closures that borrow Data need
+    /// * `unregister` - Unregisters the Trampoline with the given Handle from the API, called when the last Closure was removed
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Id does not belong to a registered Closure or the Error of `unregister`
+    ///
+    /// # Note
+    ///
+    /// Once this returns, running Dispatches skip the Closure, even if they started before.
+    /// Only the Calls of the removed Closure are waited for, so a Closure may remove other Closures while waiting, but must not wait for its own Calls.
+    pub(crate) fn remove<U>(&self, id: u64, wait_for_calls: bool, unregister: U) -> Result<(), Error> where U: FnOnce(usize) -> Result<(), Error> {
+        let (removed, result) = {
+            let mut registration = self.registration.lock().unwrap_or_else(PoisonError::into_inner);
+            let (removed, is_empty) = {
+                let mut handlers = self.handlers.write().unwrap_or_else(PoisonError::into_inner);
+                let position = handlers.iter().position(|(handler_id, _)| *handler_id == id)
+                    .ok_or_else(|| Error::new(ErrorKind::InvalidArgument("the handle does not belong to a registered handler")))?;
+                (handlers.remove(position).1, handlers.is_empty())
+            };
+            let mut result = Ok(());
+            if is_empty && *registration != 0 {
+                result = unregister(*registration);
+                if result.is_ok() {
+                    *registration = 0;
+                }
+            }
+            (removed, result)
+        };
+        // The Locks of the Registry are released, so the running Closures may add or remove Handlers meanwhile.
+        // The Closure is dropped outside of the Locks, so its Captures may use the Registry.
+        drop(removed.take(wait_for_calls));
+        return result;
+    }
+
+    /// Calls the Closures in Order until one of them continues the Execution
+    ///
+    /// # Return
+    ///
+    /// This Function returns `HandlerDecision::ContinueExecution` if a Closure continued the Execution and `HandlerDecision::ContinueSearch` otherwise
+    ///
+    /// # Note
+    ///
+    /// A panicking Closure is treated as if it continued the Search, since Panics must not unwind into the Code that raised the Exception.
+    /// The Closures are called on a Snapshot of the Registry without holding its Lock, so they may add or remove Handlers.
+    /// Added Closures are called from the next Dispatch on, while removed Closures are skipped right away.
+    // Only the Trampolines dispatch Exceptions, which exist on Windows only
+    #[cfg_attr(not(windows), allow(dead_code))]
+    pub(crate) fn dispatch(&self, info: &mut ExceptionInfo) -> HandlerDecision {
+        let _dispatching = DispatchGuard::enter();
+        let handlers: Vec<Arc<RegisteredHandler>> = self.handlers.read().unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(_, handler)| Arc::clone(handler))
+            .collect();
+        // Every Entry of the Snapshot is dropped as soon as it was called
+        for handler in handlers.into_iter() {
+            if handler.call(info) == HandlerDecision::ContinueExecution {
+                return HandlerDecision::ContinueExecution;
+            }
+        }
+        return HandlerDecision::ContinueSearch;
+    }
+}
+
+/// One Kind of Vectored Handler: The Registry of its Closures and the Functions that register its Trampoline with the API
+pub(crate) struct HandlerKind {
+    registry: &'static HandlerRegistry,
+    register: fn(PlacementPosition) -> Result<usize, Error>,
+    unregister: fn(usize) -> Result<(), Error>,
+}
+
+impl HandlerKind {
+    /// Adds a Closure and registers the Trampoline if needed, see `HandlerRegistry::add`
+    #[inline]
+    pub(crate) fn add(&self, placement_position: PlacementPosition, handler: Handler) -> Result<u64, Error> {
+        return self.registry.add(placement_position, handler, || (self.register)(placement_position));
+    }
+
+    /// Removes a Closure and unregisters the Trampoline if needed, see `HandlerRegistry::remove`
+    #[inline]
+    pub(crate) fn remove(&self, id: u64, wait_for_calls: bool) -> Result<(), Error> {
+        return self.registry.remove(id, wait_for_calls, self.unregister);
+    }
+}
+
+/// The Closures registered as Vectored Exception Handlers
+static EXCEPTION_HANDLERS: HandlerRegistry = HandlerRegistry::new();
+
+/// The Closures registered as Vectored Continue Handlers
+static CONTINUE_HANDLERS: HandlerRegistry = HandlerRegistry::new();
+
+/// Vectored Exception Handlers
+static EXCEPTION_KIND: HandlerKind = HandlerKind {
+    registry: &EXCEPTION_HANDLERS,
+    register: register_exception_trampoline,
+    unregister: unregister_exception_trampoline,
+};
+
+/// Vectored Continue Handlers
+static CONTINUE_KIND: HandlerKind = HandlerKind {
+    registry: &CONTINUE_HANDLERS,
+    register: register_continue_trampoline,
+    unregister: unregister_continue_trampoline,
+};
+
+#[cfg(windows)]
+fn register_exception_trampoline(placement_position: PlacementPosition) -> Result<usize, Error> {
+    return AddVectoredExceptionHandler(placement_position, exception_trampoline).map(|handle| handle as usize);
+}
+
+#[cfg(windows)]
+fn unregister_exception_trampoline(handle: usize) -> Result<(), Error> {
+    return RemoveVectoredExceptionHandler(handle as PVOID);
+}
+
+#[cfg(windows)]
+fn register_continue_trampoline(placement_position: PlacementPosition) -> Result<usize, Error> {
+    return AddVectoredContinueHandler(placement_position, continue_trampoline).map(|handle| handle as usize);
+}
+
+#[cfg(windows)]
+fn unregister_continue_trampoline(handle: usize) -> Result<(), Error> {
+    return RemoveVectoredContinueHandler(handle as PVOID);
+}
+
+// Structured Exceptions only exist on Windows, so Handlers cannot be registered on other Hosts
+#[cfg(not(windows))]
+fn register_exception_trampoline(_placement_position: PlacementPosition) -> Result<usize, Error> {
+    return Err(Error::new(ErrorKind::InvalidArgument("vectored handlers are only available on windows")).with_api("AddVectoredExceptionHandler"));
+}
+
+#[cfg(not(windows))]
+fn unregister_exception_trampoline(_handle: usize) -> Result<(), Error> {
+    return Ok(());
+}
+
+#[cfg(not(windows))]
+fn register_continue_trampoline(_placement_position: PlacementPosition) -> Result<usize, Error> {
+    return Err(Error::new(ErrorKind::InvalidArgument("vectored handlers are only available on windows")).with_api("AddVectoredContinueHandler"));
+}
+
+#[cfg(not(windows))]
+fn unregister_continue_trampoline(_handle: usize) -> Result<(), Error> {
+    return Ok(());
+}
+
+/// Dispatches an Exception of the Win32 API to the Closures of a Registry
+///
+/// # Safety
+///
+/// The Pointer must be the EXCEPTION_POINTERS passed to a Vectored Handler
+#[cfg(windows)]
+unsafe fn dispatch_raw(registry: &HandlerRegistry, pointers: *mut winapi::um::winnt::EXCEPTION_POINTERS) -> LONG {
+    // Panics while converting the Exception must not unwind into the Code that raised it either
+    let _dispatching = DispatchGuard::enter();
+    let decision = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut info = ExceptionInfo::from_raw(pointers);
+        let decision = registry.dispatch(&mut info);
+        if decision == HandlerDecision::ContinueExecution && info.write_context_to_raw(pointers).is_err() {
+            return HandlerDecision::ContinueSearch;
+        }
+        return decision;
+    }));
+    return decision.unwrap_or(HandlerDecision::ContinueSearch).into();
+}
+
+/// The Trampoline registered as Vectored Exception Handler
+#[cfg(windows)]
+unsafe extern "system" fn exception_trampoline(pointers: *mut winapi::um::winnt::EXCEPTION_POINTERS) -> LONG {
+    return dispatch_raw(&EXCEPTION_HANDLERS, pointers);
+}
+
+/// The Trampoline registered as Vectored Continue Handler
+#[cfg(windows)]
+unsafe extern "system" fn continue_trampoline(pointers: *mut winapi::um::winnt::EXCEPTION_POINTERS) -> LONG {
+    return dispatch_raw(&CONTINUE_HANDLERS, pointers);
+}
+
+/// A Rust Closure installed as top-level Exception Filter
+type Filter = dyn Fn(&mut ExceptionInfo, &NextFilter) -> FilterDecision + Send + Sync;
+
+/// Access to the top-level Exception Filter of the Process
+///
+/// Filters are identified by their Addresses, 0 stands for no Filter, which makes the System call UnhandledExceptionFilter.
+/// Exceptions are identified by the Addresses of their EXCEPTION_POINTERS, so the Filter Chain can be tested with a fake Backend.
+pub(crate) trait FilterBackend: Sync {
+    /// Returns the Address of the Trampoline that dispatches Exceptions to the Filter Chain
+    fn trampoline(&self) -> usize;
+
+    /// Installs a Filter and returns the previously installed Filter
+    fn set_filter(&self, filter: usize) -> usize;
+
+    /// Calls a Filter that is not part of the Filter Chain, 0 calls UnhandledExceptionFilter
+    ///
+    /// The Registers of the Context of the Exception Info are written back before the Filter is called, so it sees the Changes of the Closures.
+    fn call_filter(&self, filter: usize, exception: usize, info: &ExceptionInfo) -> FilterDecision;
+}
+
+/// The State of the Filter Chain
+struct FilterChainState {
+    // The installed Closures, the most recently installed one first
+    filters: Vec<(u64, Arc<Filter>)>,
+    next_id: u64,
+    // The Filter the Trampoline replaced, or None if the Trampoline is not installed
+    previous: Option<usize>,
+}
+
+/// The Chain of Rust Closures installed as top-level Exception Filter
+///
+/// The Win32 API only accepts Function Pointers without a Context Argument as top-level Filter.
+/// Thus a Trampoline is installed while Closures are installed, which calls the most recently installed Closure.
+/// Every Closure can forward the Exception to the Closure installed before it and the last one to the Filter the Trampoline replaced.
+#[cfg_attr(not(windows), allow(dead_code))]
+pub(crate) struct FilterChain {
+    backend: &'static dyn FilterBackend,
+    state: Mutex<FilterChainState>,
+}
+
+#[cfg_attr(not(windows), allow(dead_code))]
+impl FilterChain {
+    /// Creates an empty Chain on top of a Backend
+    pub(crate) const fn new(backend: &'static dyn FilterBackend) -> FilterChain {
+        return FilterChain {
+            backend,
+            state: Mutex::new(FilterChainState { filters: Vec::new(), next_id: 1, previous: None }),
+        };
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, FilterChainState> {
+        return self.state.lock().unwrap_or_else(PoisonError::into_inner);
+    }
+
+    /// Installs a Closure on top of the Chain and installs the Trampoline if needed
+    ///
+    /// # Return
+    ///
+    /// This Function returns the Id of the Closure
+    pub(crate) fn install(&self, filter: Arc<Filter>) -> u64 {
+        let mut state = self.lock();
+        if state.previous.is_none() {
+            state.previous = Some(self.backend.set_filter(self.backend.trampoline()));
+        }
+        let id = state.next_id;
+        state.next_id += 1;
+        state.filters.insert(0, (id, filter));
+        return id;
+    }
+
+    /// Removes a Closure from the Chain and restores the previous Filter when the last Closure was removed
+    ///
+    /// # Note
+    ///
+    /// Closures can be removed in any Order, the Closures installed before and after a removed Closure are linked directly.
+    /// If another Filter was installed on top of the Trampoline in the meantime, that Filter is kept installed.
+    /// The Trampoline then stays in Place and forwards every Exception to the previous Filter.
+    pub(crate) fn uninstall(&self, id: u64) {
+        let mut state = self.lock();
+        let removed = state.filters.iter().position(|(filter_id, _)| *filter_id == id).map(|position| state.filters.remove(position));
+        if !state.filters.is_empty() || removed.is_none() {
+            return;
+        }
+        if let Some(previous) = state.previous {
+            let current = self.backend.set_filter(previous);
+            if current == self.backend.trampoline() {
+                state.previous = None;
+            } else {
+                // Someone else installed a Filter on top of the Trampoline, which must not be replaced
+                self.backend.set_filter(current);
+            }
+        }
+    }
+
+    /// Passes an Exception through the Chain
+    ///
+    /// # Arguments
+    ///
+    /// * `exception` - The Address of the EXCEPTION_POINTERS of the Exception
+    /// * `info` - The Exception
+    ///
+    /// # Note
+    ///
+    /// The Chain is not locked while the Closures run, so they may install and uninstall Filters.
+    pub(crate) fn dispatch(&self, exception: usize, info: &mut ExceptionInfo) -> FilterDecision {
+        let _dispatching = DispatchGuard::enter();
+        let (filters, previous) = {
+            let state = self.lock();
+            let filters: Vec<Arc<Filter>> = state.filters.iter().map(|(_, filter)| Arc::clone(filter)).collect();
+            (filters, state.previous.unwrap_or(0))
+        };
+        let next = NextFilter { chain: self, filters: &filters, exception, previous };
+        return next.forward(info);
+    }
+}
+
+/// The Rest of the Filter Chain after the Closure it is passed to
+pub struct NextFilter<'a> {
+    chain: &'a FilterChain,
+    filters: &'a [Arc<Filter>],
+    exception: usize,
+    previous: usize,
+}
+
+impl<'a> NextFilter<'a> {
+    /// Forwards the Exception to the Filter that was installed before the calling Closure
+    ///
+    /// # Return
+    ///
+    /// This Function returns the Decision of the next Filter.
+    /// If there is no previous Filter, the default Handling of UnhandledExceptionFilter decides.
+    ///
+    /// # Note
+    ///
+    /// A panicking Closure is treated as if it continued the Search, since Panics must not unwind into the Code that raised the Exception
+    pub fn forward(&self, info: &mut ExceptionInfo) -> FilterDecision {
+        return match self.filters.split_first() {
+            Some((filter, rest)) => {
+                let next = NextFilter { chain: self.chain, filters: rest, exception: self.exception, previous: self.previous };
+                panic::catch_unwind(AssertUnwindSafe(|| filter(info, &next))).unwrap_or(FilterDecision::ContinueSearch)
+            }
+            None => self.chain.backend.call_filter(self.previous, self.exception, info),
+        };
+    }
+
+    /// Forwards the Exception to UnhandledExceptionFilter, skipping all previous Filters
+    ///
+    /// # Return
+    ///
+    /// This Function returns the Decision of the default Handling, which notifies a Debugger or reports the Exception to Windows Error Reporting
+    #[inline]
+    pub fn forward_to_default(&self, info: &mut ExceptionInfo) -> FilterDecision {
+        return self.chain.backend.call_filter(0, self.exception, info);
+    }
+}
+
+/// The Backend of the top-level Filter of Windows
+#[cfg(windows)]
+struct WindowsFilterBackend;
+
+#[cfg(windows)]
+impl WindowsFilterBackend {
+    fn to_function(filter: usize) -> Option<TopLevelFilterFunction> {
+        return match filter {
+            0 => None,
+            // Filters are only ever created from TopLevelFilterFunctions
+            _ => Some(unsafe { mem::transmute::<usize, TopLevelFilterFunction>(filter) }),
+        };
+    }
+}
+
+#[cfg(windows)]
+impl FilterBackend for WindowsFilterBackend {
+    fn trampoline(&self) -> usize {
+        return filter_trampoline as TopLevelFilterFunction as usize;
+    }
+
+    fn set_filter(&self, filter: usize) -> usize {
+        return SetUnhandledExceptionFilter(WindowsFilterBackend::to_function(filter)).map_or(0, |function| function as usize);
+    }
+
+    fn call_filter(&self, filter: usize, exception: usize, info: &ExceptionInfo) -> FilterDecision {
+        let pointers = exception as *mut winapi::um::winnt::EXCEPTION_POINTERS;
+        unsafe {
+            // The Context of the Exception is only written back if it belongs to the Architecture of the Process, which it always does
+            let _ = info.write_context_to_raw(pointers);
+            return match WindowsFilterBackend::to_function(filter) {
+                Some(function) => FilterDecision::from(function(pointers)),
+                None => UnhandledExceptionFilter(pointers),
+            };
+        }
+    }
+}
+
+/// The Closures installed as top-level Exception Filter
+#[cfg(windows)]
+static FILTERS: FilterChain = FilterChain::new(&WindowsFilterBackend);
+
+/// The Trampoline installed as top-level Exception Filter
+#[cfg(windows)]
+unsafe extern "system" fn filter_trampoline(pointers: *mut winapi::um::winnt::EXCEPTION_POINTERS) -> LONG {
+    // Panics while converting the Exception must not unwind into the Code that raised it
+    let _dispatching = DispatchGuard::enter();
+    let decision = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut info = ExceptionInfo::from_raw(pointers);
+        let decision = FILTERS.dispatch(pointers as usize, &mut info);
+        if decision == FilterDecision::ContinueExecution && info.write_context_to_raw(pointers).is_err() {
+            return FilterDecision::ContinueSearch;
+        }
+        return decision;
+    }));
+    return decision.unwrap_or(FilterDecision::ContinueSearch).into();
+}
+
+/// Guard of a Closure installed as top-level Exception Filter, which uninstalls the Closure when dropped
+///
+/// See `set_unhandled_exception_filter`
+#[cfg(windows)]
+pub struct UnhandledExceptionFilterGuard {
+    id: u64
+}
+
+#[cfg(windows)]
+impl Drop for UnhandledExceptionFilterGuard {
+    /// Uninstalls the Closure, restoring the previous Filter if it was the last one
+    #[inline]
+    fn drop(&mut self) {
+        FILTERS.uninstall(self.id);
+    }
+}
+
+/// Installs a Closure as top-level Exception Filter, which is called for Exceptions that no Handler handled
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-setunhandledexceptionfilter)
+///
+/// # Arguments
+///
+/// * `filter` - The Closure that should be installed, it receives the Exception and the previous Filter, to which it can forward the Exception
+///
+/// # Return
+///
+/// This Function returns a Guard, which uninstalls the Closure when dropped.
+/// When the last Closure is uninstalled, the Filter that was installed before the first Closure is restored.
+///
+/// # Example
+///
+/// ```ignore
+/// let _guard = set_unhandled_exception_filter(|info, next| {
+///     write_crash_log(&info.record);
+///     return next.forward(info);
+/// });
+/// ```
+#[cfg(windows)]
+pub fn set_unhandled_exception_filter<F>(filter: F) -> UnhandledExceptionFilterGuard
+    where F: Fn(&mut ExceptionInfo, &NextFilter) -> FilterDecision + Send + Sync + 'static {
+    return UnhandledExceptionFilterGuard { id: FILTERS.install(Arc::new(filter)) };
+}
+
+/// Raises a Custom Exception, whose Value is serialized into the Parameters of the Exception
+///
+/// # Arguments
+///
+/// * `exception` - The Value that should be raised
+/// * `is_continuable` - Indicates whether Handlers can continue the Execution after the Exception
+///
+/// # Return
+///
+/// This Function returns `ErrorKind::InvalidArgument` if the Value cannot be encoded into the Parameters, or if a Parameter does not fit into a Pointer of the Process.
+/// Otherwise it returns when a Handler continued the Execution.
+#[cfg(windows)]
+pub fn raise_custom_exception<E: CustomException>(exception: &E, is_continuable: bool) -> Result<(), Error> {
+    let record = ExceptionRecord::builder_from_custom(exception)?.build();
+    let mut arguments = Vec::with_capacity(record.parameters().len());
+    for parameter in record.parameters() {
+        match usize::try_from(*parameter) {
+            Ok(argument) => arguments.push(argument),
+            Err(_) => return Err(Error::new(ErrorKind::InvalidArgument("the exception parameter does not fit into a pointer")).with_api("RaiseException")),
+        }
+    }
+    RaiseException(record.code(), is_continuable, &arguments);
+    return Ok(());
+}
+
+/// Wraps a Closure for a Custom Exception into a Vectored Handler, which only calls the Closure for Exceptions of that Type
+///
+/// # Arguments
+///
+/// * `handler` - The Closure, which receives the deserialized Value and the Exception
+///
+/// # Return
+///
+/// This Function returns a Handler for `VectoredExceptionHandler::add_vectored_exception_handler` or `HandlerScope`, which continues the Search for all other Exceptions
+///
+/// # Example
+///
+/// ```ignore
+/// let _handler = VectoredExceptionHandler::add_vectored_exception_handler(PlacementPosition::First, custom_exception_handler(|quota: QuotaExceeded, _info| {
+///     log_quota(quota.requested, quota.available);
+///     return HandlerDecision::ContinueExecution;
+/// }));
+/// ```
+pub fn custom_exception_handler<E, F>(handler: F) -> impl Fn(&mut ExceptionInfo) -> HandlerDecision + Send + Sync
+    where E: CustomException, F: Fn(E, &mut ExceptionInfo) -> HandlerDecision + Send + Sync {
+    return move |info: &mut ExceptionInfo| {
+        return match info.record.downcast::<E>() {
+            Some(exception) => handler(exception, info),
+            None => HandlerDecision::ContinueSearch,
+        };
+    };
+}
+
+/// The Ways in which the Panic Hook of `install_panic_hook` raises Panics as Exceptions
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PanicRaiseMode {
+    /// Raises a noncontinuable Exception with `RaiseException`, so Vectored Handlers and the top-level Filter see the Panic first
+    Exception,
+
+    /// Raises the Exception with `RaiseFailFastException`, which terminates the Process immediately and bypasses all Handlers
+    FailFast,
+}
+
+/// Installs a Panic Hook that raises every Panic as Structured Exception with `PANIC_EXCEPTION_CODE`, so Windows Error Reporting captures a Dump
+///
+/// The Message, the Location and the Name of the Thread are encoded into the Parameters of the Exception, see `PanicReport`.
+/// Crash Reporters and Exception Filters can decode them again with `PanicReport::from_exception_record`.
+///
+/// # Arguments
+///
+/// * `mode` - How the Exception should be raised
+///
+/// # Note
+///
+/// The previously installed Hook is called first, so the Panic is still printed.
+/// Since the Exception is raised from within the Hook, Panics can no longer be caught with `catch_unwind` and always end the Process.
+///
+/// Panics of Closures registered as Vectored Handlers or top-level Filters are not raised, but unwind as usual.
+/// Their Dispatch catches them and treats the Closure as continuing the Search, since raising an Exception while one is dispatched would end the Process.
+#[cfg(windows)]
+pub fn install_panic_hook(mode: PanicRaiseMode) {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        previous(info);
+        if DispatchGuard::is_dispatching() {
+            return;
+        }
+        let architecture = match Architecture::native() {
+            Some(architecture) => architecture,
+            None => return,
+        };
+        let parameters = PanicReport::from_panic_info(info).to_parameters(architecture);
+        match mode {
+            PanicRaiseMode::Exception => {
+                let arguments: Vec<usize> = parameters.iter().map(|parameter| *parameter as usize).collect();
+                RaiseException(NtStatus::from(PANIC_EXCEPTION_CODE), false, &arguments);
+            },
+            PanicRaiseMode::FailFast => {
+                let mut builder = ExceptionRecord::builder(NtStatus::from(PANIC_EXCEPTION_CODE));
+                for parameter in parameters {
+                    builder = match builder.parameter(parameter) {
+                        Ok(builder) => builder,
+                        Err(_) => return,
+                    };
+                }
+                let _ = RaiseFailFastException(Some(&builder.build()), None, true);
+            },
+        }
+    }));
+}
 
 /// Handle to a Registered Vectored Exception Handler.
 ///
@@ -28,49 +993,49 @@ use winapi::ctypes::c_void;
 /// # Note
 ///
 /// This Handle implements the Drop Trait, so that Registered Handlers get Unregistered Automatically.
-/// Since the Handle will need to be unregistered when the Handler Function goes out of Scope or is unloaded, a lifetime Parameter might be needed.
-/// As yet i do not have a concrete implementation for this though.
+/// Since the registered Closures are `'static`, they stay valid as long as they are registered.
+/// Handlers that borrow Data can be added with `scope`, which removes them before the Data goes away.
 ///
 /// The Fact that Vectored Exception Handlers are not Frame-based may warrant a restructuring of this Handler Struct
 // TODO: Check if handlers to non Frame-based Entities require special implementations
+#[cfg(windows)]
 pub struct VectoredExceptionHandler {
-    handle: PVOID
+    id: u64
 }
 
+#[cfg(windows)]
 impl VectoredExceptionHandler {
-    /// # Warning Unimplemented
-    ///
-    /// This Function is not yet implemented.
-    /// This Function will remain unimplemented until a good way to wrap FFI Callbacks is found
-    ///
-    /// # Note
-    ///
-    /// Registers a new Vectored Exception Handler
+    /// Registers a Closure as Vectored Exception Handler
     ///
     /// # Arguments
     ///
     /// * `placement_position` - Specifies, where the Handler will be placed in the Exception Handler List
-    /// * `handler` - The Handler Function that should be added
+    /// * `handler` - The Closure that should be added, it receives the Exception and decides whether the Execution continues
     ///
     /// # Return
     ///
     /// This Function returns a Result Containing a Handle to the Registered Exception Handler
-
-    // TODO: Implement this Function
-    pub fn add_vectored_exception_handler(placement_position: PlacementPosition) -> Result<VectoredExceptionHandler, ()> {
-        unimplemented!();
-        let handle = AddVectoredExceptionHandler(placement_position)?;
-        return Ok(VectoredExceptionHandler{handle});
+    ///
+    /// # Note
+    ///
+    /// The Closure is called through a Trampoline, see `HandlerRegistry` for how this affects the Order of Handlers.
+    /// Panics of the Closure are caught and treated as `HandlerDecision::ContinueSearch`.
+    pub fn add_vectored_exception_handler<F>(placement_position: PlacementPosition, handler: F) -> Result<VectoredExceptionHandler, Error>
+        where F: Fn(&mut ExceptionInfo) -> HandlerDecision + Send + Sync + 'static {
+        let id = EXCEPTION_KIND.add(placement_position, Box::new(handler))?;
+        return Ok(VectoredExceptionHandler{id});
     }
 }
 
+#[cfg(windows)]
 impl Drop for VectoredExceptionHandler {
     /// Unregisters the Vectored Exception Handler
     #[inline]
     fn drop(&mut self) {
-        // Since this Function Should only fail for a not registered handle, this expect should be safe
-        // We also cannot return a Result since Drop must succeed
-        RemoveVectoredExceptionHandler(self.handle).expect("A Registered Exception Handler could not be dropped.");
+        // The Closure is always removed from the Registry, only unregistering the Trampoline can fail.
+        // A Trampoline that stays registered dispatches to an empty Registry, which is harmless.
+        // The Closure is 'static, so Dispatches on other Threads may finish with it after the Handle is gone
+        let _ = EXCEPTION_KIND.remove(self.id, false);
     }
 }
 
@@ -85,48 +1050,693 @@ impl Drop for VectoredExceptionHandler {
 /// # Note
 ///
 /// This Handle implements the Drop Trait, so that Registered Handlers get Unregistered Automatically.
-/// Since the Handle will need to be unregistered when the Handler Function goes out of Scope or is unloaded, a lifetime Parameter might be needed.
-/// As yet i do not have a concrete implementation for this though.
+/// Since the registered Closures are `'static`, they stay valid as long as they are registered.
+/// Handlers that borrow Data can be added with `scope`, which removes them before the Data goes away.
 ///
 /// The Fact that Vectored Continue Handlers are not Frame-based may warrant a restructuring of this Handler Struct
 // TODO: Check if handlers to non Frame-based Entities require special implementations
+#[cfg(windows)]
 pub struct VectoredContinueHandler {
-    handle: PVOID
+    id: u64
 }
 
+#[cfg(windows)]
 impl VectoredContinueHandler {
-    /// # Warning Unimplemented
+    /// Registers a Closure as Vectored Continue Handler
+    ///
+    /// # Arguments
+    ///
+    /// * `placement_position` - Specifies, where the Handler will be placed in the Continue Handler List
+    /// * `handler` - The Closure that should be added, it receives the Exception and decides whether the Execution continues
     ///
-    /// This Function is not yet implemented.
-    /// This Function will remain unimplemented until a good way to wrap FFI Callbacks is found
+    /// # Return
+    ///
+    /// This Function returns a Result Containing a Handle to the Registered Continue Handler
     ///
     /// # Note
     ///
-    /// Registers a new Vectored Continue Handler
+    /// The Closure is called through a Trampoline, see `HandlerRegistry` for how this affects the Order of Handlers.
+    /// Panics of the Closure are caught and treated as `HandlerDecision::ContinueSearch`.
+    pub fn add_vectored_continue_handler<F>(placement_position: PlacementPosition, handler: F) -> Result<VectoredContinueHandler, Error>
+        where F: Fn(&mut ExceptionInfo) -> HandlerDecision + Send + Sync + 'static {
+        let id = CONTINUE_KIND.add(placement_position, Box::new(handler))?;
+        return Ok(VectoredContinueHandler{id});
+    }
+}
+
+#[cfg(windows)]
+impl Drop for VectoredContinueHandler {
+    /// Unregisters the Vectored Continue Handler
+    #[inline]
+    fn drop(&mut self) {
+        // The Closure is always removed from the Registry, only unregistering the Trampoline can fail.
+        // A Trampoline that stays registered dispatches to an empty Registry, which is harmless.
+        // The Closure is 'static, so Dispatches on other Threads may finish with it after the Handle is gone
+        let _ = CONTINUE_KIND.remove(self.id, false);
+    }
+}
+
+/// A Scope in which Vectored Handlers can borrow Data, see `scope`
+///
+/// All Handlers added to the Scope are removed before `scope` returns, so they can borrow everything that outlives the Call of `scope`.
+pub struct HandlerScope<'scope, 'env: 'scope> {
+    exception_kind: &'static HandlerKind,
+    continue_kind: &'static HandlerKind,
+    registered: Mutex<Vec<(&'static HandlerKind, u64)>>,
+    // Invariance over both Lifetimes, like std::thread::Scope, so that neither can be shortened or extended
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, 'env> HandlerScope<'scope, 'env> {
+    /// Adds a Vectored Exception Handler that borrows Data for the Rest of the Scope
     ///
     /// # Arguments
     ///
+    /// * `state` - The Data the Handler borrows, it has to outlive the Call of `scope`
     /// * `placement_position` - Specifies, where the Handler will be placed in the Exception Handler List
-    /// * `handler` - The Handler Function that should be added
+    /// * `handler` - The Closure that should be added, it receives the State and the Exception and decides whether the Execution continues
     ///
     /// # Return
     ///
-    /// This Function returns a Result Containing a Handle to the Registered Continue Handler
+    /// This Function returns an Error if the Handler could not be registered
+    pub fn add_exception_handler<S, F>(&'scope self, state: &'env S, placement_position: PlacementPosition, handler: F) -> Result<(), Error>
+        where S: Sync + ?Sized, F: Fn(&S, &mut ExceptionInfo) -> HandlerDecision + Send + Sync + 'env {
+        return self.add(self.exception_kind, placement_position, Box::new(move |info: &mut ExceptionInfo| handler(state, info)));
+    }
 
-    // TODO: Implement this Function
-    pub fn add_vectored_exception_handler(placement_position: PlacementPosition) -> Result<VectoredExceptionHandler, ()> {
-        unimplemented!();
-        let handle = AddVectoredExceptionHandler(placement_position)?;
-        return Ok(VectoredExceptionHandler{handle});
+    /// Adds a Vectored Continue Handler that borrows Data for the Rest of the Scope
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The Data the Handler borrows, it has to outlive the Call of `scope`
+    /// * `placement_position` - Specifies, where the Handler will be placed in the Continue Handler List
+    /// * `handler` - The Closure that should be added, it receives the State and the Exception and decides whether the Execution continues
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if the Handler could not be registered
+    pub fn add_continue_handler<S, F>(&'scope self, state: &'env S, placement_position: PlacementPosition, handler: F) -> Result<(), Error>
+        where S: Sync + ?Sized, F: Fn(&S, &mut ExceptionInfo) -> HandlerDecision + Send + Sync + 'env {
+        return self.add(self.continue_kind, placement_position, Box::new(move |info: &mut ExceptionInfo| handler(state, info)));
+    }
+
+    fn add(&self, kind: &'static HandlerKind, placement_position: PlacementPosition, handler: Box<dyn Fn(&mut ExceptionInfo) -> HandlerDecision + Send + Sync + 'env>) -> Result<(), Error> {
+        // The Handler is removed when the Scope is dropped, which happens before 'env ends, so it never outlives the borrowed Data
+        let handler = unsafe { mem::transmute::<Box<dyn Fn(&mut ExceptionInfo) -> HandlerDecision + Send + Sync + 'env>, Handler>(handler) };
+        let id = kind.add(placement_position, handler)?;
+        self.registered.lock().unwrap_or_else(PoisonError::into_inner).push((kind, id));
+        return Ok(());
     }
 }
 
-impl Drop for VectoredContinueHandler {
-    /// Unregisters the Vectored Continue Handler
-    #[inline]
-    fn drop(&mut self) {
-        // Since this Function Should only fail for a not registered handle, this expect should be safe
-        // We also cannot return a Result since Drop must succeed
-        RemoveVectoredContinueHandler(self.handle).expect("A Registered Continue Handler could not be dropped.");
+impl<'scope, 'env> HandlerScope<'scope, 'env> {
+    /// Removes all Handlers of the Scope, in the reverse Order of their Addition
+    fn remove_all(&self) {
+        let mut registered = self.registered.lock().unwrap_or_else(PoisonError::into_inner);
+        for (kind, id) in registered.drain(..).rev() {
+            // The Closure is always removed from the Registry, only unregistering the Trampoline can fail.
+            // A Trampoline that stays registered dispatches to an empty Registry, which is harmless.
+            // The Closure borrows Data of the Scope, so Dispatches on other Threads have to finish with it before the Scope ends
+            let _ = kind.remove(id, true);
+        }
     }
-}
\ No newline at end of file
+}
+
+/// Creates a Scope for Vectored Handlers that borrow Data, modelled on `std::thread::scope`
+///
+/// # Arguments
+///
+/// * `f` - The Function that adds Handlers to the Scope and runs while they are registered
+///
+/// # Return
+///
+/// This Function returns the Result of `f`, after all Handlers of the Scope have been removed.
+/// The Handlers are removed as well if `f` panics.
+///
+/// # Example
+///
+/// ```ignore
+/// let faults = AtomicUsize::new(0);
+/// windings::error_handling_api::scope(|s| {
+///     s.add_exception_handler(&faults, PlacementPosition::EmplaceFirst, |faults, _info| {
+///         faults.fetch_add(1, Ordering::SeqCst);
+///         return HandlerDecision::ContinueSearch;
+///     })?;
+///     return run_plugin();
+/// })
+/// ```
+#[inline]
+pub fn scope<'env, F, T>(f: F) -> T where F: for<'scope> FnOnce(&'scope HandlerScope<'scope, 'env>) -> T {
+    return scope_with(&EXCEPTION_KIND, &CONTINUE_KIND, f);
+}
+
+/// Creates a Scope for Vectored Handlers of the given Kinds, see `scope`
+pub(crate) fn scope_with<'env, F, T>(exception_kind: &'static HandlerKind, continue_kind: &'static HandlerKind, f: F) -> T
+    where F: for<'scope> FnOnce(&'scope HandlerScope<'scope, 'env>) -> T {
+    let scope = HandlerScope {
+        exception_kind,
+        continue_kind,
+        registered: Mutex::new(Vec::new()),
+        scope: PhantomData,
+        env: PhantomData,
+    };
+    // Like std::thread::scope, the Handlers are removed before a Panic of the Function is resumed
+    let result = panic::catch_unwind(AssertUnwindSafe(|| f(&scope)));
+    scope.remove_all();
+    return match result {
+        Ok(value) => value,
+        Err(payload) => panic::resume_unwind(payload),
+    };
+}
+
+/// Tests for the Registry of Vectored Handlers
+#[cfg(test)]
+mod test_handler_registry {
+    use std::cell::Cell;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::{Duration, Instant};
+    use crate::error::{ErrorKind, NtStatus};
+    use crate::safe_wrappers::error_handling_api::{DispatchGuard, HandlerDecision, HandlerRegistry, PlacementPosition};
+    use crate::type_wrappers::exception::{ContextAmd64, ExceptionInfo, ExceptionRecord};
+
+    fn info() -> ExceptionInfo {
+        return ExceptionInfo {
+            record: ExceptionRecord::builder(NtStatus::from(0xC000_0005u32)).build(),
+            context: Some(ContextAmd64::builder().rip(0x1000).build().into()),
+        };
+    }
+
+    /// Tests that the Trampoline is registered with the first and unregistered with the last Closure
+    #[test]
+    fn test_registration() {
+        let registry = HandlerRegistry::new();
+        let registered = Cell::new(0);
+        let unregistered = Cell::new(0);
+
+        let first = registry.add(PlacementPosition::EmplaceLast, Box::new(|_| HandlerDecision::ContinueSearch), || {
+            registered.set(registered.get() + 1);
+            return Ok(42);
+        }).unwrap();
+        let second = registry.add(PlacementPosition::EmplaceLast, Box::new(|_| HandlerDecision::ContinueSearch), || panic!("registered twice")).unwrap();
+        assert_eq!(registered.get(), 1);
+
+        registry.remove(first, false, |_| panic!("unregistered too early")).unwrap();
+        registry.remove(second, false, |handle| {
+            assert_eq!(handle, 42);
+            unregistered.set(unregistered.get() + 1);
+            return Ok(());
+        }).unwrap();
+        assert_eq!(unregistered.get(), 1);
+
+        let error = registry.remove(second, false, |_| Ok(())).unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::InvalidArgument(_)));
+    }
+
+    /// Tests that Closures are called in the Order of their Placement until one continues the Execution
+    #[test]
+    fn test_dispatch_order() {
+        let registry = HandlerRegistry::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        registry.add(PlacementPosition::EmplaceLast, Box::new(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            return HandlerDecision::ContinueSearch;
+        }), || Ok(1)).unwrap();
+        assert_eq!(registry.dispatch(&mut info()), HandlerDecision::ContinueSearch);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        registry.add(PlacementPosition::EmplaceFirst, Box::new(|info: &mut ExceptionInfo| {
+            if let Some(crate::type_wrappers::exception::Context::Amd64(context)) = info.context.as_mut() {
+                context.rip += 4;
+            }
+            return HandlerDecision::ContinueExecution;
+        }), || Ok(1)).unwrap();
+        let mut info = info();
+        assert_eq!(registry.dispatch(&mut info), HandlerDecision::ContinueExecution);
+        assert_eq!(info.context.unwrap().instruction_pointer(), 0x1004);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    /// Tests that panicking Closures are treated as continuing the Search
+    #[test]
+    fn test_dispatch_panic_guard() {
+        let registry = HandlerRegistry::new();
+        registry.add(PlacementPosition::EmplaceLast, Box::new(|_| panic!("handler panicked")), || Ok(1)).unwrap();
+        assert_eq!(registry.dispatch(&mut info()), HandlerDecision::ContinueSearch);
+
+        registry.add(PlacementPosition::EmplaceLast, Box::new(|_| HandlerDecision::ContinueExecution), || Ok(1)).unwrap();
+        assert_eq!(registry.dispatch(&mut info()), HandlerDecision::ContinueExecution);
+    }
+
+    /// Tests that the Thread is marked as dispatching while the Closures run, also after a Closure panicked
+    #[test]
+    fn test_dispatch_marks_thread() {
+        let registry = HandlerRegistry::new();
+        registry.add(PlacementPosition::EmplaceLast, Box::new(|_| panic!("handler panicked")), || Ok(1)).unwrap();
+        registry.add(PlacementPosition::EmplaceLast, Box::new(|_| match DispatchGuard::is_dispatching() {
+            true => HandlerDecision::ContinueExecution,
+            false => HandlerDecision::ContinueSearch,
+        }), || Ok(1)).unwrap();
+
+        assert!(!DispatchGuard::is_dispatching());
+        assert_eq!(registry.dispatch(&mut info()), HandlerDecision::ContinueExecution);
+        assert!(!DispatchGuard::is_dispatching());
+    }
+
+    /// Tests that Closures can add and remove Handlers of their own Registry, which takes Effect with the next Dispatch
+    #[test]
+    fn test_dispatch_reentrant() {
+        static REGISTRY: HandlerRegistry = HandlerRegistry::new();
+        static FIRST: AtomicU64 = AtomicU64::new(0);
+
+        let first = REGISTRY.add(PlacementPosition::EmplaceLast, Box::new(|_| {
+            REGISTRY.add(PlacementPosition::EmplaceLast, Box::new(|_| HandlerDecision::ContinueExecution), || Ok(1)).unwrap();
+            REGISTRY.remove(FIRST.load(Ordering::SeqCst), false, |_| Ok(())).unwrap();
+            return HandlerDecision::ContinueSearch;
+        }), || Ok(1)).unwrap();
+        FIRST.store(first, Ordering::SeqCst);
+
+        assert_eq!(REGISTRY.dispatch(&mut info()), HandlerDecision::ContinueSearch);
+        assert_eq!(REGISTRY.dispatch(&mut info()), HandlerDecision::ContinueExecution);
+    }
+
+    /// Tests that removing a Closure waits for its Calls on other Threads if requested
+    #[test]
+    fn test_remove_waits_for_calls() {
+        static REGISTRY: HandlerRegistry = HandlerRegistry::new();
+        static STARTED: AtomicBool = AtomicBool::new(false);
+        static FINISHED: AtomicBool = AtomicBool::new(false);
+
+        let id = REGISTRY.add(PlacementPosition::EmplaceLast, Box::new(|_| {
+            STARTED.store(true, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(50));
+            FINISHED.store(true, Ordering::SeqCst);
+            return HandlerDecision::ContinueSearch;
+        }), || Ok(1)).unwrap();
+        let dispatcher = thread::spawn(|| REGISTRY.dispatch(&mut info()));
+        while !STARTED.load(Ordering::SeqCst) {
+            thread::yield_now();
+        }
+        REGISTRY.remove(id, true, |_| Ok(())).unwrap();
+        assert!(FINISHED.load(Ordering::SeqCst));
+        assert_eq!(dispatcher.join().unwrap(), HandlerDecision::ContinueSearch);
+    }
+
+    /// Tests that removing a Closure does not wait for other running Closures and that running Dispatches skip it
+    #[test]
+    fn test_remove_skips_other_calls() {
+        static REGISTRY: HandlerRegistry = HandlerRegistry::new();
+        static STARTED: AtomicBool = AtomicBool::new(false);
+        static REMOVED: AtomicBool = AtomicBool::new(false);
+        static CALLED: AtomicBool = AtomicBool::new(false);
+
+        REGISTRY.add(PlacementPosition::EmplaceLast, Box::new(|_| {
+            STARTED.store(true, Ordering::SeqCst);
+            // Bounded, so a Removal that waits for this Closure fails the Test instead of hanging it
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while !REMOVED.load(Ordering::SeqCst) && Instant::now() < deadline {
+                thread::yield_now();
+            }
+            return HandlerDecision::ContinueSearch;
+        }), || Ok(1)).unwrap();
+        let id = REGISTRY.add(PlacementPosition::EmplaceLast, Box::new(|_| {
+            CALLED.store(true, Ordering::SeqCst);
+            return HandlerDecision::ContinueExecution;
+        }), || Ok(1)).unwrap();
+        let dispatcher = thread::spawn(|| REGISTRY.dispatch(&mut info()));
+        while !STARTED.load(Ordering::SeqCst) {
+            thread::yield_now();
+        }
+        let started = Instant::now();
+        REGISTRY.remove(id, true, |_| Ok(())).unwrap();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        REMOVED.store(true, Ordering::SeqCst);
+        assert_eq!(dispatcher.join().unwrap(), HandlerDecision::ContinueSearch);
+        assert!(!CALLED.load(Ordering::SeqCst));
+    }
+
+    /// Tests that failing Registrations leave the Registry empty
+    #[test]
+    fn test_registration_failure() {
+        let registry = HandlerRegistry::new();
+        let error = crate::error::Error::new(ErrorKind::NullPointer).with_api("AddVectoredExceptionHandler");
+        assert_eq!(registry.add(PlacementPosition::EmplaceLast, Box::new(|_| HandlerDecision::ContinueExecution), || Err(error)), Err(error));
+        assert_eq!(registry.dispatch(&mut info()), HandlerDecision::ContinueSearch);
+    }
+}
+
+/// Tests for Scopes of Vectored Handlers
+#[cfg(test)]
+mod test_handler_scope {
+    use std::panic;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use crate::error::NtStatus;
+    use crate::safe_wrappers::error_handling_api::{scope, scope_with, HandlerDecision, HandlerKind, HandlerRegistry, PlacementPosition};
+    use crate::type_wrappers::exception::{ExceptionInfo, ExceptionRecord};
+
+    static EXCEPTION_HANDLERS: HandlerRegistry = HandlerRegistry::new();
+    static CONTINUE_HANDLERS: HandlerRegistry = HandlerRegistry::new();
+    static REGISTERED: AtomicUsize = AtomicUsize::new(0);
+
+    fn register(_placement_position: PlacementPosition) -> Result<usize, crate::error::Error> {
+        REGISTERED.fetch_add(1, Ordering::SeqCst);
+        return Ok(1);
+    }
+
+    fn unregister(_handle: usize) -> Result<(), crate::error::Error> {
+        REGISTERED.fetch_sub(1, Ordering::SeqCst);
+        return Ok(());
+    }
+
+    static EXCEPTION_KIND: HandlerKind = HandlerKind { registry: &EXCEPTION_HANDLERS, register, unregister };
+    static CONTINUE_KIND: HandlerKind = HandlerKind { registry: &CONTINUE_HANDLERS, register, unregister };
+
+    fn info() -> ExceptionInfo {
+        return ExceptionInfo { record: ExceptionRecord::builder(NtStatus::from(0xC000_0005u32)).build(), context: None };
+    }
+
+    /// Tests that Handlers borrow Data of the Caller and are removed when the Scope returns or panics
+    #[test]
+    fn test_scope_borrows_and_removes() {
+        let calls = AtomicUsize::new(0);
+        let decision = scope_with(&EXCEPTION_KIND, &CONTINUE_KIND, |s| {
+            s.add_exception_handler(&calls, PlacementPosition::EmplaceFirst, |calls, _| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                return HandlerDecision::ContinueSearch;
+            }).unwrap();
+            s.add_continue_handler(&calls, PlacementPosition::EmplaceFirst, |_, _| HandlerDecision::ContinueExecution).unwrap();
+            assert_eq!(REGISTERED.load(Ordering::SeqCst), 2);
+            assert_eq!(CONTINUE_HANDLERS.dispatch(&mut info()), HandlerDecision::ContinueExecution);
+            return EXCEPTION_HANDLERS.dispatch(&mut info());
+        });
+        assert_eq!(decision, HandlerDecision::ContinueSearch);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(REGISTERED.load(Ordering::SeqCst), 0);
+        assert_eq!(EXCEPTION_HANDLERS.dispatch(&mut info()), HandlerDecision::ContinueSearch);
+
+        let result = panic::catch_unwind(|| {
+            scope_with(&EXCEPTION_KIND, &CONTINUE_KIND, |s| {
+                s.add_exception_handler(&calls, PlacementPosition::EmplaceLast, |_, _| HandlerDecision::ContinueExecution).unwrap();
+                panic!("scope panicked");
+            })
+        });
+        assert!(result.is_err());
+        assert_eq!(REGISTERED.load(Ordering::SeqCst), 0);
+        assert_eq!(EXCEPTION_HANDLERS.dispatch(&mut info()), HandlerDecision::ContinueSearch);
+    }
+
+    /// Tests that Handlers cannot be registered on Hosts without Structured Exceptions
+    #[cfg(not(windows))]
+    #[test]
+    fn test_scope_unsupported() {
+        let state = 0;
+        let result = scope(|s| s.add_exception_handler(&state, PlacementPosition::EmplaceFirst, |_, _| HandlerDecision::ContinueSearch));
+        assert_eq!(result.unwrap_err().api(), Some("AddVectoredExceptionHandler"));
+    }
+}
+
+/// Tests for the Chain of top-level Exception Filters
+#[cfg(test)]
+mod test_filter_chain {
+    use std::sync::{Arc, Mutex};
+    use crate::error::NtStatus;
+    use crate::safe_wrappers::error_handling_api::{DispatchGuard, FilterBackend, FilterChain, FilterDecision, NextFilter};
+    use crate::type_wrappers::exception::{ExceptionInfo, ExceptionRecord};
+
+    const TRAMPOLINE: usize = 0x1000;
+    const NATIVE_FILTER: usize = 0x2000;
+    const FOREIGN_FILTER: usize = 0x3000;
+
+    /// A Backend that records the installed Filter and the Calls of Filters outside of the Chain
+    struct FakeBackend {
+        installed: Mutex<usize>,
+        calls: Mutex<Vec<usize>>,
+    }
+
+    impl FilterBackend for FakeBackend {
+        fn trampoline(&self) -> usize {
+            return TRAMPOLINE;
+        }
+
+        fn set_filter(&self, filter: usize) -> usize {
+            return std::mem::replace(&mut *self.installed.lock().unwrap(), filter);
+        }
+
+        fn call_filter(&self, filter: usize, _exception: usize, _info: &ExceptionInfo) -> FilterDecision {
+            self.calls.lock().unwrap().push(filter);
+            return match filter {
+                0 => FilterDecision::ExecuteHandler,
+                _ => FilterDecision::ContinueSearch,
+            };
+        }
+    }
+
+    fn backend() -> &'static FakeBackend {
+        return Box::leak(Box::new(FakeBackend { installed: Mutex::new(NATIVE_FILTER), calls: Mutex::new(Vec::new()) }));
+    }
+
+    fn info() -> ExceptionInfo {
+        return ExceptionInfo { record: ExceptionRecord::builder(NtStatus::from(0xC000_0005u32)).build(), context: None };
+    }
+
+    /// Tests that the most recently installed Closure is called first and can forward to the previous Filters
+    #[test]
+    fn test_chaining_order() {
+        let backend = backend();
+        let chain = FilterChain::new(backend);
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let first_order = Arc::clone(&order);
+        chain.install(Arc::new(move |info: &mut ExceptionInfo, next: &NextFilter| {
+            first_order.lock().unwrap().push("first");
+            return next.forward(info);
+        }));
+        let second_order = Arc::clone(&order);
+        chain.install(Arc::new(move |info: &mut ExceptionInfo, next: &NextFilter| {
+            second_order.lock().unwrap().push("second");
+            return next.forward(info);
+        }));
+        assert_eq!(*backend.installed.lock().unwrap(), TRAMPOLINE);
+
+        assert_eq!(chain.dispatch(0, &mut info()), FilterDecision::ContinueSearch);
+        assert_eq!(*order.lock().unwrap(), vec!["second", "first"]);
+        assert_eq!(*backend.calls.lock().unwrap(), vec![NATIVE_FILTER]);
+    }
+
+    /// Tests that Closures can decide themselves, forward to the default Handling and are guarded against Panics
+    #[test]
+    fn test_decisions() {
+        let backend = backend();
+        let chain = FilterChain::new(backend);
+        chain.install(Arc::new(|_: &mut ExceptionInfo, _: &NextFilter| FilterDecision::ContinueExecution));
+        let id = chain.install(Arc::new(|info: &mut ExceptionInfo, next: &NextFilter| next.forward_to_default(info)));
+        assert_eq!(chain.dispatch(0, &mut info()), FilterDecision::ExecuteHandler);
+        assert_eq!(*backend.calls.lock().unwrap(), vec![0]);
+
+        chain.uninstall(id);
+        chain.install(Arc::new(|_: &mut ExceptionInfo, _: &NextFilter| panic!("filter panicked")));
+        assert_eq!(chain.dispatch(0, &mut info()), FilterDecision::ContinueSearch);
+    }
+
+    /// Tests that the Thread is marked as dispatching while the Closures run
+    #[test]
+    fn test_dispatch_marks_thread() {
+        let chain = FilterChain::new(backend());
+        chain.install(Arc::new(|_: &mut ExceptionInfo, _: &NextFilter| match DispatchGuard::is_dispatching() {
+            true => FilterDecision::ContinueExecution,
+            false => FilterDecision::ContinueSearch,
+        }));
+
+        assert_eq!(chain.dispatch(0, &mut info()), FilterDecision::ContinueExecution);
+        assert!(!DispatchGuard::is_dispatching());
+    }
+
+    /// Tests that the previous Filter is restored when the last Closure is uninstalled, in any Order
+    #[test]
+    fn test_restore_on_uninstall() {
+        let backend = backend();
+        let chain = FilterChain::new(backend);
+        let first = chain.install(Arc::new(|_: &mut ExceptionInfo, _: &NextFilter| FilterDecision::ContinueSearch));
+        let second = chain.install(Arc::new(|_: &mut ExceptionInfo, _: &NextFilter| FilterDecision::ContinueSearch));
+
+        chain.uninstall(first);
+        assert_eq!(*backend.installed.lock().unwrap(), TRAMPOLINE);
+        chain.uninstall(second);
+        assert_eq!(*backend.installed.lock().unwrap(), NATIVE_FILTER);
+
+        // Uninstalling twice does not touch the installed Filter
+        chain.uninstall(second);
+        assert_eq!(*backend.installed.lock().unwrap(), NATIVE_FILTER);
+    }
+
+    /// Tests that Filters installed on top of the Trampoline by others are not replaced
+    #[test]
+    fn test_foreign_filter_kept() {
+        let backend = backend();
+        let chain = FilterChain::new(backend);
+        let id = chain.install(Arc::new(|_: &mut ExceptionInfo, _: &NextFilter| FilterDecision::ExecuteHandler));
+        backend.set_filter(FOREIGN_FILTER);
+
+        chain.uninstall(id);
+        assert_eq!(*backend.installed.lock().unwrap(), FOREIGN_FILTER);
+        // The Trampoline, which the foreign Filter may call, forwards to the previous Filter
+        assert_eq!(chain.dispatch(0, &mut info()), FilterDecision::ContinueSearch);
+        assert_eq!(*backend.calls.lock().unwrap(), vec![NATIVE_FILTER]);
+    }
+}
+
+/// Tests for restoring Error Modes with a fake Backend
+#[cfg(test)]
+mod test_mode_guard {
+    use crate::safe_wrappers::error_handling_api::{ErrorModeBackend, ModeGuard, ERROR_MODE};
+    use crate::error::{Error, ErrorKind, Win32Error};
+    use std::cell::Cell;
+    use std::panic::{self, AssertUnwindSafe};
+
+    struct FakeBackend<'a> {
+        mode: &'a Cell<u32>,
+        fail: bool,
+    }
+
+    impl<'a> ErrorModeBackend for FakeBackend<'a> {
+        type Mode = ERROR_MODE;
+
+        fn get(&self) -> u32 {
+            return self.mode.get();
+        }
+
+        fn set(&self, bits: u32) -> Result<u32, Error> {
+            if self.fail {
+                return Err(Error::new(ErrorKind::Win32(Win32Error(87))).with_api("SetThreadErrorMode"));
+            }
+            return Ok(self.mode.replace(bits));
+        }
+
+        fn to_bits(mode: ERROR_MODE) -> u32 {
+            return mode.bits();
+        }
+
+        fn from_bits_truncate(bits: u32) -> ERROR_MODE {
+            return ERROR_MODE::from_bits_truncate(bits);
+        }
+    }
+
+    /// Tests that nested Guards restore the previous Modes in reverse Order
+    #[test]
+    fn test_restore_nested() {
+        let mode = Cell::new(ERROR_MODE::FAILCRITICALERRORS.bits());
+        {
+            let outer = ModeGuard::update(FakeBackend { mode: &mode, fail: false }, |mode| mode | ERROR_MODE::NOOPENFILEERRORBOX).unwrap();
+            assert_eq!(outer.previous(), ERROR_MODE::FAILCRITICALERRORS);
+            assert_eq!(mode.get(), (ERROR_MODE::FAILCRITICALERRORS | ERROR_MODE::NOOPENFILEERRORBOX).bits());
+            {
+                let _inner = ModeGuard::set(FakeBackend { mode: &mode, fail: false }, ERROR_MODE::empty()).unwrap();
+                assert_eq!(mode.get(), 0);
+            }
+            assert_eq!(mode.get(), (ERROR_MODE::FAILCRITICALERRORS | ERROR_MODE::NOOPENFILEERRORBOX).bits());
+        }
+        assert_eq!(mode.get(), ERROR_MODE::FAILCRITICALERRORS.bits());
+    }
+
+    /// Tests that Bits unknown to the Bitflags are kept by Updates and restored
+    #[test]
+    fn test_restore_unknown_bits() {
+        const UNKNOWN: u32 = 0x0001_0000;
+        let mode = Cell::new(ERROR_MODE::FAILCRITICALERRORS.bits() | UNKNOWN);
+        {
+            let guard = ModeGuard::update(FakeBackend { mode: &mode, fail: false }, |mode| mode - ERROR_MODE::FAILCRITICALERRORS).unwrap();
+            assert_eq!(guard.previous(), ERROR_MODE::FAILCRITICALERRORS);
+            assert_eq!(mode.get(), UNKNOWN);
+            {
+                let _inner = ModeGuard::set(FakeBackend { mode: &mode, fail: false }, ERROR_MODE::NOGPFAULTERRORBOX).unwrap();
+                assert_eq!(mode.get(), ERROR_MODE::NOGPFAULTERRORBOX.bits());
+            }
+            assert_eq!(mode.get(), UNKNOWN);
+        }
+        assert_eq!(mode.get(), ERROR_MODE::FAILCRITICALERRORS.bits() | UNKNOWN);
+    }
+
+    /// Tests that the previous Mode is restored while unwinding from a Panic
+    #[test]
+    fn test_restore_on_panic() {
+        let mode = Cell::new(0);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _guard = ModeGuard::set(FakeBackend { mode: &mode, fail: false }, ERROR_MODE::NOGPFAULTERRORBOX).unwrap();
+            assert_eq!(mode.get(), ERROR_MODE::NOGPFAULTERRORBOX.bits());
+            panic!("probing failed");
+        }));
+        assert!(result.is_err());
+        assert_eq!(mode.get(), 0);
+    }
+
+    /// Tests that failing to set the Mode returns the Error and leaves the Mode unchanged
+    #[test]
+    fn test_set_failure() {
+        let mode = Cell::new(ERROR_MODE::FAILCRITICALERRORS.bits());
+        let error = ModeGuard::set(FakeBackend { mode: &mode, fail: true }, ERROR_MODE::empty()).err().unwrap();
+        assert_eq!(error.api(), Some("SetThreadErrorMode"));
+        assert_eq!(mode.get(), ERROR_MODE::FAILCRITICALERRORS.bits());
+    }
+
+    /// Tests that the Thread Error Mode Guard restores the Mode of the Thread
+    #[cfg(windows)]
+    #[test]
+    fn test_thread_error_mode_guard() {
+        use crate::safe_wrappers::error_handling_api::{GetThreadErrorMode, ThreadErrorModeGuard, THREAD_ERROR_MODE};
+
+        let before = GetThreadErrorMode();
+        {
+            let guard = ThreadErrorModeGuard::insert(THREAD_ERROR_MODE::NOOPENFILEERRORBOX).unwrap();
+            assert_eq!(guard.previous(), before);
+            assert!(GetThreadErrorMode().contains(THREAD_ERROR_MODE::NOOPENFILEERRORBOX));
+        }
+        assert_eq!(GetThreadErrorMode(), before);
+    }
+}
+
+/// Tests for Handlers of Custom Exceptions
+#[cfg(test)]
+mod test_custom_exception_handler {
+    use crate::safe_wrappers::error_handling_api::{custom_exception_handler, CustomException, ExceptionInfo, HandlerDecision};
+    use crate::error::NtStatus;
+    use crate::type_wrappers::exception::ExceptionRecord;
+
+    struct Retry {
+        attempts: u64,
+    }
+
+    impl CustomException for Retry {
+        const CODE: u32 = 0xE000_0007;
+
+        fn to_parameters(&self) -> Vec<u64> {
+            return vec![self.attempts];
+        }
+
+        fn from_parameters(parameters: &[u64]) -> Option<Retry> {
+            return parameters.first().map(|attempts| Retry { attempts: *attempts });
+        }
+    }
+
+    /// Tests that the Closure only receives Exceptions of its Type
+    #[test]
+    fn test_downcast_in_handler() {
+        let handler = custom_exception_handler(|retry: Retry, _info: &mut ExceptionInfo| {
+            return match retry.attempts < 3 {
+                true => HandlerDecision::ContinueExecution,
+                false => HandlerDecision::ContinueSearch,
+            };
+        });
+
+        let mut info = ExceptionInfo { record: ExceptionRecord::builder_from_custom(&Retry { attempts: 1 }).unwrap().build(), context: None };
+        assert_eq!(handler(&mut info), HandlerDecision::ContinueExecution);
+
+        info.record = ExceptionRecord::builder_from_custom(&Retry { attempts: 5 }).unwrap().build();
+        assert_eq!(handler(&mut info), HandlerDecision::ContinueSearch);
+
+        info.record = ExceptionRecord::builder(NtStatus::from(0xC000_0005u32)).parameter(1).unwrap().build();
+        assert_eq!(handler(&mut info), HandlerDecision::ContinueSearch);
+    }
+}
@@ -1,5 +1,10 @@
+#[cfg(all(windows, feature = "dxgi"))]
 pub mod dxgi;
+#[cfg(all(windows, feature = "unknown"))]
 pub mod unknown;
 
 /// Safe Wrappers for the errhandlingapi.h Header
 pub mod error_handling_api;
+
+/// Safe Wrappers for the werapi.h Header
+pub mod wer_api;
@@ -1,6 +1,7 @@
-#![cfg(feature = "unknown")]
 #![allow(non_snake_case, dead_code)]
 use winapi::shared::winerror::HRESULT;
+use crate::error::{Error, ErrorKind};
+use crate::type_wrappers::type_conversion::success::{check, HResultPolicy};
 use winapi::_core::ptr::{null_mut};
 
 pub trait COMContainer<I: winapi::Interface> {
@@ -11,12 +12,6 @@ pub struct IUnknownContainer {
     pub (crate) interface: *mut winapi::um::unknwnbase::IUnknown,
 }
 
-pub enum QueryInterfaceError {
-    NoInterface,
-    Nullptr,
-    UnknownResult(HRESULT),
-}
-
 impl IUnknownContainer {
     // I Don't know if AddRef and Release should be pub because they are needed to use the api, until i get such information, those will remain private
 
@@ -34,10 +29,9 @@ impl IUnknownContainer {
         }
     }
 
-    pub fn QueryInterface<InterfaceType: winapi::Interface, ContainerType: COMContainer<InterfaceType>>(&self) -> Result<ContainerType, QueryInterfaceError> {
+    pub fn QueryInterface<InterfaceType: winapi::Interface, ContainerType: COMContainer<InterfaceType>>(&self) -> Result<ContainerType, Error> {
         use winapi::ctypes::c_void;
         use winapi::shared::guiddef::{GUID,IID,REFIID};
-        use winapi::shared::winerror::{S_OK, E_NOINTERFACE,E_POINTER};
 
         let guid: GUID = InterfaceType::uuidof();
         let iid: IID = IID::from(guid);
@@ -52,19 +46,11 @@ impl IUnknownContainer {
             result = reference.QueryInterface(riid, pp_interface);
         }
 
-        match result {
-            S_OK => {},
-            E_NOINTERFACE => return Err(QueryInterfaceError::NoInterface),
-            E_POINTER => panic!("We passed a Nullptr, this shouldn't happen"),
-            _ => return Err(QueryInterfaceError::UnknownResult(result))
-        };
-
-        if p_interface == null_mut() {
-            return Err(QueryInterfaceError::Nullptr);
-        }
+        // An unsupported Interface is reported as E_NOINTERFACE
+        check::<HResultPolicy, _>("QueryInterface", result)?;
 
         let p_interface: *mut InterfaceType = p_interface as *mut InterfaceType;
-        return Ok(ContainerType::from_ptr(p_interface).unwrap());
+        return ContainerType::from_ptr(p_interface).ok_or_else(|| Error::new(ErrorKind::NullPointer).with_api("QueryInterface"));
     }
 }
 
@@ -0,0 +1,340 @@
+// Re-Exports of Types that are available on every Host
+pub use crate::type_wrappers::error_reporting::wer_api::{FaultReportingFlags, FileFlags, REGISTER_FILE_TYPE, WER_MAX_MEM_BLOCK_SIZE, WER_MAX_REGISTERED_ENTRIES};
+
+// Re-Exports of Functions that only required Type Conversions
+#[cfg(windows)]
+pub use crate::type_wrappers::error_reporting::wer_api::{
+    WerAddExcludedApplication,
+    WerGetFlags,
+    WerRegisterFile,
+    WerRemoveExcludedApplication,
+    WerSetFlags,
+    WerUnregisterFile,
+};
+
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::success::{check, HResultPolicy};
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::win_types::{DWORD, PVOID};
+use std::marker::PhantomData;
+use std::sync::{Mutex, PoisonError};
+use crate::error::{Error, ErrorKind, HResult, Win32Error};
+
+// Value of the winerror.h Header, which WER returns when too many Blocks are registered
+const ERROR_INSUFFICIENT_BUFFER: u32 = 122;
+
+/// The Functions that register Memory Blocks with Windows Error Reporting
+pub(crate) trait MemoryBlockBackend: Sync {
+    /// Registers the Memory Block at an Address
+    fn register(&self, address: usize, size: usize) -> Result<(), Error>;
+
+    /// Unregisters the Memory Block at an Address
+    fn unregister(&self, address: usize) -> Result<(), Error>;
+}
+
+/// Registry of the Memory Blocks registered through `WerMemoryRegistration`
+///
+/// The Registry checks the Limits of WER before calling it and rejects Blocks whose Address is already registered,
+/// since WER identifies Blocks by their Address only.
+///
+/// # Note
+///
+/// Blocks registered with `WerRegisterMemoryBlock` directly are not counted, WER then reports the exceeded Limit itself.
+#[cfg_attr(not(windows), allow(dead_code))]
+pub(crate) struct BlockRegistry {
+    backend: &'static dyn MemoryBlockBackend,
+    // The Addresses of the registered Blocks
+    blocks: Mutex<Vec<usize>>,
+}
+
+#[cfg_attr(not(windows), allow(dead_code))]
+impl BlockRegistry {
+    /// Creates an empty Registry
+    pub(crate) const fn new(backend: &'static dyn MemoryBlockBackend) -> BlockRegistry {
+        return BlockRegistry { backend, blocks: Mutex::new(Vec::new()) };
+    }
+
+    /// Registers a Memory Block
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Block is empty, larger than `WER_MAX_MEM_BLOCK_SIZE` or already registered.
+    /// It returns `HRESULT_FROM_WIN32(ERROR_INSUFFICIENT_BUFFER)` like WER, if `WER_MAX_REGISTERED_ENTRIES` Blocks are already registered.
+    pub(crate) fn register(&self, address: usize, size: usize) -> Result<(), Error> {
+        if size == 0 {
+            return Err(Error::new(ErrorKind::InvalidArgument("the memory block is empty")).with_api("WerRegisterMemoryBlock"));
+        }
+        if size > WER_MAX_MEM_BLOCK_SIZE {
+            return Err(Error::new(ErrorKind::InvalidArgument("the memory block is larger than WER_MAX_MEM_BLOCK_SIZE")).with_api("WerRegisterMemoryBlock"));
+        }
+        // The Lock is held while calling WER, so the Count cannot change in between
+        let mut blocks = self.blocks.lock().unwrap_or_else(PoisonError::into_inner);
+        if blocks.contains(&address) {
+            return Err(Error::new(ErrorKind::InvalidArgument("the memory block is already registered")).with_api("WerRegisterMemoryBlock"));
+        }
+        if blocks.len() >= WER_MAX_REGISTERED_ENTRIES {
+            return Err(Error::from(HResult::from(Win32Error(ERROR_INSUFFICIENT_BUFFER))).with_api("WerRegisterMemoryBlock"));
+        }
+        self.backend.register(address, size)?;
+        blocks.push(address);
+        return Ok(());
+    }
+
+    /// Unregisters a Memory Block
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if no Block is registered at the Address.
+    /// The Block is removed from the Registry even if WER fails to unregister it, since WER does not know it anymore in that Case.
+    pub(crate) fn unregister(&self, address: usize) -> Result<(), Error> {
+        let mut blocks = self.blocks.lock().unwrap_or_else(PoisonError::into_inner);
+        let position = match blocks.iter().position(|block| *block == address) {
+            Some(position) => position,
+            None => return Err(Error::new(ErrorKind::InvalidArgument("the memory block is not registered")).with_api("WerUnregisterMemoryBlock")),
+        };
+        blocks.swap_remove(position);
+        return self.backend.unregister(address);
+    }
+
+    /// Returns the Number of registered Blocks
+    pub(crate) fn len(&self) -> usize {
+        return self.blocks.lock().unwrap_or_else(PoisonError::into_inner).len();
+    }
+}
+
+/// The Backend calling the werapi.h Functions
+#[cfg(windows)]
+struct WindowsBlockBackend;
+
+#[cfg(windows)]
+impl MemoryBlockBackend for WindowsBlockBackend {
+    fn register(&self, address: usize, size: usize) -> Result<(), Error> {
+        let result: i32;
+        unsafe {
+            result = winapi::um::werapi::WerRegisterMemoryBlock(address as PVOID, size as DWORD);
+        }
+        return check::<HResultPolicy, _>("WerRegisterMemoryBlock", result);
+    }
+
+    fn unregister(&self, address: usize) -> Result<(), Error> {
+        let result: i32;
+        unsafe {
+            result = winapi::um::werapi::WerUnregisterMemoryBlock(address as PVOID);
+        }
+        return check::<HResultPolicy, _>("WerUnregisterMemoryBlock", result);
+    }
+}
+
+/// The Memory Blocks registered by this Process
+#[cfg(windows)]
+static MEMORY_BLOCKS: BlockRegistry = BlockRegistry::new(&WindowsBlockBackend);
+
+/// Guard of a Memory Block registered for Collection by Windows Error Reporting, which unregisters the Block when dropped
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/werapi/nf-werapi-werregistermemoryblock)
+///
+/// The Guard borrows the registered Data, so it cannot be moved or freed while WER may still read it.
+/// Reports contain the Contents of the Block at the Time of the Crash, Data that changes while registered needs Interior Mutability like Atomics or Cells.
+///
+/// # Example
+///
+/// ```ignore
+/// let breadcrumbs: Vec<u64> = collect_breadcrumbs();
+/// // Vecs are registered through their Slice, the borrow keeps them from reallocating
+/// let _registration = WerMemoryRegistration::register_slice(&breadcrumbs)?;
+/// ```
+///
+/// # Note
+///
+/// A Process can register at most `WER_MAX_REGISTERED_ENTRIES` Blocks of at most `WER_MAX_MEM_BLOCK_SIZE` Bytes each.
+/// Blocks are identified by their Address, so two Guards cannot register the same Address.
+pub struct WerMemoryRegistration<'a> {
+    registry: &'static BlockRegistry,
+    address: usize,
+    size: usize,
+    _data: PhantomData<&'a [u8]>,
+}
+
+impl<'a> WerMemoryRegistration<'a> {
+    /// Registers a Block in a Registry, the Caller has to make sure that the Block stays valid for `'a`
+    #[cfg_attr(not(windows), allow(dead_code))]
+    pub(crate) fn register_in(registry: &'static BlockRegistry, address: usize, size: usize) -> Result<WerMemoryRegistration<'a>, Error> {
+        registry.register(address, size)?;
+        return Ok(WerMemoryRegistration { registry, address, size, _data: PhantomData });
+    }
+
+    /// Registers the Memory of a Value
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The Value whose Bytes should be included in Reports
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Value is zero-sized, larger than `WER_MAX_MEM_BLOCK_SIZE` or already registered.
+    /// Otherwise it returns the Error of WerRegisterMemoryBlock, which is `HRESULT_FROM_WIN32(ERROR_INSUFFICIENT_BUFFER)` if too many Blocks are registered.
+    #[cfg(windows)]
+    #[inline]
+    pub fn register<T>(value: &'a T) -> Result<WerMemoryRegistration<'a>, Error> {
+        return WerMemoryRegistration::register_in(&MEMORY_BLOCKS, value as *const T as usize, std::mem::size_of::<T>());
+    }
+
+    /// Registers the Memory of a Slice, for example the Contents of a Vec
+    ///
+    /// # Arguments
+    ///
+    /// * `slice` - The Slice whose Elements should be included in Reports
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Slice is empty, larger than `WER_MAX_MEM_BLOCK_SIZE` or already registered.
+    /// Otherwise it returns the Error of WerRegisterMemoryBlock, which is `HRESULT_FROM_WIN32(ERROR_INSUFFICIENT_BUFFER)` if too many Blocks are registered.
+    #[cfg(windows)]
+    #[inline]
+    pub fn register_slice<T>(slice: &'a [T]) -> Result<WerMemoryRegistration<'a>, Error> {
+        return WerMemoryRegistration::register_in(&MEMORY_BLOCKS, slice.as_ptr() as usize, std::mem::size_of_val(slice));
+    }
+
+    /// Registers a raw Byte Range, for example Memory that is not owned by Rust
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The first Byte of the Range
+    /// * `size` - The Number of Bytes in the Range
+    ///
+    /// # Return
+    ///
+    /// This Function returns the same Errors as `register_slice`
+    ///
+    /// # Safety
+    ///
+    /// The Range must stay readable until the Guard is dropped
+    #[cfg(windows)]
+    #[inline]
+    pub unsafe fn register_raw(address: *const u8, size: usize) -> Result<WerMemoryRegistration<'static>, Error> {
+        return WerMemoryRegistration::register_in(&MEMORY_BLOCKS, address as usize, size);
+    }
+
+    /// Returns the Address of the registered Block
+    #[inline]
+    pub fn address(&self) -> usize {
+        return self.address;
+    }
+
+    /// Returns the Size of the registered Block in Bytes
+    #[inline]
+    pub fn size(&self) -> usize {
+        return self.size;
+    }
+}
+
+impl<'a> Drop for WerMemoryRegistration<'a> {
+    /// Unregisters the Block
+    fn drop(&mut self) {
+        // Failing to unregister only means that WER no longer knows the Block
+        let _ = self.registry.unregister(self.address);
+    }
+}
+
+/// Tests for the Limits and Registrations of Memory Blocks with a fake Backend
+#[cfg(test)]
+mod test_block_registry {
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use crate::error::{Error, ErrorKind, HResult};
+    use crate::safe_wrappers::wer_api::*;
+
+    /// Records the Calls to WER
+    struct FakeBackend {
+        calls: Mutex<Vec<(&'static str, usize)>>,
+        fail: AtomicBool,
+    }
+
+    impl FakeBackend {
+        const fn new() -> FakeBackend {
+            return FakeBackend { calls: Mutex::new(Vec::new()), fail: AtomicBool::new(false) };
+        }
+
+        fn calls(&self) -> Vec<(&'static str, usize)> {
+            return self.calls.lock().unwrap().clone();
+        }
+    }
+
+    impl MemoryBlockBackend for FakeBackend {
+        fn register(&self, address: usize, _size: usize) -> Result<(), Error> {
+            if self.fail.load(Ordering::SeqCst) {
+                return Err(Error::from(HResult(0x8004_1001u32 as i32)).with_api("WerRegisterMemoryBlock"));
+            }
+            self.calls.lock().unwrap().push(("register", address));
+            return Ok(());
+        }
+
+        fn unregister(&self, address: usize) -> Result<(), Error> {
+            self.calls.lock().unwrap().push(("unregister", address));
+            return Ok(());
+        }
+    }
+
+    /// Tests that the Contents of a Vec are unregistered when the Guard is dropped
+    #[test]
+    fn test_unregister_on_drop() {
+        static BACKEND: FakeBackend = FakeBackend::new();
+        static REGISTRY: BlockRegistry = BlockRegistry::new(&BACKEND);
+
+        let breadcrumbs: Vec<u64> = vec![1, 2, 3];
+        let slice: &[u64] = &breadcrumbs;
+        {
+            let registration = WerMemoryRegistration::register_in(&REGISTRY, slice.as_ptr() as usize, std::mem::size_of_val(slice)).unwrap();
+            assert_eq!(registration.size(), 24);
+            assert_eq!(REGISTRY.len(), 1);
+        }
+        assert_eq!(REGISTRY.len(), 0);
+        let address = breadcrumbs.as_ptr() as usize;
+        assert_eq!(BACKEND.calls(), vec![("register", address), ("unregister", address)]);
+    }
+
+    /// Tests that empty Blocks, Blocks larger than WER_MAX_MEM_BLOCK_SIZE and registered Addresses are rejected before calling WER
+    #[test]
+    fn test_block_size_limits() {
+        static BACKEND: FakeBackend = FakeBackend::new();
+        static REGISTRY: BlockRegistry = BlockRegistry::new(&BACKEND);
+
+        let empty = WerMemoryRegistration::register_in(&REGISTRY, 0x1000, 0).err().unwrap();
+        assert_eq!(empty.kind(), ErrorKind::InvalidArgument("the memory block is empty"));
+        assert!(WerMemoryRegistration::register_in(&REGISTRY, 0x1000, WER_MAX_MEM_BLOCK_SIZE + 1).is_err());
+        assert!(BACKEND.calls().is_empty());
+
+        let _largest = WerMemoryRegistration::register_in(&REGISTRY, 0x1000, WER_MAX_MEM_BLOCK_SIZE).unwrap();
+        let duplicate = WerMemoryRegistration::register_in(&REGISTRY, 0x1000, 8).err().unwrap();
+        assert_eq!(duplicate.kind(), ErrorKind::InvalidArgument("the memory block is already registered"));
+        assert_eq!(REGISTRY.len(), 1);
+    }
+
+    /// Tests that no more than WER_MAX_REGISTERED_ENTRIES Blocks are registered at once
+    #[test]
+    fn test_entry_limit() {
+        static BACKEND: FakeBackend = FakeBackend::new();
+        static REGISTRY: BlockRegistry = BlockRegistry::new(&BACKEND);
+
+        let mut registrations: Vec<WerMemoryRegistration> = (0..WER_MAX_REGISTERED_ENTRIES)
+            .map(|index| WerMemoryRegistration::register_in(&REGISTRY, 0x1000 + index * 8, 8).unwrap())
+            .collect();
+        let exceeded = WerMemoryRegistration::register_in(&REGISTRY, 0x10_0000, 8).err().unwrap();
+        assert_eq!(exceeded.kind(), ErrorKind::HResult(HResult(0x8007_007Au32 as i32)));
+        assert_eq!(exceeded.api(), Some("WerRegisterMemoryBlock"));
+
+        registrations.pop();
+        assert!(WerMemoryRegistration::register_in(&REGISTRY, 0x10_0000, 8).is_ok());
+    }
+
+    /// Tests that Blocks that WER failed to register are not counted
+    #[test]
+    fn test_backend_failure() {
+        static BACKEND: FakeBackend = FakeBackend::new();
+        static REGISTRY: BlockRegistry = BlockRegistry::new(&BACKEND);
+
+        BACKEND.fail.store(true, Ordering::SeqCst);
+        assert!(WerMemoryRegistration::register_in(&REGISTRY, 0x1000, 8).is_err());
+        assert_eq!(REGISTRY.len(), 0);
+        assert!(REGISTRY.unregister(0x1000).is_err());
+    }
+}
@@ -5,4 +5,5 @@
 //! Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/_recovery/)
 
 /// Type Wrappers for the Parts of Application Recovery and Restart included in the winbase.h Header
+#[cfg(windows)]
 pub mod winbase;
\ No newline at end of file
@@ -8,37 +8,20 @@
 //! Link to the Header Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/_recovery/)
 
 use winapi::shared::minwindef::BOOL;
-use bitflags::_core::mem::MaybeUninit;
-use crate::type_wrappers::type_conversion::convert_c_bool;
-
-const S_OK: i32 = 0;
+use windings_macros::ffi_wrap;
+use crate::error::Error;
 
 // This Function is already Safe
 // TODO: Document this
-wrap_noreturn_ffi_function!(
-    /// Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-applicationrecoveryfinished)
-    pub fn ApplicationRecoveryFinished(recovery_success: bool)
-    FFI fn winapi::um::winbase::ApplicationRecoveryFinished;
-    FFI args(recovery_success_ffi);
-
-    IN:
-        AUTO recovery_success => recovery_success_ffi: bool => BOOL
-);
+/// Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-applicationrecoveryfinished)
+#[ffi_wrap(winapi::um::winbase::ApplicationRecoveryFinished)]
+pub fn ApplicationRecoveryFinished(#[ffi(BOOL)] recovery_success: bool);
 
 // This Function is already Safe
 // TODO: Document this
-wrap_ffi_function!(
-    /// Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-applicationrecoveryinprogress)
-    pub fn ApplicationRecoveryInProgress()
-    FFI fn winapi::um::winbase::ApplicationRecoveryInProgress;
-    FFI args(cancelled) -> i32;
-
-    OUT:
-        AUTO cancelled_uninit, cancelled: BOOL => bool
-
-    RETURN:
-        OK VALUE: S_OK
-);
+/// Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-applicationrecoveryinprogress)
+#[ffi_wrap(winapi::um::winbase::ApplicationRecoveryInProgress, success = hresult)]
+pub fn ApplicationRecoveryInProgress(#[out(BOOL)] cancelled: bool) -> Result<bool, Error>;
 
 /// Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getapplicationrecoverycallback)
 // TODO: Document this
@@ -55,12 +38,6 @@ pub fn RegisterApplicationRecoveryCallback() {
 }
 
 // TODO: Document this
-wrap_ffi_function!(
-    /// Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-unregisterapplicationrecoverycallback)
-    pub fn UnregisterApplicationRecoveryCallback()
-    FFI fn winapi::um::winbase::UnregisterApplicationRecoveryCallback;
-    FFI args() -> i32;
-
-    RETURN:
-        OK VALUE: S_OK
-);
\ No newline at end of file
+/// Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-unregisterapplicationrecoverycallback)
+#[ffi_wrap(winapi::um::winbase::UnregisterApplicationRecoveryCallback, success = hresult)]
+pub fn UnregisterApplicationRecoveryCallback() -> Result<(), Error>;
\ No newline at end of file
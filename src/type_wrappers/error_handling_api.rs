@@ -1,36 +1,53 @@
-#![allow(non_snake_case, dead_code)]
-
-use std::ffi::{CString, OsStr, OsString};
-use crate::type_wrappers::type_conversion::{convert_c_to_os_wide_string, convert_c_bool};
-use std::convert::{TryInto, TryFrom};
-use bitflags::*;
-use std::error::Error;
-use bitflags::_core::mem::MaybeUninit;
-use winapi::shared::minwindef::{BOOL, DWORD, ULONG};
-use winapi::um::winnt::PVOID;
-use std::panic::resume_unwind;
-
-
-bitflags!(
+#![allow(non_snake_case, non_camel_case_types, dead_code)]
+
+#[cfg(windows)]
+use std::ffi::CStr;
+#[cfg(windows)]
+use std::mem::MaybeUninit;
+#[cfg(windows)]
+use crate::error::{Error, ErrorKind, NtStatus};
+#[cfg(windows)]
+use std::ptr::null_mut;
+#[cfg(windows)]
+use crate::type_wrappers::exception::{Context, ExceptionRecord};
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::code_page::CodePage;
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::wide_string::WideCStr;
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::win32_str::{Win32Str, call_with_win32_str};
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::success::{check, BoolPolicy};
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::win_types::{BOOL, DWORD, PVOID};
+use crate::type_wrappers::type_conversion::win_types::{LONG, UINT};
+
+// Values of the winbase.h Header, defined here so that the Bitflags are available on every Host
+const SEM_FAILCRITICALERRORS: UINT = 0x0001;
+const SEM_NOGPFAULTERRORBOX: UINT = 0x0002;
+const SEM_NOALIGNMENTFAULTEXCEPT: UINT = 0x0004;
+const SEM_NOOPENFILEERRORBOX: UINT = 0x8000;
+
+NAMED_BITFLAGS!(
     /// Process Error Mode Bitflags
-    pub struct ERROR_MODE : winapi::shared::minwindef::UINT {
-        const FAILCRITICALERRORS = winapi::um::winbase::SEM_FAILCRITICALERRORS;
-        const NOALIGNMENTFAULTEXCEPT = winapi::um::winbase::SEM_NOALIGNMENTFAULTEXCEPT;
-        const NOGPFAULTERRORBOX = winapi::um::winbase::SEM_NOGPFAULTERRORBOX;
-        const NOOPENFILEERRORBOX = winapi::um::winbase::SEM_NOOPENFILEERRORBOX;
+    pub struct ERROR_MODE : UINT {
+        const FAILCRITICALERRORS = SEM_FAILCRITICALERRORS;
+        const NOALIGNMENTFAULTEXCEPT = SEM_NOALIGNMENTFAULTEXCEPT;
+        const NOGPFAULTERRORBOX = SEM_NOGPFAULTERRORBOX;
+        const NOOPENFILEERRORBOX = SEM_NOOPENFILEERRORBOX;
 });
 
 
-bitflags!(
+NAMED_BITFLAGS!(
     /// Thread Error Mode Bitflags
     ///
     /// # Note
     ///
     /// ERROR_MODE and THREAD_ERROR_MODE are the same Bitflags in principle but since THREAD_ERROR_MODE does not support NOALIGNMENTFAULTEXCEPT this had to be made into separate Bitflags
-    pub struct THREAD_ERROR_MODE : winapi::shared::minwindef::UINT {
-        const FAILCRITICALERRORS = winapi::um::winbase::SEM_FAILCRITICALERRORS;
-        const NOGPFAULTERRORBOX = winapi::um::winbase::SEM_NOGPFAULTERRORBOX;
-        const NOOPENFILEERRORBOX = winapi::um::winbase::SEM_NOOPENFILEERRORBOX;
+    pub struct THREAD_ERROR_MODE : UINT {
+        const FAILCRITICALERRORS = SEM_FAILCRITICALERRORS;
+        const NOGPFAULTERRORBOX = SEM_NOGPFAULTERRORBOX;
+        const NOOPENFILEERRORBOX = SEM_NOOPENFILEERRORBOX;
 });
 
 /// Wrapping Function for the GetLastError Function
@@ -40,6 +57,7 @@ bitflags!(
 ///
 /// This Function returns the calling Threads last Error Code
 // This Function is already Safe
+#[cfg(windows)]
 #[inline]
 pub fn GetLastError() -> u32 {
     let value: u32;
@@ -58,6 +76,7 @@ pub fn GetLastError() -> u32 {
 ///
 /// * `error_code` - The Error Code that should be set
 // This Function is already Safe
+#[cfg(windows)]
 #[inline]
 pub fn SetLastError(error_code: u32) {
     unsafe {
@@ -73,38 +92,85 @@ pub fn SetLastError(error_code: u32) {
 /// # Arguments
 ///
 /// * `message_text` - The Text that should be displayed inside the message box
+///
+/// # Note
+///
+/// The Text is interpreted in the active ANSI Code Page, not as UTF-8, see `fatal_app_exit_ansi` for Rust Strings.
 // This Function is already Safe
+#[cfg(windows)]
 #[inline]
-pub fn FatalAppExitA(message_text: CString) {
+pub fn FatalAppExitA(message_text: &CStr) {
     unsafe {
         winapi::um::errhandlingapi::FatalAppExitA(0,message_text.as_ptr());
     }
 }
 
-/// Wrapping Function for the FatalAppExitW Function
-/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-fatalappexitw)
+/// Wrapping Function for the FatalAppExitA Function, that encodes the Text into a Code Page
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-fatalappexita)
 ///
 /// This Function Displays an Error Message Box and terminates the Application when the Message box is closed
 ///
 /// # Arguments
 ///
+/// * `code_page` - The Code Page of the Text, which should be the active ANSI Code Page
 /// * `message_text` - The Text that should be displayed inside the message box
 ///
-/// # Note
+/// # Return
 ///
-/// Due to the Fact that OSStrings can contain inner Null-Characters and are not Null-Terminated, the Function instead accepts a CString.
-/// This means, that for the Moment this Function does the same thing as its ASCII String Equivalent, although it will be less performant because of the needed String Conversion
+/// This Function only returns `ErrorKind::CodePage` if the Text could not be encoded into the Code Page
+// This Function is already Safe
+#[cfg(windows)]
+#[inline]
+pub fn fatal_app_exit_ansi(code_page: CodePage, message_text: &str) -> Result<(), Error> {
+    let message_text = code_page.encode_c_string(message_text)?;
+    FatalAppExitA(&message_text);
+    return Ok(());
+}
+
+/// Wrapping Function for the FatalAppExitW Function
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-fatalappexitw)
+///
+/// This Function Displays an Error Message Box and terminates the Application when the Message box is closed
+///
+/// # Arguments
+///
+/// * `message_text` - The Text that should be displayed inside the message box
 
 // This Function is already Safe
-// TODO: Make this Function work with OSStrings
+#[cfg(windows)]
 #[inline]
-pub fn FatalAppExitW(message_text: CString) {
-    let os_string = convert_c_to_os_wide_string(message_text);
+pub fn FatalAppExitW(message_text: &WideCStr) {
     unsafe {
-        winapi::um::errhandlingapi::FatalAppExitW(0,os_string.as_ptr());
+        winapi::um::errhandlingapi::FatalAppExitW(0,message_text.as_ptr());
     }
 }
 
+/// Generic Wrapping Function for the FatalAppExitA and FatalAppExitW Functions
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-fatalappexitw)
+///
+/// This Function Displays an Error Message Box and terminates the Application when the Message box is closed.
+/// The Entry Point is chosen from the Type of the Message, see the `win32_str` Module.
+///
+/// # Arguments
+///
+/// * `message_text` - The Text that should be displayed inside the message box
+///
+/// # Return
+///
+/// This Function only returns `ErrorKind::InvalidString` if the Text could not be converted, because it contains a Null-Character
+
+// This Function is already Safe
+#[cfg(windows)]
+#[inline]
+pub fn fatal_app_exit<S: Win32Str + ?Sized>(message_text: &S) -> Result<(), Error> {
+    call_with_win32_str(
+        message_text,
+        |text| unsafe { winapi::um::errhandlingapi::FatalAppExitA(0, text) },
+        |text| unsafe { winapi::um::errhandlingapi::FatalAppExitW(0, text) }
+    )?;
+    return Ok(());
+}
+
 /// Wrapping Function for the GetErrorMode Function
 /// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-geterrormode)
 ///
@@ -115,10 +181,13 @@ pub fn FatalAppExitW(message_text: CString) {
 /// # Note
 ///
 /// The Microsoft Documentation indicates that the Function treats the Error Mode as an enum and returns one of the possibilities.
-/// Testing has revealed, that this is not true and that the Error Mode possibilities are instead treated as Bitflags
+/// Testing has revealed, that this is not true and that the Error Mode possibilities are instead treated as Bitflags.
+///
+/// Flags unknown to this Crate are dropped from the returned Error Mode. `ErrorModeGuard` and `ThreadErrorModeGuard` keep them when restoring an Error Mode.
 
 // This Function is already Safe
 // TODO: Additional Testing to ensure that the above assumption is indeed true and the test results where not just a fluke
+#[cfg(windows)]
 #[inline]
 pub fn GetErrorMode() -> ERROR_MODE {
     let error_mode: u32;
@@ -126,7 +195,7 @@ pub fn GetErrorMode() -> ERROR_MODE {
         error_mode = winapi::um::errhandlingapi::GetErrorMode();
     }
 
-    return ERROR_MODE::from_bits(error_mode).expect("Invalid Error Mode Flags");
+    return ERROR_MODE::from_bits_truncate(error_mode);
 }
 
 /// Wrapping Function for the SetErrorMode Function
@@ -141,10 +210,13 @@ pub fn GetErrorMode() -> ERROR_MODE {
 /// # Note
 ///
 /// The Microsoft Documentation indicates that the Function treats the Error Mode as an enum and expects only one of the possibilities.
-/// Testing has revealed, that this is not true and that the Error Mode possibilities are instead treated as Bitflags
+/// Testing has revealed, that this is not true and that the Error Mode possibilities are instead treated as Bitflags.
+///
+/// Flags unknown to this Crate are dropped from the returned Error Mode. `ErrorModeGuard` and `ThreadErrorModeGuard` keep them when restoring an Error Mode.
 
 // This Function is already Safe
 // TODO: Additional Testing to ensure that the above assumption is indeed true and the test results where not just a fluke
+#[cfg(windows)]
 #[inline]
 pub fn SetErrorMode(mode: ERROR_MODE) -> ERROR_MODE {
     let previous_error_mode: u32;
@@ -152,7 +224,7 @@ pub fn SetErrorMode(mode: ERROR_MODE) -> ERROR_MODE {
         previous_error_mode = winapi::um::errhandlingapi::SetErrorMode(mode.bits);
     }
 
-    return ERROR_MODE::from_bits(previous_error_mode).expect("Invalid Error Mode Flags");
+    return ERROR_MODE::from_bits_truncate(previous_error_mode);
 }
 
 /// Wrapping Function for the GetThreadErrorMode Function
@@ -165,17 +237,20 @@ pub fn SetErrorMode(mode: ERROR_MODE) -> ERROR_MODE {
 /// # Note
 ///
 /// The Microsoft Documentation indicates that the Function treats the Error Mode as an enum and returns one of the possibilities.
-/// Testing has revealed, that this is not true and that the Error Mode possibilities are instead treated as Bitflags
+/// Testing has revealed, that this is not true and that the Error Mode possibilities are instead treated as Bitflags.
+///
+/// Flags unknown to this Crate are dropped from the returned Error Mode. `ErrorModeGuard` and `ThreadErrorModeGuard` keep them when restoring an Error Mode.
 
 // This Function is already Safe
 // TODO: Additional Testing to ensure that the above assumption is indeed true and the test results where not just a fluke
+#[cfg(windows)]
 #[inline]
 pub fn GetThreadErrorMode() -> THREAD_ERROR_MODE {
     let error_mode: u32;
     unsafe {
         error_mode = winapi::um::errhandlingapi::GetThreadErrorMode();
     }
-    return THREAD_ERROR_MODE::from_bits(error_mode).expect("Invalid Error Mode Flags");
+    return THREAD_ERROR_MODE::from_bits_truncate(error_mode);
 }
 
 /// Wrapping Function for the SetErrorMode Function
@@ -190,29 +265,26 @@ pub fn GetThreadErrorMode() -> THREAD_ERROR_MODE {
 /// # Note
 ///
 /// The Microsoft Documentation indicates that the Function treats the Error Mode as an enum and expects only one of the possibilities.
-/// Testing has revealed, that this is not true and that the Error Mode possibilities are instead treated as Bitflags
+/// Testing has revealed, that this is not true and that the Error Mode possibilities are instead treated as Bitflags.
+///
+/// Flags unknown to this Crate are dropped from the returned Error Mode. `ErrorModeGuard` and `ThreadErrorModeGuard` keep them when restoring an Error Mode.
 
 // This Function is already Safe
 // TODO: Additional Testing to ensure that the above assumption is indeed true and the test results where not just a fluke
+#[cfg(windows)]
 #[inline]
-pub fn SetThreadErrorMode(mode: THREAD_ERROR_MODE) -> Result<THREAD_ERROR_MODE, u32> {
+pub fn SetThreadErrorMode(mode: THREAD_ERROR_MODE) -> Result<THREAD_ERROR_MODE, Error> {
     let mut previous_error_mode: MaybeUninit<DWORD> = MaybeUninit::uninit();
     let succeeded: BOOL;
     unsafe {
         succeeded = winapi::um::errhandlingapi::SetThreadErrorMode(mode.bits, (&mut previous_error_mode).as_mut_ptr())
     }
-    return match convert_c_bool(succeeded) {
-        true => {
-            let init_error_mode: u32;
-            unsafe {
-                init_error_mode = previous_error_mode.assume_init();
-            }
-            Ok(THREAD_ERROR_MODE::from_bits(init_error_mode).expect("Invalid Error Mode Flags"))
-        },
-        false => {
-            Err(GetLastError())
-        }
+    check::<BoolPolicy, _>("SetThreadErrorMode", succeeded)?;
+    let init_error_mode: u32;
+    unsafe {
+        init_error_mode = previous_error_mode.assume_init();
     }
+    return Ok(THREAD_ERROR_MODE::from_bits_truncate(init_error_mode));
 }
 
 /// Wrapping Function for the RaiseException Function
@@ -222,39 +294,75 @@ pub fn SetThreadErrorMode(mode: THREAD_ERROR_MODE) -> Result<THREAD_ERROR_MODE,
 ///
 /// # Arguments
 ///
-/// * `exception_code` - The Error Code of the Exception that should be raised. This Error Code is Application Defined
+/// * `exception_code` - The Exception Code of the Exception that should be raised. Application defined Codes should have the Customer Bit set
 /// * `is_continuable` - Indicates whether this Exception should be continuable
 /// * `exception_arguments` - Pointers to the Exception Arguments that should be passed with the Exception
 
 // This Function is already Safe
+#[cfg(windows)]
 #[inline]
-pub fn RaiseException(exception_code: u32, is_continuable: bool, exception_arguments: &[usize]) {
+pub fn RaiseException(exception_code: NtStatus, is_continuable: bool, exception_arguments: &[usize]) {
     let continuable_flag = match is_continuable {
         true => 0,
         false => winapi::um::minwinbase::EXCEPTION_NONCONTINUABLE_EXCEPTION
     };
     unsafe {
-        winapi::um::errhandlingapi::RaiseException(exception_code, continuable_flag, exception_arguments.len() as u32, exception_arguments.as_ptr())
+        winapi::um::errhandlingapi::RaiseException(exception_code.into(), continuable_flag, exception_arguments.len() as u32, exception_arguments.as_ptr())
     }
 }
 
 // This is needed here because it's not included in WINAPI
 const FAIL_FAST_GENERATE_EXCEPTION_ADDRESS: u32 = 1;
 
-/// # Warning Unimplemented
+/// Wrapping Function for the RaiseFailFastException Function
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-raisefailfastexception)
+///
+/// This Function terminates the Process immediately, bypassing all Exception Handlers, and reports the Exception to Windows Error Reporting
+///
+/// # Arguments
+///
+/// * `exception_record` - The Exception that should be reported, None reports a `STATUS_FAIL_FAST_EXCEPTION`
+/// * `context` - The Register State that should be reported, None reports the State of the calling Thread
+/// * `generate_exception_address` - Indicates whether the Address of the Exception should be replaced by the Instruction Pointer of the Context
 ///
-/// This Function is not yet implemented.
-/// This is because the Exception Record and Context Structs are not well Documented and seem to require additional wrapping
+/// # Return
 ///
-/// Documentation can be found [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-raisefailfastexception)
+/// This Function only returns `ErrorKind::InvalidArgument` if the Arguments could not be converted,
+/// because the Context belongs to a different Architecture or an Address does not fit into a Pointer of the Process
 
-// TODO: Implement this Method
+// This Function is already Safe
+#[cfg(windows)]
 #[inline]
-pub fn RaiseFailFastException(exception_record: u32, context: u32, generate_exception_address: bool) {
-    unimplemented!();
+pub fn RaiseFailFastException(exception_record: Option<&ExceptionRecord>, context: Option<&Context>, generate_exception_address: bool) -> Result<(), Error> {
+    let mut records = match exception_record {
+        Some(record) => record.to_native_chain()?,
+        None => Vec::new(),
+    };
+    let mut native_context = match context {
+        Some(context) => Some(context.to_native()?),
+        None => None,
+    };
+    let flags = match generate_exception_address {
+        true => FAIL_FAST_GENERATE_EXCEPTION_ADDRESS,
+        false => 0,
+    };
+    // `as_mut_ptr` does not create a Reference to the Records, which would invalidate the Pointers linking them
+    let record_ptr = match records.is_empty() {
+        true => null_mut(),
+        false => records.as_mut_ptr(),
+    };
+    unsafe {
+        winapi::um::errhandlingapi::RaiseFailFastException(
+            record_ptr,
+            native_context.as_mut().map_or(null_mut(), |context| context as *mut _),
+            flags
+        );
+    }
+    return Ok(());
 }
 
 /// Specifies where the VectoredHandler should be placed
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(u32)]
 pub enum PlacementPosition {
     /// The Vectored Handler will be placed at the Front of the Handler List
@@ -264,16 +372,77 @@ pub enum PlacementPosition {
     EmplaceLast = 1u32
 }
 
-/// # Warning Unimplemented
-///
-/// This Function is not yet implemented.
-/// This is because i have not yet found a good way to wrap FFI Callbacks.
-///
-/// # Note
-///
-/// The Function returns a Handle to the registered handler which has to be unregistered when the registered function goes out of scope so a safe wrapper will probably be needed.
+// Values of the winnt.h Header, defined here so that the Decisions are available on every Host
+const EXCEPTION_CONTINUE_SEARCH: LONG = 0;
+const EXCEPTION_CONTINUE_EXECUTION: LONG = -1;
+
+/// The Decision of a Vectored Handler about an Exception
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HandlerDecision {
+    /// The Exception is passed on to the next Handler
+    ContinueSearch,
+
+    /// The Exception is dismissed and the Thread continues with the Context of the Exception
+    ContinueExecution,
+}
+
+impl From<HandlerDecision> for LONG {
+    #[inline]
+    fn from(decision: HandlerDecision) -> LONG {
+        return match decision {
+            HandlerDecision::ContinueSearch => EXCEPTION_CONTINUE_SEARCH,
+            HandlerDecision::ContinueExecution => EXCEPTION_CONTINUE_EXECUTION,
+        };
+    }
+}
+
+// Values of the excpt.h Header, defined here so that the Decisions are available on every Host
+const EXCEPTION_EXECUTE_HANDLER: LONG = 1;
+
+/// The Decision of an Exception Filter about an Exception
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FilterDecision {
+    /// The Exception Handler of the Filter is executed, for the top-level Filter this terminates the Process
+    ExecuteHandler,
+
+    /// The Exception is passed on, for the top-level Filter this performs the default Handling
+    ContinueSearch,
+
+    /// The Exception is dismissed and the Thread continues with the Context of the Exception
+    ContinueExecution,
+}
+
+impl From<FilterDecision> for LONG {
+    #[inline]
+    fn from(decision: FilterDecision) -> LONG {
+        return match decision {
+            FilterDecision::ExecuteHandler => EXCEPTION_EXECUTE_HANDLER,
+            FilterDecision::ContinueSearch => EXCEPTION_CONTINUE_SEARCH,
+            FilterDecision::ContinueExecution => EXCEPTION_CONTINUE_EXECUTION,
+        };
+    }
+}
+
+impl From<LONG> for FilterDecision {
+    /// Converts the Return Value of a Filter, which is interpreted by its Sign like the System does
+    #[inline]
+    fn from(value: LONG) -> FilterDecision {
+        return match value {
+            value if value > 0 => FilterDecision::ExecuteHandler,
+            0 => FilterDecision::ContinueSearch,
+            _ => FilterDecision::ContinueExecution,
+        };
+    }
+}
+
+/// The Signature of Vectored Exception and Continue Handlers
+#[cfg(windows)]
+pub type VectoredHandlerFunction = unsafe extern "system" fn(*mut winapi::um::winnt::EXCEPTION_POINTERS) -> LONG;
+
+/// Wrapping Function for the AddVectoredContinueHandler Function
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-addvectoredcontinuehandler)
 ///
-/// Documentation can be found [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-addvectoredcontinuehandler).
+/// This Function Registers a Vectored Continue Handler.
 /// An overview about Vectored Exception Handling can be found [here](http://bytepointer.com/resources/pietrek_vectored_exception_handling.htm).
 /// A Reference on Continue Handling has yet to be found, the best information i can find is [this thread](https://reverseengineering.stackexchange.com/questions/14992/what-are-the-vectored-continue-handlers).
 /// It seems like Vectored Continue Handlers are called after an Exception Handler returns EXCEPTION_CONTINUE_EXECUTION.
@@ -281,47 +450,59 @@ pub enum PlacementPosition {
 /// # Arguments
 ///
 /// * `placement_position` - Specifies, where the Handler will be placed in the Exception Handler List
-///
-/// * `handler` - Pointer to the Handler Function that should be placed in the Vector
+/// * `handler` - The Handler Function that should be placed in the Vector
 ///
 /// # Return
 ///
-/// This function returns a Handle to the Placed Exception Handler
+/// This function returns a Handle to the Placed Continue Handler or `ErrorKind::NullPointer` if it could not be registered.
 /// Said Handler must be unregistered with RemoveVectoredContinueHandler before the Handler Function goes out of scope (for example, before the Functions DLL is unloaded)
-
-// TODO: Implement this Method
-#[inline]
-pub fn AddVectoredContinueHandler(placement_position: PlacementPosition) -> Result<PVOID, ()> {
-    unimplemented!()
-}
-
-/// # Warning Unimplemented
-///
-/// This Function is not yet implemented.
-/// This is because i have not yet found a good way to wrap FFI Callbacks
 ///
 /// # Note
 ///
-/// The Function returns a Handle to the registered handler which has to be unregistered when the registered function goes out of scope so a safe wrapper will probably be needed
+/// Use `VectoredContinueHandler` to register Rust Closures, which are unregistered automatically
+#[cfg(windows)]
+#[inline]
+pub fn AddVectoredContinueHandler(placement_position: PlacementPosition, handler: VectoredHandlerFunction) -> Result<PVOID, Error> {
+    let handle: PVOID;
+    unsafe {
+        handle = winapi::um::errhandlingapi::AddVectoredContinueHandler(placement_position as u32, Some(handler));
+    }
+    return match handle.is_null() {
+        true => Err(Error::new(ErrorKind::NullPointer).with_api("AddVectoredContinueHandler")),
+        false => Ok(handle)
+    };
+}
+
+/// Wrapping Function for the AddVectoredExceptionHandler Function
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-addvectoredexceptionhandler)
 ///
-/// Documentation can be found [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-addvectoredexceptionhandler)
+/// This Function Registers a Vectored Exception Handler.
 /// An overview about Vectored Exception Handling can be found [here](http://bytepointer.com/resources/pietrek_vectored_exception_handling.htm)
 ///
 /// # Arguments
 ///
 /// * `placement_position` - Specifies, where the Handler will be placed in the Exception Handler List
-///
-/// * `handler` - Pointer to the Handler Function that should be placed in the Vector
+/// * `handler` - The Handler Function that should be placed in the Vector
 ///
 /// # Return
 ///
-/// This function returns a Handle to the Placed Exception Handler if the Function Succeeds
+/// This function returns a Handle to the Placed Exception Handler or `ErrorKind::NullPointer` if it could not be registered.
 /// Said Handler must be unregistered with RemoveVectoredExceptionHandler before the Handler Function goes out of scope (for example, before the Functions DLL is unloaded)
-
-// TODO: Implement this Method
+///
+/// # Note
+///
+/// Use `VectoredExceptionHandler` to register Rust Closures, which are unregistered automatically
+#[cfg(windows)]
 #[inline]
-pub fn AddVectoredExceptionHandler(placement_position: PlacementPosition) -> Result<PVOID, ()> {
-    unimplemented!()
+pub fn AddVectoredExceptionHandler(placement_position: PlacementPosition, handler: VectoredHandlerFunction) -> Result<PVOID, Error> {
+    let handle: PVOID;
+    unsafe {
+        handle = winapi::um::errhandlingapi::AddVectoredExceptionHandler(placement_position as u32, Some(handler));
+    }
+    return match handle.is_null() {
+        true => Err(Error::new(ErrorKind::NullPointer).with_api("AddVectoredExceptionHandler")),
+        false => Ok(handle)
+    };
 }
 
 /// Wrapping Function for the RemoveVectoredContinueHandler Function
@@ -335,15 +516,16 @@ pub fn AddVectoredExceptionHandler(placement_position: PlacementPosition) -> Res
 ///
 /// # Return
 ///
-/// This Function returns a Result indicating Success
+/// This Function returns `ErrorKind::InvalidArgument` if the Handle does not belong to a registered Handler
+#[cfg(windows)]
 #[inline]
-pub fn RemoveVectoredContinueHandler(handle: PVOID) -> Result<(),()> {
+pub fn RemoveVectoredContinueHandler(handle: PVOID) -> Result<(), Error> {
     let result: u32;
     unsafe {
         result = winapi::um::errhandlingapi::RemoveVectoredContinueHandler(handle);
     }
     return match result {
-        0 => Err(()),
+        0 => Err(Error::new(ErrorKind::InvalidArgument("the handle does not belong to a registered handler")).with_api("RemoveVectoredContinueHandler")),
         _ => Ok(())
     }
 }
@@ -359,83 +541,100 @@ pub fn RemoveVectoredContinueHandler(handle: PVOID) -> Result<(),()> {
 ///
 /// # Return
 ///
-/// This Function returns a Result indicating Success
+/// This Function returns `ErrorKind::InvalidArgument` if the Handle does not belong to a registered Handler
+#[cfg(windows)]
 #[inline]
-pub fn RemoveVectoredExceptionHandler(handle: PVOID) -> Result<(),()> {
+pub fn RemoveVectoredExceptionHandler(handle: PVOID) -> Result<(), Error> {
     let result: u32;
     unsafe {
         result = winapi::um::errhandlingapi::RemoveVectoredExceptionHandler(handle);
     }
 
     return match result {
-        0 => Err(()),
+        0 => Err(Error::new(ErrorKind::InvalidArgument("the handle does not belong to a registered handler")).with_api("RemoveVectoredExceptionHandler")),
         _ => Ok(())
     }
 }
 
-/// # Warning Unimplemented
+/// The Signature of top-level Exception Filters
+#[cfg(windows)]
+pub type TopLevelFilterFunction = unsafe extern "system" fn(*mut winapi::um::winnt::EXCEPTION_POINTERS) -> LONG;
+
+/// Wrapping Function for the SetUnhandledExceptionFilter Function
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-setunhandledexceptionfilter)
 ///
-/// This Function is not yet implemented.
-/// This is because i have not yet found a good way to wrap the FFI Callbacks
+/// This Function installs the top-level Exception Filter of the Process, which is called for Exceptions that no Handler handled
 ///
-/// # Note
+/// # Arguments
 ///
-/// This Function may be implemented by storing the Function Pointer as a global and using a static function.
-/// This is not preferred though since that would lead to UB when the Function is called by external Libraries
+/// * `filter` - The Filter Function that should be installed, None restores the default Behaviour of calling UnhandledExceptionFilter
 ///
-/// Documentation can be found [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-setunhandledexceptionfilter)
-
-// TODO: Implement this Function
+/// # Return
+///
+/// This Function returns the previously installed Filter Function
+///
+/// # Note
+///
+/// Use `set_unhandled_exception_filter` to install Rust Closures, which can chain to the previous Filter and are uninstalled automatically
+#[cfg(windows)]
 #[inline]
-pub fn SetUnhandledExceptionFilter() {
-    unimplemented!()
+pub fn SetUnhandledExceptionFilter(filter: Option<TopLevelFilterFunction>) -> Option<TopLevelFilterFunction> {
+    unsafe {
+        return winapi::um::errhandlingapi::SetUnhandledExceptionFilter(filter);
+    }
 }
 
-
-/// # Warning Unimplemented
+/// Wrapping Function for the UnhandledExceptionFilter Function
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-unhandledexceptionfilter)
 ///
-/// This Function is not yet implemented.
-/// This is because the Documentation is unclear as to whether this Function is supposed to be called by the User at all
+/// This Function performs the default Handling of unhandled Exceptions: It notifies an attached Debugger or reports the Exception to Windows Error Reporting,
+/// which usually displays the Crash Dialog and terminates the Process.
 ///
-/// # Note
+/// # Arguments
 ///
-/// It seems to be the Case that this Function is supposed to be called by the user, but only within some Filter Expression of an Exception Handler.
-/// If this references the UnhandledExceptionFilter set by the SetUnhandledExceptionFilter Function warrants further research, although it seems unlikely.
-/// The Documentation also describes this as an Application defined Function, which seems weird and should be investigated, especially since WINAPI defines it as an external binding.
+/// * `exception_pointers` - The Exception, as passed to the calling Filter
 ///
-/// Documentation can be found [here](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-unhandledexceptionfilter)
-
-// TODO: Find out more about this Function
+/// # Return
+///
+/// This Function returns the Decision of the default Handling, usually `FilterDecision::ExecuteHandler`
+///
+/// # Safety
+///
+/// This Function may only be called from within an Exception Filter, with the Exception passed to that Filter
+#[cfg(windows)]
 #[inline]
-pub fn UnhandledExceptionFilter() {
-    unimplemented!()
+pub unsafe fn UnhandledExceptionFilter(exception_pointers: *mut winapi::um::winnt::EXCEPTION_POINTERS) -> FilterDecision {
+    return FilterDecision::from(winapi::um::errhandlingapi::UnhandledExceptionFilter(exception_pointers));
 }
 
-#[cfg(test)]
+#[cfg(all(test, windows))]
 mod test {
-    use crate::type_wrappers::error_handling_api::{GetErrorMode, GetThreadErrorMode, SetErrorMode, SetThreadErrorMode};
-    use crate::type_wrappers::error_handling_api::{ERROR_MODE,THREAD_ERROR_MODE};
-    use bitflags::_core::ptr::null_mut;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use crate::error::NtStatus;
+    use crate::type_wrappers::error_handling_api::{AddVectoredExceptionHandler, GetErrorMode, GetThreadErrorMode, PlacementPosition, RaiseException, RemoveVectoredExceptionHandler, SetErrorMode, SetThreadErrorMode};
+    use crate::type_wrappers::error_handling_api::{ERROR_MODE, THREAD_ERROR_MODE, EXCEPTION_CONTINUE_EXECUTION, EXCEPTION_CONTINUE_SEARCH};
+    use crate::type_wrappers::type_conversion::win_types::LONG;
 
-    #[test]
-    fn test_raise_exception() {
-        unsafe {
-            winapi::um::errhandlingapi::RaiseException(1u32, 0u32, 0u32, null_mut());
-        }
-    }
+    const TEST_EXCEPTION_CODE: u32 = 0xE000_0001;
 
-    #[test]
-    fn test_error_mode() {
-        let error_mode = GetErrorMode();
-        let error_uint: u32 = error_mode.bits;
-        println!("{:?}, {}",error_mode,error_uint);
+    static HANDLED: AtomicUsize = AtomicUsize::new(0);
+
+    /// Continues the Execution after the Exceptions raised by the Test
+    unsafe extern "system" fn continue_test_exception(pointers: *mut winapi::um::winnt::EXCEPTION_POINTERS) -> LONG {
+        if (*(*pointers).ExceptionRecord).ExceptionCode != TEST_EXCEPTION_CODE {
+            return EXCEPTION_CONTINUE_SEARCH;
+        }
+        HANDLED.fetch_add(1, Ordering::SeqCst);
+        return EXCEPTION_CONTINUE_EXECUTION;
     }
 
+    /// Tests that a continuable Exception reaches a Vectored Handler, which continues the Execution after RaiseException
     #[test]
-    fn test_thread_error_mode() {
-        let error_mode = GetThreadErrorMode();
-        let error_uint: u32 = error_mode.bits;
-        println!("{:?}, {}",error_mode, error_uint);
+    fn test_raise_exception() {
+        let handle = AddVectoredExceptionHandler(PlacementPosition::EmplaceFirst, continue_test_exception).unwrap();
+        RaiseException(NtStatus::from(TEST_EXCEPTION_CODE), true, &[]);
+        RemoveVectoredExceptionHandler(handle).unwrap();
+        assert_eq!(HANDLED.load(Ordering::SeqCst), 1);
     }
 
     #[test]
@@ -443,8 +642,9 @@ mod test {
         let mut error_mode: ERROR_MODE = ERROR_MODE::empty();
         error_mode.insert(ERROR_MODE::NOALIGNMENTFAULTEXCEPT);
         error_mode.insert(ERROR_MODE::NOOPENFILEERRORBOX);
-        SetErrorMode(error_mode);
+        let previous = SetErrorMode(error_mode);
         assert_eq!(GetErrorMode(), error_mode);
+        SetErrorMode(previous);
     }
 
     #[test]
@@ -452,8 +652,26 @@ mod test {
         let mut error_mode: THREAD_ERROR_MODE = THREAD_ERROR_MODE::empty();
         error_mode.insert(THREAD_ERROR_MODE::NOGPFAULTERRORBOX);
         error_mode.insert(THREAD_ERROR_MODE::NOOPENFILEERRORBOX);
-        let set_result = SetThreadErrorMode(error_mode).unwrap();
+        let previous = SetThreadErrorMode(error_mode).unwrap();
         assert_eq!(GetThreadErrorMode(), error_mode);
+        SetThreadErrorMode(previous).unwrap();
+    }
+}
+
+/// Tests for the Constants defined in this Module
+#[cfg(all(test, windows))]
+mod test_constants {
+    use crate::type_wrappers::error_handling_api::*;
 
+    /// Tests that the Constants match the Definitions of the winbase.h Header in WINAPI
+    #[test]
+    fn test_match_winapi() {
+        assert_eq!(SEM_FAILCRITICALERRORS, winapi::um::winbase::SEM_FAILCRITICALERRORS);
+        assert_eq!(SEM_NOGPFAULTERRORBOX, winapi::um::winbase::SEM_NOGPFAULTERRORBOX);
+        assert_eq!(SEM_NOALIGNMENTFAULTEXCEPT, winapi::um::winbase::SEM_NOALIGNMENTFAULTEXCEPT);
+        assert_eq!(SEM_NOOPENFILEERRORBOX, winapi::um::winbase::SEM_NOOPENFILEERRORBOX);
+        assert_eq!(EXCEPTION_CONTINUE_SEARCH, winapi::vc::excpt::EXCEPTION_CONTINUE_SEARCH);
+        assert_eq!(EXCEPTION_CONTINUE_EXECUTION, winapi::vc::excpt::EXCEPTION_CONTINUE_EXECUTION);
+        assert_eq!(EXCEPTION_EXECUTE_HANDLER, winapi::vc::excpt::EXCEPTION_EXECUTE_HANDLER);
     }
-}
\ No newline at end of file
+}
@@ -7,15 +7,23 @@
 //!
 //! Link to the Header Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/werapi/)
 
-#![allow(non_snake_case, dead_code)]
-
-use std::ffi::CString;
-use crate::type_wrappers::type_conversion::{convert_c_to_os_wide_string, convert_rust_bool, convert_reference_to_pvoid};
-use winapi::um::winnt::{PVOID, HANDLE};
-use winapi::ctypes::c_void;
-use winapi::shared::minwindef::DWORD;
-use bitflags::bitflags;
+#![allow(non_snake_case, non_camel_case_types, non_upper_case_globals, dead_code)]
+
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::convert_reference_to_pvoid;
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::wide_string::WideCStr;
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::win_types::PVOID;
+#[cfg(windows)]
+use winapi::um::winnt::HANDLE;
+#[cfg(windows)]
 use std::mem::MaybeUninit;
+#[cfg(windows)]
+use crate::error::Error;
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::success::{check, HResultPolicy};
+use crate::type_wrappers::type_conversion::win_types::DWORD;
 
 
 /// Wrapping Function for the WerAddExcludedApplication Function
@@ -30,23 +38,22 @@ use std::mem::MaybeUninit;
 ///
 /// # Return
 ///
-/// `S_OK` - This should be returned on Success
-/// `E_ACCESSDENIED` - This should be returned if the Process does not have the permission to write to the registry
+/// `Ok(())` - On Success
+/// `ErrorKind::HResult(E_ACCESSDENIED)` - This should be returned if the Process does not have the permission to write to the registry
 ///
 /// # Notes
 ///
 /// If all_uses is true, the excluded list is stored under HKEY_LOCAL_MACHINE in the Registry.
 /// If it is false, it is stored under HKEY_CURRENT_USER in the Registry.
 
-// TODO: Consider Type Wrapping the HRESULT
+#[cfg(windows)]
 #[inline]
-pub fn WerAddExcludedApplication(application_name: CString, all_users: bool) -> i32 {
+pub fn WerAddExcludedApplication(application_name: &WideCStr, all_users: bool) -> Result<(), Error> {
     let result: i32;
-    let wide_name = convert_c_to_os_wide_string(application_name);
     unsafe {
-        result = winapi::um::werapi::WerAddExcludedApplication(wide_name.as_ptr(), all_users as i32);
+        result = winapi::um::werapi::WerAddExcludedApplication(application_name.as_ptr(), all_users as i32);
     }
-    return result;
+    return check::<HResultPolicy, _>("WerAddExcludedApplication", result);
 }
 
 /// Wrapping Function for the WerRemoveExcludedApplication Function
@@ -61,23 +68,22 @@ pub fn WerAddExcludedApplication(application_name: CString, all_users: bool) ->
 ///
 /// # Return
 ///
-/// `S_OK` - On Success
-/// `E_ACCESSDENIED` - This should be returned if the Process does not have the permission to write to the registry
+/// `Ok(())` - On Success
+/// `ErrorKind::HResult(E_ACCESSDENIED)` - This should be returned if the Process does not have the permission to write to the registry
 ///
 /// # Notes
 ///
 /// If all_uses is true, then the application is removed from the list stored under HKEY_LOCAL_MACHINE in the Registry.
 /// If it is false, then it is removed from the list stored under HKEY_CURRENT_USER in the Registry.
 
-// TODO: Consider Type Wrapping the HRESULT
+#[cfg(windows)]
 #[inline]
-pub fn WerRemoveExcludedApplication(application_name: CString, all_users: bool) -> i32 {
+pub fn WerRemoveExcludedApplication(application_name: &WideCStr, all_users: bool) -> Result<(), Error> {
     let result: i32;
-    let wide_name = convert_c_to_os_wide_string(application_name);
     unsafe {
-        result = winapi::um::werapi::WerRemoveExcludedApplication(wide_name.as_ptr(), all_users as i32);
+        result = winapi::um::werapi::WerRemoveExcludedApplication(application_name.as_ptr(), all_users as i32);
     }
-    return result;
+    return check::<HResultPolicy, _>("WerRemoveExcludedApplication", result);
 }
 
 /// Wrapping Function for the WerRegisterRuntimeExceptionModule Function
@@ -92,25 +98,24 @@ pub fn WerRemoveExcludedApplication(application_name: CString, all_users: bool)
 ///
 /// # Return
 ///
-/// `S_OK` - On Success
-/// `WER_E_INVALID_STATE` - Process State is invalid for Registering the Module
-/// `ERROR_INSUFFICIENT_BUFFER` - Too many registered Exception Modules
+/// `Ok(())` - On Success
+/// `ErrorKind::HResult(WER_E_INVALID_STATE)` - Process State is invalid for Registering the Module
+/// `ErrorKind::HResult(HRESULT_FROM_WIN32(ERROR_INSUFFICIENT_BUFFER))` - Too many registered Exception Modules
 ///
 /// # Notes
 ///
 /// The Context Pointer probably requires Safe Wrapping, but i am not sure how exactly to wrap it yet.
 /// It might be considered to treat a Succeeded Registering as a Handle with a Drop Trait.
 
-// TODO: Consider Type Wrapping the HRESULT
 // TODO: Consider Wrapping the context pointer
+#[cfg(windows)]
 #[inline]
-pub fn WerRegisterRuntimeExceptionModule(dll_name: CString, context: PVOID) -> i32 {
+pub fn WerRegisterRuntimeExceptionModule(dll_name: &WideCStr, context: PVOID) -> Result<(), Error> {
     let result: i32;
-    let wide_name = convert_c_to_os_wide_string(dll_name);
     unsafe {
-        result = winapi::um::werapi::WerRegisterRuntimeExceptionModule(wide_name.as_ptr(),context);
+        result = winapi::um::werapi::WerRegisterRuntimeExceptionModule(dll_name.as_ptr(),context);
     }
-    return result;
+    return check::<HResultPolicy, _>("WerRegisterRuntimeExceptionModule", result);
 }
 
 /// Wrapping Function for the WerUnregisterRuntimeExceptionModule Function
@@ -125,9 +130,9 @@ pub fn WerRegisterRuntimeExceptionModule(dll_name: CString, context: PVOID) -> i
 ///
 /// # Return
 ///
-/// `S_OK` - On Success
-/// `WER_E_INVALID_STATE` - Process State is invalid for Registering the Module
-/// `WER_E_NOT_FOUND` - No Module with this Name was Registered
+/// `Ok(())` - On Success
+/// `ErrorKind::HResult(WER_E_INVALID_STATE)` - Process State is invalid for Registering the Module
+/// `ErrorKind::HResult(WER_E_NOT_FOUND)` - No Module with this Name was Registered
 ///
 /// # Notes
 ///
@@ -135,18 +140,23 @@ pub fn WerRegisterRuntimeExceptionModule(dll_name: CString, context: PVOID) -> i
 /// I'm also not really sure, why this pointer is needed here.
 /// It might be considered to treat a Succeeded Registering as a Handle with a Drop Trait.
 
-// TODO: Consider Type Wrapping the HRESULT
 // TODO: Consider Wrapping the context pointer
+#[cfg(windows)]
 #[inline]
-pub fn WerUnregisterRuntimeExceptionModule(dll_name: CString, context: PVOID) -> i32 {
+pub fn WerUnregisterRuntimeExceptionModule(dll_name: &WideCStr, context: PVOID) -> Result<(), Error> {
     let result: i32;
-    let wide_name = convert_c_to_os_wide_string(dll_name);
     unsafe {
-        result = winapi::um::werapi::WerUnregisterRuntimeExceptionModule(wide_name.as_ptr(), context);
+        result = winapi::um::werapi::WerUnregisterRuntimeExceptionModule(dll_name.as_ptr(), context);
     }
-    return result;
+    return check::<HResultPolicy, _>("WerUnregisterRuntimeExceptionModule", result);
 }
 
+// Values of the werapi.h Header, which are not present in WINAPI
+/// The maximum Size of a Memory Block registered with WerRegisterMemoryBlock in Bytes
+pub const WER_MAX_MEM_BLOCK_SIZE: usize = 64 * 1024;
+/// The maximum Number of Memory Blocks a Process can register with WerRegisterMemoryBlock
+pub const WER_MAX_REGISTERED_ENTRIES: usize = 512;
+
 /// Wrapping Function for the WerRegisterMemoryBlock Function.
 /// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/werapi/nf-werapi-werregistermemoryblock)
 ///
@@ -158,27 +168,24 @@ pub fn WerUnregisterRuntimeExceptionModule(dll_name: CString, context: PVOID) ->
 ///
 /// # Return
 ///
-/// `S_OK` - On Success
-/// `WER_E_INVALID_STATE` - If the Process State is invalid for Registering Memory Blocks
-/// `ERROR_INSUFFICIENT_BUFFER` - If there are already too many Memory Blocks Registered
+/// `Ok(())` - On Success
+/// `ErrorKind::HResult(WER_E_INVALID_STATE)` - If the Process State is invalid for Registering Memory Blocks
+/// `ErrorKind::HResult(HRESULT_FROM_WIN32(ERROR_INSUFFICIENT_BUFFER))` - If there are already too many Memory Blocks Registered
 ///
 /// # Note
 ///
-/// It has yet to be determined if a reference is sufficient here or if there is a need to register arbitrary memory locations with arbitrary sizes.
-/// A point can be made though, that said arbitrary memory locations can be made into a reference beforehand.
-
-// TODO: Consider Type Wrapping the HRESULT
-// TODO: Safe Wrapping this into a Handle Struct with Drop and lifetime semantics
-// TODO: Determine, if this syntax can accommodate Array Blocks
+/// The Block stays registered until it is unregistered with `WerUnregisterMemoryBlock`, even if the Reference goes away.
+/// `wer_api::WerMemoryRegistration` registers Slices and raw Byte Ranges and unregisters them when dropped.
+#[cfg(windows)]
 #[inline]
-pub fn WerRegisterMemoryBlock<T>(reference: &mut T) -> i32 where T: Sized {
+pub fn WerRegisterMemoryBlock<T>(reference: &mut T) -> Result<(), Error> where T: Sized {
     let block_size = std::mem::size_of::<T>() as DWORD;
     let pointer = convert_reference_to_pvoid(reference);
     let result: i32;
     unsafe {
         result = winapi::um::werapi::WerRegisterMemoryBlock(pointer, block_size);
     }
-    return result;
+    return check::<HResultPolicy, _>("WerRegisterMemoryBlock", result);
 }
 
 /// Wrapping Function for the WerUnregisterMemoryBlock Function.
@@ -192,41 +199,44 @@ pub fn WerRegisterMemoryBlock<T>(reference: &mut T) -> i32 where T: Sized {
 ///
 /// # Return
 ///
-/// `S_OK` - On Success
-/// `WER_E_INVALID_STATE` - If the Process State is invalid for Registering Memory Blocks
-/// `WER_E_NOT_FOUND` - If the Memory Block has not been previously registered
+/// `Ok(())` - On Success
+/// `ErrorKind::HResult(WER_E_INVALID_STATE)` - If the Process State is invalid for Registering Memory Blocks
+/// `ErrorKind::HResult(WER_E_NOT_FOUND)` - If the Memory Block has not been previously registered
 ///
 /// # Note
 ///
-/// It has yet to be determined if a reference is sufficient here or if there is a need to unregister arbitrary memory locations.
-/// A point can be made though, that said arbitrary memory locations can be made into a reference beforehand.
-
-// TODO: Consider Type Wrapping the HRESULT
-// TODO: Safe Wrapping this into a Handle Struct with Drop and Lifetime semantics
+/// Blocks registered by `wer_api::WerMemoryRegistration` are unregistered by the Guard and must not be unregistered with this Function.
+#[cfg(windows)]
 #[inline]
-pub fn WerUnregisterMemoryBlock<T>(reference: &mut T) -> i32 where T: Sized {
+pub fn WerUnregisterMemoryBlock<T>(reference: &mut T) -> Result<(), Error> where T: Sized {
     let pointer = convert_reference_to_pvoid(reference);
     let result: i32;
     unsafe {
         result = winapi::um::werapi::WerUnregisterMemoryBlock(pointer);
     }
-    return result;
+    return check::<HResultPolicy, _>("WerUnregisterMemoryBlock", result);
 }
 
 // Values extracted from C++ Visual Studio rather than Documentation or WINAPI
 const WER_FILE_ANONYMOUS_DATA: u32 = 2;
 const WER_FILE_DELETE_WHEN_DONE: u32 = 1;
 
-bitflags!(
+NAMED_BITFLAGS!(
     pub struct FileFlags : u32 {
         const FILE_ANONYMOUS_DATA = WER_FILE_ANONYMOUS_DATA;
         const FILE_DELETE_WHEN_DONE = WER_FILE_DELETE_WHEN_DONE;
 });
 
-CONST_TO_ENUM!(const_enum REGISTER_FILE_TYPE, winapi::um::werapi::WER_REGISTER_FILE_TYPE {
-    Max = winapi::um::werapi::WerRegFileTypeMax,
-    Other = winapi::um::werapi::WerRegFileTypeOther,
-    UserDocument = winapi::um::werapi::WerRegFileTypeUserDocument,
+// Values of the werapi.h Header, defined here so that the Enum is available on every Host
+type WER_REGISTER_FILE_TYPE = u32;
+const WerRegFileTypeUserDocument: WER_REGISTER_FILE_TYPE = 1;
+const WerRegFileTypeOther: WER_REGISTER_FILE_TYPE = 2;
+const WerRegFileTypeMax: WER_REGISTER_FILE_TYPE = 3;
+
+CONST_TO_ENUM!(const_enum_open REGISTER_FILE_TYPE, WER_REGISTER_FILE_TYPE {
+    Max = WerRegFileTypeMax,
+    Other = WerRegFileTypeOther,
+    UserDocument = WerRegFileTypeUserDocument,
 });
 
 /// Wrapping Function for the WerRegisterFile Function
@@ -242,19 +252,18 @@ CONST_TO_ENUM!(const_enum REGISTER_FILE_TYPE, winapi::um::werapi::WER_REGISTER_F
 ///
 /// # Return
 ///
-/// `S_OK` - On Success
-/// `WER_E_INVALID_STATE` - If the Process State is Invalid for Registering a File
-/// `ERROR_INSUFFICIENT_BUFFER` - If the number of Registered Files Exceeds the Limit
+/// `Ok(())` - On Success
+/// `ErrorKind::HResult(WER_E_INVALID_STATE)` - If the Process State is Invalid for Registering a File
+/// `ErrorKind::HResult(HRESULT_FROM_WIN32(ERROR_INSUFFICIENT_BUFFER))` - If the number of Registered Files Exceeds the Limit
 
-// TODO: Consider Wrapping the HRESULT
+#[cfg(windows)]
 #[inline]
-pub fn WerRegisterFile(file_path: CString, file_type: REGISTER_FILE_TYPE, file_flags: FileFlags) -> i32 {
-    let wide_path = convert_c_to_os_wide_string(file_path);
+pub fn WerRegisterFile(file_path: &WideCStr, file_type: REGISTER_FILE_TYPE, file_flags: FileFlags) -> Result<(), Error> {
     let result: i32;
     unsafe {
-        result = winapi::um::werapi::WerRegisterFile(wide_path.as_ptr(),file_type.into(),file_flags.bits());
+        result = winapi::um::werapi::WerRegisterFile(file_path.as_ptr(),file_type.into(),file_flags.bits());
     }
-    return result;
+    return check::<HResultPolicy, _>("WerRegisterFile", result);
 }
 
 /// Wrapping Function for the WerUnregisterFile Function
@@ -268,19 +277,18 @@ pub fn WerRegisterFile(file_path: CString, file_type: REGISTER_FILE_TYPE, file_f
 ///
 /// # Return
 ///
-/// `S_OK` - On Success
-/// `WER_E_INVALID_STATE` - If the Process State is Invalid for Registering a File
-/// `WER_E_NOT_FOUND` - If the File has not been registered for Collection
+/// `Ok(())` - On Success
+/// `ErrorKind::HResult(WER_E_INVALID_STATE)` - If the Process State is Invalid for Registering a File
+/// `ErrorKind::HResult(WER_E_NOT_FOUND)` - If the File has not been registered for Collection
 
-// TODO: Consider Wrapping the HRESULT
+#[cfg(windows)]
 #[inline]
-pub fn WerUnregisterFile(file_path: CString) -> i32 {
-    let wide_path = convert_c_to_os_wide_string(file_path);
+pub fn WerUnregisterFile(file_path: &WideCStr) -> Result<(), Error> {
     let result: i32;
     unsafe {
-        result = winapi::um::werapi::WerUnregisterFile(wide_path.as_ptr());
+        result = winapi::um::werapi::WerUnregisterFile(file_path.as_ptr());
     }
-    return result;
+    return check::<HResultPolicy, _>("WerUnregisterFile", result);
 }
 
 // Copied from WINAPI, needed as they are not present in the current WINAPI release, but it seems like they will be included in the next release
@@ -296,13 +304,19 @@ pub const WER_FAULT_REPORTING_DISABLE_SNAPSHOT_HANG: DWORD = 256;
 pub const WER_FAULT_REPORTING_CRITICAL: DWORD = 512;
 pub const WER_FAULT_REPORTING_DURABLE: DWORD = 1024;
 
-bitflags!(
+NAMED_BITFLAGS!(
     pub struct FaultReportingFlags : DWORD {
         const NOHEAP = WER_FAULT_REPORTING_FLAG_NOHEAP;
         const QUEUE = WER_FAULT_REPORTING_FLAG_QUEUE;
         const DISABLE_THREAD_SUSPENSION = WER_FAULT_REPORTING_FLAG_DISABLE_THREAD_SUSPENSION;
         const QUEUE_UPLOAD = WER_FAULT_REPORTING_FLAG_QUEUE_UPLOAD;
         const ALWAYS_SHOW_UI = WER_FAULT_REPORTING_ALWAYS_SHOW_UI;
+        const NO_UI = WER_FAULT_REPORTING_NO_UI;
+        const NO_HEAP_ON_QUEUE = WER_FAULT_REPORTING_FLAG_NO_HEAP_ON_QUEUE;
+        const DISABLE_SNAPSHOT_CRASH = WER_FAULT_REPORTING_DISABLE_SNAPSHOT_CRASH;
+        const DISABLE_SNAPSHOT_HANG = WER_FAULT_REPORTING_DISABLE_SNAPSHOT_HANG;
+        const CRITICAL = WER_FAULT_REPORTING_CRITICAL;
+        const DURABLE = WER_FAULT_REPORTING_DURABLE;
 });
 
 /// Wrapping Function for the WerGetFlags Function
@@ -316,25 +330,20 @@ bitflags!(
 ///
 /// # Return
 ///
-/// On Success, returns the fetched Flags.
-/// On Error, returns the Error Code.
+/// On Success, returns the fetched Flags, without Flags unknown to this Crate.
+/// On Error, returns the failing HRESULT as `ErrorKind::HResult`.
 
-// TODO: Consider Wrapping the HRESULT
+#[cfg(windows)]
 #[inline]
-pub fn WerGetFlags(process: HANDLE) -> Result<FaultReportingFlags, i32> {
+pub fn WerGetFlags(process: HANDLE) -> Result<FaultReportingFlags, Error> {
     let mut flags = MaybeUninit::<DWORD>::uninit();
     let result: i32;
     unsafe {
         result = winapi::um::werapi::WerGetFlags(process, flags.as_mut_ptr());
     }
-    return match result {
-        0 => {
-            let flags = unsafe { flags.assume_init() };
-            let flags = FaultReportingFlags::from_bits(flags).expect("Unknown Flags");
-            Ok(flags)
-        }
-        _ => Err(result)
-    }
+    check::<HResultPolicy, _>("WerGetFlags", result)?;
+    let flags = unsafe { flags.assume_init() };
+    return Ok(FaultReportingFlags::from_bits_truncate(flags));
 }
 
 /// Wrapping Function for the WerSetFlags Function
@@ -344,14 +353,47 @@ pub fn WerGetFlags(process: HANDLE) -> Result<FaultReportingFlags, i32> {
 ///
 /// # Return
 ///
-/// Returns the HRESULT
+/// Returns `Ok(())` on Success.
+/// On Error, returns the failing HRESULT as `ErrorKind::HResult`.
 
-// TODO: Consider Wrapping the HRESULT
+#[cfg(windows)]
 #[inline]
-pub fn WerSetFlags(flags: FaultReportingFlags) -> i32 {
+pub fn WerSetFlags(flags: FaultReportingFlags) -> Result<(), Error> {
     let result: i32;
     unsafe {
         result = winapi::um::werapi::WerSetFlags(flags.bits);
     }
-    return result;
-}
\ No newline at end of file
+    return check::<HResultPolicy, _>("WerSetFlags", result);
+}
+
+/// Tests for the Constants defined in this Module
+#[cfg(all(test, windows))]
+mod test_constants {
+    use crate::type_wrappers::error_reporting::wer_api::*;
+
+    /// Tests that the Constants match the Definitions of the werapi.h Header in WINAPI
+    #[test]
+    fn test_match_winapi() {
+        assert_eq!(WerRegFileTypeUserDocument, winapi::um::werapi::WerRegFileTypeUserDocument);
+        assert_eq!(WerRegFileTypeOther, winapi::um::werapi::WerRegFileTypeOther);
+        assert_eq!(WerRegFileTypeMax, winapi::um::werapi::WerRegFileTypeMax);
+        assert_eq!(WER_FAULT_REPORTING_FLAG_NOHEAP, winapi::um::werapi::WER_FAULT_REPORTING_FLAG_NOHEAP);
+        assert_eq!(WER_FAULT_REPORTING_FLAG_QUEUE, winapi::um::werapi::WER_FAULT_REPORTING_FLAG_QUEUE);
+        assert_eq!(WER_FAULT_REPORTING_FLAG_DISABLE_THREAD_SUSPENSION, winapi::um::werapi::WER_FAULT_REPORTING_FLAG_DISABLE_THREAD_SUSPENSION);
+        assert_eq!(WER_FAULT_REPORTING_FLAG_QUEUE_UPLOAD, winapi::um::werapi::WER_FAULT_REPORTING_FLAG_QUEUE_UPLOAD);
+        assert_eq!(WER_FAULT_REPORTING_ALWAYS_SHOW_UI, winapi::um::werapi::WER_FAULT_REPORTING_ALWAYS_SHOW_UI);
+    }
+}
+
+/// Tests for the Flags defined in this Module
+#[cfg(test)]
+mod test_flags {
+    use crate::type_wrappers::error_reporting::wer_api::FaultReportingFlags;
+
+    /// Tests that every documented Fault Reporting Flag is known, so fetched Flags are not truncated
+    #[test]
+    fn test_fault_reporting_flags_complete() {
+        assert_eq!(FaultReportingFlags::all().bits(), 0x7FF);
+        assert_eq!(FaultReportingFlags::from_bits_truncate(0x1200), FaultReportingFlags::CRITICAL);
+    }
+}
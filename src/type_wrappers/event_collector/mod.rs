@@ -7,4 +7,6 @@
 /// Type Wrappers for the evcoll.h Header
 ///
 /// Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/evcoll/)
+// Named after the Header, like the other Type Wrapper Modules
+#[allow(clippy::module_inception)]
 pub mod event_collector;
\ No newline at end of file
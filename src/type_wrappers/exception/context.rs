@@ -0,0 +1,494 @@
+#![allow(non_upper_case_globals)]
+
+use crate::error::{Error, ErrorKind};
+use crate::type_wrappers::exception::{check_size, Architecture, LayoutField};
+use crate::type_wrappers::type_conversion::win_types::DWORD;
+
+// Values of the winnt.h Header, defined here so that the Contexts are available on every Host
+const CONTEXT_i386: DWORD = 0x0001_0000;
+const CONTEXT_AMD64: DWORD = 0x0010_0000;
+const CONTEXT_ARM64: DWORD = 0x0040_0000;
+const CONTEXT_ARCHITECTURE_MASK: DWORD = CONTEXT_i386 | CONTEXT_AMD64 | CONTEXT_ARM64;
+const CONTEXT_CONTROL: DWORD = 0x1;
+const CONTEXT_INTEGER: DWORD = 0x2;
+const CONTEXT_SEGMENTS: DWORD = 0x4;
+
+/// Generates a View of the CONTEXT Struct of an Architecture, with its Builder and the Decoding from and Encoding into raw Bytes
+///
+/// Every Register is given with its Offset in the CONTEXT Struct of the Architecture.
+/// Registers that are not part of the View are zero in encoded Contexts.
+macro_rules! context_view {
+    (
+        $(#[$outer:meta])*
+        pub struct $name:ident, $builder:ident {
+            architecture: $architecture:expr,
+            size: $size:expr,
+            flags_offset: $flags_offset:expr,
+            default_flags: $default_flags:expr,
+        } {
+            $(
+                $(#[$inner:meta])*
+                $register:ident : $type:ty = $offset:expr,
+            )*
+        }
+    ) => {
+        $(#[$outer])*
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+        pub struct $name {
+            /// The ContextFlags, which hold the Architecture and select the valid Register Sets
+            pub context_flags: u32,
+            $(
+                $(#[$inner])*
+                pub $register: $type,
+            )*
+        }
+
+        impl $name {
+            /// The Size of the CONTEXT Struct of the Architecture
+            pub const SIZE: usize = $size;
+
+            /// Creates a Builder for a new Context, whose Flags select the Registers of the View
+            #[inline]
+            pub fn builder() -> $builder {
+                return $builder { context: $name { context_flags: $default_flags, ..Default::default() } };
+            }
+
+            /// Decodes a Context from the raw Bytes of the CONTEXT Struct of the Architecture
+            ///
+            /// # Return
+            ///
+            /// This Function returns `ErrorKind::InvalidArgument` if the Bytes are too small or the Flags belong to a different Architecture
+            pub fn from_bytes(bytes: &[u8]) -> Result<$name, Error> {
+                check_size(bytes, $size)?;
+                let context_flags = u32::read(bytes, $flags_offset);
+                if context_flags & CONTEXT_ARCHITECTURE_MASK != $default_flags & CONTEXT_ARCHITECTURE_MASK {
+                    return Err(Error::new(ErrorKind::InvalidArgument("the context flags belong to a different architecture")));
+                }
+                return Ok($name {
+                    context_flags,
+                    $($register: <$type>::read(bytes, $offset),)*
+                });
+            }
+
+            /// Encodes the Context into the raw Bytes of the CONTEXT Struct of the Architecture
+            #[inline]
+            pub fn to_bytes(&self) -> Vec<u8> {
+                let mut bytes = vec![0u8; $size];
+                // The Buffer has the Size of the Layout
+                let _ = self.write_registers(&mut bytes);
+                return bytes;
+            }
+
+            /// Writes the Flags and Registers of the View into the raw Bytes of a CONTEXT Struct, leaving all other Registers unchanged
+            ///
+            /// # Return
+            ///
+            /// This Function returns `ErrorKind::InvalidArgument` if the Bytes are too small
+            pub fn write_registers(&self, bytes: &mut [u8]) -> Result<(), Error> {
+                check_size(bytes, $size)?;
+                self.context_flags.write(bytes, $flags_offset);
+                $(self.$register.write(bytes, $offset);)*
+                return Ok(());
+            }
+        }
+
+        /// Builder for the Context, see the Registers of the Context for the Setters
+        #[derive(Debug, Copy, Clone)]
+        pub struct $builder {
+            context: $name,
+        }
+
+        impl $builder {
+            /// Sets the ContextFlags, which select the valid Register Sets
+            #[inline]
+            pub fn context_flags(mut self, context_flags: u32) -> $builder {
+                self.context.context_flags = context_flags;
+                return self;
+            }
+
+            $(
+                #[inline]
+                pub fn $register(mut self, value: $type) -> $builder {
+                    self.context.$register = value;
+                    return self;
+                }
+            )*
+
+            /// Finishes the Context
+            #[inline]
+            pub fn build(self) -> $name {
+                return self.context;
+            }
+        }
+
+        impl From<$name> for Context {
+            #[inline]
+            fn from(context: $name) -> Context {
+                return $architecture(context);
+            }
+        }
+    };
+}
+
+context_view!(
+    /// View of the x86 CONTEXT Struct with the Control, Integer and Segment Registers
+    ///
+    /// # Note
+    ///
+    /// The Debug, Floating Point and Extended Registers are not part of the View
+    // TODO: Add the Floating Point and Extended Registers when they are needed
+    pub struct ContextX86, ContextX86Builder {
+        architecture: Context::X86,
+        size: 716,
+        flags_offset: 0,
+        default_flags: CONTEXT_i386 | CONTEXT_CONTROL | CONTEXT_INTEGER | CONTEXT_SEGMENTS,
+    } {
+        seg_gs: u32 = 140,
+        seg_fs: u32 = 144,
+        seg_es: u32 = 148,
+        seg_ds: u32 = 152,
+        edi: u32 = 156,
+        esi: u32 = 160,
+        ebx: u32 = 164,
+        edx: u32 = 168,
+        ecx: u32 = 172,
+        eax: u32 = 176,
+        ebp: u32 = 180,
+        eip: u32 = 184,
+        seg_cs: u32 = 188,
+        eflags: u32 = 192,
+        esp: u32 = 196,
+        seg_ss: u32 = 200,
+    }
+);
+
+context_view!(
+    /// View of the x64 CONTEXT Struct with the Control, Integer and Segment Registers
+    ///
+    /// # Note
+    ///
+    /// The Debug, Floating Point and Vector Registers are not part of the View, except for MxCsr
+    // TODO: Add the Floating Point and Vector Registers when they are needed
+    pub struct ContextAmd64, ContextAmd64Builder {
+        architecture: Context::Amd64,
+        size: 1232,
+        flags_offset: 0x30,
+        default_flags: CONTEXT_AMD64 | CONTEXT_CONTROL | CONTEXT_INTEGER | CONTEXT_SEGMENTS,
+    } {
+        mx_csr: u32 = 0x34,
+        seg_cs: u16 = 0x38,
+        seg_ds: u16 = 0x3A,
+        seg_es: u16 = 0x3C,
+        seg_fs: u16 = 0x3E,
+        seg_gs: u16 = 0x40,
+        seg_ss: u16 = 0x42,
+        eflags: u32 = 0x44,
+        rax: u64 = 0x78,
+        rcx: u64 = 0x80,
+        rdx: u64 = 0x88,
+        rbx: u64 = 0x90,
+        rsp: u64 = 0x98,
+        rbp: u64 = 0xA0,
+        rsi: u64 = 0xA8,
+        rdi: u64 = 0xB0,
+        r8: u64 = 0xB8,
+        r9: u64 = 0xC0,
+        r10: u64 = 0xC8,
+        r11: u64 = 0xD0,
+        r12: u64 = 0xD8,
+        r13: u64 = 0xE0,
+        r14: u64 = 0xE8,
+        r15: u64 = 0xF0,
+        rip: u64 = 0xF8,
+    }
+);
+
+context_view!(
+    /// View of the ARM64 CONTEXT Struct with the Control and Integer Registers
+    ///
+    /// # Note
+    ///
+    /// The Debug and Vector Registers are not part of the View, except for the Floating Point Control and Status Registers
+    // TODO: Add the Vector Registers when they are needed
+    pub struct ContextArm64, ContextArm64Builder {
+        architecture: Context::Arm64,
+        size: 912,
+        flags_offset: 0,
+        default_flags: CONTEXT_ARM64 | CONTEXT_CONTROL | CONTEXT_INTEGER,
+    } {
+        cpsr: u32 = 0x4,
+        /// The General Purpose Registers X0 to X28
+        x: [u64; 29] = 0x8,
+        /// The Frame Pointer, X29
+        fp: u64 = 0xF0,
+        /// The Link Register, X30
+        lr: u64 = 0xF8,
+        sp: u64 = 0x100,
+        pc: u64 = 0x108,
+        fpcr: u32 = 0x310,
+        fpsr: u32 = 0x314,
+    }
+);
+
+/// Safe View of the CONTEXT Struct, which holds the Register State of a Thread
+///
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-context)
+///
+/// # Note
+///
+/// The Layout of the CONTEXT Struct depends on the Architecture, so every Architecture has its own View.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Context {
+    X86(ContextX86),
+    Amd64(ContextAmd64),
+    Arm64(ContextArm64),
+}
+
+impl Context {
+    /// Returns the Architecture of the Context
+    #[inline]
+    pub fn architecture(&self) -> Architecture {
+        return match self {
+            Context::X86(_) => Architecture::X86,
+            Context::Amd64(_) => Architecture::Amd64,
+            Context::Arm64(_) => Architecture::Arm64,
+        };
+    }
+
+    /// Returns the Address of the next Instruction
+    #[inline]
+    pub fn instruction_pointer(&self) -> u64 {
+        return match self {
+            Context::X86(context) => context.eip as u64,
+            Context::Amd64(context) => context.rip,
+            Context::Arm64(context) => context.pc,
+        };
+    }
+
+    /// Returns the Address of the Top of the Stack
+    #[inline]
+    pub fn stack_pointer(&self) -> u64 {
+        return match self {
+            Context::X86(context) => context.esp as u64,
+            Context::Amd64(context) => context.rsp,
+            Context::Arm64(context) => context.sp,
+        };
+    }
+
+    /// Decodes a Context from the raw Bytes of the CONTEXT Struct of an Architecture
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The Bytes of the Context
+    /// * `architecture` - The Architecture the Context belongs to
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Bytes are too small or the Flags belong to a different Architecture
+    pub fn from_bytes(bytes: &[u8], architecture: Architecture) -> Result<Context, Error> {
+        return match architecture {
+            Architecture::X86 => ContextX86::from_bytes(bytes).map(Context::X86),
+            Architecture::Amd64 => ContextAmd64::from_bytes(bytes).map(Context::Amd64),
+            Architecture::Arm64 => ContextArm64::from_bytes(bytes).map(Context::Arm64),
+        };
+    }
+
+    /// Encodes the Context into the raw Bytes of the CONTEXT Struct of its Architecture
+    #[inline]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        return match self {
+            Context::X86(context) => context.to_bytes(),
+            Context::Amd64(context) => context.to_bytes(),
+            Context::Arm64(context) => context.to_bytes(),
+        };
+    }
+
+    /// Writes the Flags and Registers of the View into the raw Bytes of a CONTEXT Struct, leaving all other Registers unchanged
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Bytes are too small for the Layout of the Architecture
+    #[inline]
+    pub fn write_registers(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        return match self {
+            Context::X86(context) => context.write_registers(bytes),
+            Context::Amd64(context) => context.write_registers(bytes),
+            Context::Arm64(context) => context.write_registers(bytes),
+        };
+    }
+
+    /// Reads a Context from a CONTEXT of the running Process
+    ///
+    /// # Safety
+    ///
+    /// The Pointer must point to a valid CONTEXT
+    #[cfg(windows)]
+    pub unsafe fn from_raw(context: *const winapi::um::winnt::CONTEXT) -> Context {
+        let bytes = std::slice::from_raw_parts(context as *const u8, std::mem::size_of::<winapi::um::winnt::CONTEXT>());
+        let architecture = Architecture::native().expect("The Architecture of the Process has no known Context Layout");
+        return Context::from_bytes(bytes, architecture).expect("A CONTEXT of the Process does not match its Architecture");
+    }
+
+    /// Writes the Registers of the View back into a CONTEXT of the running Process, leaving all other Registers unchanged
+    ///
+    /// # Safety
+    ///
+    /// The Pointer must point to a valid CONTEXT, that can be written
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Context belongs to a different Architecture than the Process
+    #[cfg(windows)]
+    pub(crate) unsafe fn write_to_raw(&self, context: *mut winapi::um::winnt::CONTEXT) -> Result<(), Error> {
+        if Architecture::native() != Some(self.architecture()) {
+            return Err(Error::new(ErrorKind::InvalidArgument("the context belongs to a different architecture than the process")));
+        }
+        let bytes = std::slice::from_raw_parts_mut(context as *mut u8, std::mem::size_of::<winapi::um::winnt::CONTEXT>());
+        return self.write_registers(bytes);
+    }
+
+    /// Converts the Context into a CONTEXT of the running Process
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Context belongs to a different Architecture than the Process
+    #[cfg(windows)]
+    pub(crate) fn to_native(&self) -> Result<winapi::um::winnt::CONTEXT, Error> {
+        if Architecture::native() != Some(self.architecture()) {
+            return Err(Error::new(ErrorKind::InvalidArgument("the context belongs to a different architecture than the process")));
+        }
+        let bytes = self.to_bytes();
+        let mut native: winapi::um::winnt::CONTEXT = unsafe { std::mem::zeroed() };
+        assert_eq!(bytes.len(), std::mem::size_of::<winapi::um::winnt::CONTEXT>());
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), &mut native as *mut winapi::um::winnt::CONTEXT as *mut u8, bytes.len());
+        }
+        return Ok(native);
+    }
+}
+
+/// Tests for the Decoding and Encoding of Contexts
+#[cfg(test)]
+mod test_context {
+    use crate::error::ErrorKind;
+    use crate::type_wrappers::exception::Architecture;
+    use crate::type_wrappers::exception::context::*;
+    use crate::type_wrappers::exception::fixture;
+
+    /// Tests that an x64 CONTEXT is decoded
+    #[test]
+    fn test_decode_amd64() {
+        let bytes = fixture(ContextAmd64::SIZE, &[
+            (0x30, &(CONTEXT_AMD64 | CONTEXT_CONTROL | CONTEXT_INTEGER).to_le_bytes()),
+            (0x38, &0x33u16.to_le_bytes()),
+            (0x44, &0x246u32.to_le_bytes()),
+            (0x78, &1u64.to_le_bytes()),
+            (0x98, &0x0000_00C0_FFEE_0000u64.to_le_bytes()),
+            (0xF0, &15u64.to_le_bytes()),
+            (0xF8, &0x0000_7FF6_1234_5678u64.to_le_bytes()),
+        ]);
+        let context = Context::from_bytes(&bytes, Architecture::Amd64).unwrap();
+        assert_eq!(context.architecture(), Architecture::Amd64);
+        assert_eq!(context.instruction_pointer(), 0x0000_7FF6_1234_5678);
+        assert_eq!(context.stack_pointer(), 0x0000_00C0_FFEE_0000);
+        match context {
+            Context::Amd64(registers) => {
+                assert_eq!(registers.seg_cs, 0x33);
+                assert_eq!(registers.eflags, 0x246);
+                assert_eq!(registers.rax, 1);
+                assert_eq!(registers.r15, 15);
+            }
+            _ => panic!("The Context was not decoded as x64"),
+        }
+    }
+
+    /// Tests that an x86 CONTEXT is decoded
+    #[test]
+    fn test_decode_x86() {
+        let bytes = fixture(ContextX86::SIZE, &[
+            (0, &(CONTEXT_i386 | CONTEXT_CONTROL).to_le_bytes()),
+            (176, &0xAAu32.to_le_bytes()),
+            (184, &0x0040_1000u32.to_le_bytes()),
+            (196, &0x0019_FF00u32.to_le_bytes()),
+        ]);
+        let context = ContextX86::from_bytes(&bytes).unwrap();
+        assert_eq!(context.eax, 0xAA);
+        assert_eq!(Context::from(context).instruction_pointer(), 0x0040_1000);
+        assert_eq!(Context::from(context).stack_pointer(), 0x0019_FF00);
+    }
+
+    /// Tests that an ARM64 CONTEXT is decoded
+    #[test]
+    fn test_decode_arm64() {
+        let bytes = fixture(ContextArm64::SIZE, &[
+            (0, &(CONTEXT_ARM64 | CONTEXT_CONTROL | CONTEXT_INTEGER).to_le_bytes()),
+            (0x8, &10u64.to_le_bytes()),
+            (0x8 + 28 * 8, &28u64.to_le_bytes()),
+            (0xF8, &0x4000u64.to_le_bytes()),
+            (0x100, &0x8000u64.to_le_bytes()),
+            (0x108, &0x1234u64.to_le_bytes()),
+        ]);
+        let context = ContextArm64::from_bytes(&bytes).unwrap();
+        assert_eq!(context.x[0], 10);
+        assert_eq!(context.x[28], 28);
+        assert_eq!(context.lr, 0x4000);
+        assert_eq!(Context::from(context).stack_pointer(), 0x8000);
+        assert_eq!(Context::from(context).instruction_pointer(), 0x1234);
+    }
+
+    /// Tests that Contexts of a different Architecture and too small Buffers are rejected
+    #[test]
+    fn test_decode_invalid() {
+        let bytes = fixture(ContextAmd64::SIZE, &[(0x30, &CONTEXT_ARM64.to_le_bytes())]);
+        let error = ContextAmd64::from_bytes(&bytes).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidArgument("the context flags belong to a different architecture"));
+        assert!(ContextArm64::from_bytes(&[0u8; 16]).is_err());
+    }
+
+    /// Tests that writing the Registers of a View leaves the other Registers unchanged
+    #[test]
+    fn test_write_registers() {
+        let mut bytes = vec![0xFFu8; ContextAmd64::SIZE];
+        let context = Context::from(ContextAmd64::builder().rip(0x1000).build());
+        context.write_registers(&mut bytes).unwrap();
+        assert_eq!(bytes[0x100], 0xFF);
+        assert_eq!(Context::from_bytes(&bytes, Architecture::Amd64).unwrap().instruction_pointer(), 0x1000);
+        assert!(context.write_registers(&mut [0u8; 16]).is_err());
+    }
+
+    /// Tests that built Contexts round trip through the raw Layouts
+    #[test]
+    fn test_builder_round_trip() {
+        let context = ContextAmd64::builder().rip(0x1000).rsp(0x2000).seg_ss(0x2B).build();
+        assert_eq!(context.context_flags, CONTEXT_AMD64 | CONTEXT_CONTROL | CONTEXT_INTEGER | CONTEXT_SEGMENTS);
+        assert_eq!(ContextAmd64::from_bytes(&context.to_bytes()), Ok(context));
+
+        let context = Context::from(ContextArm64::builder().x([7; 29]).pc(0x10).build());
+        assert_eq!(Context::from_bytes(&context.to_bytes(), Architecture::Arm64), Ok(context));
+
+        let context = Context::from(ContextX86::builder().eip(0x10).build());
+        assert_eq!(Context::from_bytes(&context.to_bytes(), Architecture::X86), Ok(context));
+    }
+}
+
+/// Tests for the Constants defined in this Module
+#[cfg(all(test, windows))]
+mod test_constants {
+    use crate::type_wrappers::exception::Architecture;
+    use crate::type_wrappers::exception::context::*;
+    use crate::type_wrappers::exception::fixture;
+
+    /// Tests that the Constants and the Size of the native Layout match the Definitions of the winnt.h Header in WINAPI
+    #[test]
+    fn test_match_winapi() {
+        assert_eq!(CONTEXT_CONTROL, winapi::um::winnt::CONTEXT_CONTROL & !CONTEXT_ARCHITECTURE_MASK);
+        assert_eq!(CONTEXT_INTEGER, winapi::um::winnt::CONTEXT_INTEGER & !CONTEXT_ARCHITECTURE_MASK);
+        let size = match Architecture::native() {
+            Some(Architecture::X86) => ContextX86::SIZE,
+            Some(Architecture::Amd64) => ContextAmd64::SIZE,
+            Some(Architecture::Arm64) => ContextArm64::SIZE,
+            None => return,
+        };
+        assert_eq!(size, std::mem::size_of::<winapi::um::winnt::CONTEXT>());
+    }
+}
@@ -0,0 +1,164 @@
+use crate::error::{Error, ErrorKind, NtStatus};
+use crate::type_wrappers::exception::{ExceptionRecord, ExceptionRecordBuilder};
+use crate::type_wrappers::exception::exception_record::EXCEPTION_MAXIMUM_PARAMETERS;
+
+/// The Customer Bit of Exception Codes, which marks Codes that are defined by Applications
+pub const CUSTOMER_BIT: u32 = 0x2000_0000;
+
+/// Application defined Exceptions, that carry a Rust Value in their Parameters
+///
+/// The Value is serialized into at most `EXCEPTION_MAXIMUM_PARAMETERS` Parameters when it is raised and deserialized again by the Handlers.
+/// Only the Parameters are passed, so Values must be encoded by Value and never as Pointers to Rust Data.
+///
+/// # Example
+///
+/// ```
+/// use windings::exception::{CustomException, ExceptionRecord};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct QuotaExceeded {
+///     requested: u64,
+///     available: u64,
+/// }
+///
+/// impl CustomException for QuotaExceeded {
+///     const CODE: u32 = 0xE000_0101;
+///
+///     fn to_parameters(&self) -> Vec<u64> {
+///         return vec![self.requested, self.available];
+///     }
+///
+///     fn from_parameters(parameters: &[u64]) -> Option<QuotaExceeded> {
+///         return match parameters {
+///             [requested, available] => Some(QuotaExceeded { requested: *requested, available: *available }),
+///             _ => None,
+///         };
+///     }
+/// }
+///
+/// let exception = QuotaExceeded { requested: 4096, available: 1024 };
+/// let record = ExceptionRecord::builder_from_custom(&exception).unwrap().build();
+/// assert_eq!(record.downcast::<QuotaExceeded>(), Some(exception));
+/// ```
+pub trait CustomException: Sized {
+    /// The Exception Code, which has to have the Customer Bit set and identifies the Type
+    const CODE: u32;
+
+    /// Serializes the Value into the Parameters of the Exception
+    fn to_parameters(&self) -> Vec<u64>;
+
+    /// Deserializes the Value from the Parameters of the Exception
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the Parameters do not encode a Value of the Type
+    fn from_parameters(parameters: &[u64]) -> Option<Self>;
+}
+
+impl ExceptionRecord {
+    /// Creates a Builder for a new Record that carries a Custom Exception
+    ///
+    /// # Arguments
+    ///
+    /// * `exception` - The Value, which determines the Exception Code and the Parameters
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Code of the Type lacks the Customer Bit or the Value needs more than `EXCEPTION_MAXIMUM_PARAMETERS` Parameters
+    pub fn builder_from_custom<E: CustomException>(exception: &E) -> Result<ExceptionRecordBuilder, Error> {
+        if E::CODE & CUSTOMER_BIT == 0 {
+            return Err(Error::new(ErrorKind::InvalidArgument("custom exception codes must have the customer bit set")));
+        }
+        let parameters = exception.to_parameters();
+        if parameters.len() > EXCEPTION_MAXIMUM_PARAMETERS {
+            return Err(Error::new(ErrorKind::InvalidArgument("the number of exception parameters is too large")));
+        }
+        let mut builder = ExceptionRecord::builder(NtStatus::from(E::CODE));
+        for parameter in parameters {
+            builder = builder.parameter(parameter)?;
+        }
+        return Ok(builder);
+    }
+
+    /// Deserializes the Custom Exception carried by the Record
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the Record has a different Exception Code or its Parameters do not encode a Value of the Type
+    pub fn downcast<E: CustomException>(&self) -> Option<E> {
+        if self.code() != NtStatus::from(E::CODE) {
+            return None;
+        }
+        return E::from_parameters(self.parameters());
+    }
+}
+
+/// Tests for the Serialization of Custom Exceptions
+#[cfg(test)]
+mod test_custom_exception {
+    use crate::error::NtStatus;
+    use crate::type_wrappers::exception::{CustomException, ExceptionRecord};
+    use crate::type_wrappers::exception::exception_record::EXCEPTION_MAXIMUM_PARAMETERS;
+    use std::convert::TryFrom;
+
+    #[derive(Debug, PartialEq)]
+    struct Checksum {
+        bytes: Vec<u8>,
+    }
+
+    impl CustomException for Checksum {
+        const CODE: u32 = 0xE000_0042;
+
+        fn to_parameters(&self) -> Vec<u64> {
+            return self.bytes.iter().map(|byte| *byte as u64).collect();
+        }
+
+        fn from_parameters(parameters: &[u64]) -> Option<Checksum> {
+            return parameters.iter().map(|parameter| u8::try_from(*parameter).ok()).collect::<Option<Vec<u8>>>().map(|bytes| Checksum { bytes });
+        }
+    }
+
+    struct SystemCode;
+
+    impl CustomException for SystemCode {
+        const CODE: u32 = 0xC000_0005;
+
+        fn to_parameters(&self) -> Vec<u64> {
+            return Vec::new();
+        }
+
+        fn from_parameters(_parameters: &[u64]) -> Option<SystemCode> {
+            return Some(SystemCode);
+        }
+    }
+
+    /// Tests that Values round trip through Records up to the maximum Number of Parameters
+    #[test]
+    fn test_round_trip() {
+        for length in [0, 1, EXCEPTION_MAXIMUM_PARAMETERS].iter() {
+            let checksum = Checksum { bytes: (0..*length as u8).collect() };
+            let record = ExceptionRecord::builder_from_custom(&checksum).unwrap().build();
+            assert_eq!(record.code(), NtStatus::from(Checksum::CODE));
+            assert_eq!(record.parameters().len(), *length);
+            assert_eq!(record.downcast::<Checksum>(), Some(checksum));
+        }
+    }
+
+    /// Tests that Values with too many Parameters and Codes without the Customer Bit are rejected
+    #[test]
+    fn test_encoding_limits() {
+        let checksum = Checksum { bytes: vec![0; EXCEPTION_MAXIMUM_PARAMETERS + 1] };
+        assert!(ExceptionRecord::builder_from_custom(&checksum).is_err());
+        assert!(ExceptionRecord::builder_from_custom(&SystemCode).is_err());
+    }
+
+    /// Tests that Records of other Codes or with invalid Parameters are not downcast
+    #[test]
+    fn test_downcast_mismatch() {
+        let other = ExceptionRecord::builder(NtStatus::from(0xE000_0043u32)).parameter(1).unwrap().build();
+        assert_eq!(other.downcast::<Checksum>(), None);
+
+        let invalid = ExceptionRecord::builder(NtStatus::from(Checksum::CODE)).parameter(256).unwrap().build();
+        assert_eq!(invalid.downcast::<Checksum>(), None);
+    }
+}
@@ -0,0 +1,562 @@
+#![allow(non_camel_case_types)]
+
+use std::convert::TryFrom;
+use crate::error::{Error, ErrorKind, NtStatus};
+use crate::type_wrappers::exception::{check_size, Architecture, LayoutField};
+use crate::type_wrappers::type_conversion::win_types::DWORD;
+
+// Values of the winnt.h Header, defined here so that the Bitflags are available on every Host
+const EXCEPTION_NONCONTINUABLE: DWORD = 0x01;
+const EXCEPTION_UNWINDING: DWORD = 0x02;
+const EXCEPTION_EXIT_UNWIND: DWORD = 0x04;
+const EXCEPTION_STACK_INVALID: DWORD = 0x08;
+const EXCEPTION_NESTED_CALL: DWORD = 0x10;
+const EXCEPTION_TARGET_UNWIND: DWORD = 0x20;
+const EXCEPTION_COLLIDED_UNWIND: DWORD = 0x40;
+
+/// The maximum Number of Parameters of an Exception Record
+pub const EXCEPTION_MAXIMUM_PARAMETERS: usize = 15;
+
+/// The Size of the EXCEPTION_RECORD32 Layout, used by x86
+pub const EXCEPTION_RECORD32_SIZE: usize = 80;
+
+/// The Size of the EXCEPTION_RECORD64 Layout, used by x64 and ARM64
+pub const EXCEPTION_RECORD64_SIZE: usize = 152;
+
+// Exception Codes with well-known Parameter Layouts
+const STATUS_ACCESS_VIOLATION: u32 = 0xC000_0005;
+const STATUS_IN_PAGE_ERROR: u32 = 0xC000_0006;
+const STATUS_STACK_BUFFER_OVERRUN: u32 = 0xC000_0409;
+
+NAMED_BITFLAGS!(
+    /// Exception Flags Bitflags
+    pub struct EXCEPTION_FLAGS : DWORD {
+        const NONCONTINUABLE = EXCEPTION_NONCONTINUABLE;
+        const UNWINDING = EXCEPTION_UNWINDING;
+        const EXIT_UNWIND = EXCEPTION_EXIT_UNWIND;
+        const STACK_INVALID = EXCEPTION_STACK_INVALID;
+        const NESTED_CALL = EXCEPTION_NESTED_CALL;
+        const TARGET_UNWIND = EXCEPTION_TARGET_UNWIND;
+        const COLLIDED_UNWIND = EXCEPTION_COLLIDED_UNWIND;
+});
+
+/// The Operation that caused an Access Violation or In-Page Error
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AccessOperation {
+    /// The Thread tried to read the Target Address
+    Read,
+
+    /// The Thread tried to write the Target Address
+    Write,
+
+    /// The Thread tried to execute the Target Address, which was prevented by Data Execution Prevention
+    Execute,
+
+    /// An Operation that is not documented
+    Unknown(u64),
+}
+
+impl From<u64> for AccessOperation {
+    #[inline]
+    fn from(value: u64) -> AccessOperation {
+        return match value {
+            0 => AccessOperation::Read,
+            1 => AccessOperation::Write,
+            8 => AccessOperation::Execute,
+            _ => AccessOperation::Unknown(value),
+        };
+    }
+}
+
+impl From<AccessOperation> for u64 {
+    #[inline]
+    fn from(operation: AccessOperation) -> u64 {
+        return match operation {
+            AccessOperation::Read => 0,
+            AccessOperation::Write => 1,
+            AccessOperation::Execute => 8,
+            AccessOperation::Unknown(value) => value,
+        };
+    }
+}
+
+/// The decoded Parameters of Exceptions with well-known Parameter Layouts
+///
+/// See the Documentation of the Layouts [here](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-exception_record)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ExceptionDetails {
+    /// `STATUS_ACCESS_VIOLATION`, with the Operation and the inaccessible Address
+    AccessViolation { operation: AccessOperation, target: u64 },
+
+    /// `STATUS_IN_PAGE_ERROR`, with the Operation, the inaccessible Address and the Status of the failed Paging
+    InPageError { operation: AccessOperation, target: u64, status: NtStatus },
+
+    /// `STATUS_STACK_BUFFER_OVERRUN`, which is raised by `__fastfail` with the Fast Fail Code, like `FAST_FAIL_STACK_COOKIE_CHECK_FAILURE`
+    FastFail { code: u64 },
+}
+
+impl ExceptionDetails {
+    /// Returns the Exception Code of the Details
+    #[inline]
+    pub fn code(&self) -> NtStatus {
+        return NtStatus::from(match self {
+            ExceptionDetails::AccessViolation { .. } => STATUS_ACCESS_VIOLATION,
+            ExceptionDetails::InPageError { .. } => STATUS_IN_PAGE_ERROR,
+            ExceptionDetails::FastFail { .. } => STATUS_STACK_BUFFER_OVERRUN,
+        });
+    }
+
+    /// Returns the Parameters that encode the Details
+    pub fn parameters(&self) -> Vec<u64> {
+        return match *self {
+            ExceptionDetails::AccessViolation { operation, target } => vec![operation.into(), target],
+            ExceptionDetails::InPageError { operation, target, status } => vec![operation.into(), target, u32::from(status) as u64],
+            ExceptionDetails::FastFail { code } => vec![code],
+        };
+    }
+
+    /// Decodes the Parameters of an Exception
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The Exception Code
+    /// * `parameters` - The Parameters of the Exception
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the Exception Code has no well-known Layout or there are too few Parameters for it
+    pub fn decode(code: NtStatus, parameters: &[u64]) -> Option<ExceptionDetails> {
+        return match (u32::from(code), parameters) {
+            (STATUS_ACCESS_VIOLATION, [operation, target, ..]) => {
+                Some(ExceptionDetails::AccessViolation { operation: AccessOperation::from(*operation), target: *target })
+            }
+            (STATUS_IN_PAGE_ERROR, [operation, target, status, ..]) => Some(ExceptionDetails::InPageError {
+                operation: AccessOperation::from(*operation),
+                target: *target,
+                status: NtStatus::from(*status as u32),
+            }),
+            (STATUS_STACK_BUFFER_OVERRUN, [code, ..]) => Some(ExceptionDetails::FastFail { code: *code }),
+            _ => None,
+        };
+    }
+}
+
+/// Safe View of the EXCEPTION_RECORD Struct
+///
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-exception_record)
+///
+/// # Note
+///
+/// Addresses and Parameters are stored as 64-Bit Values, so Records of every Architecture can be represented on every Host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExceptionRecord {
+    code: NtStatus,
+    flags: EXCEPTION_FLAGS,
+    address: u64,
+    nested: Option<Box<ExceptionRecord>>,
+    parameters: Vec<u64>,
+}
+
+impl ExceptionRecord {
+    /// Creates a Builder for a new Record
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The Exception Code. Application defined Codes should have the Customer Bit set
+    #[inline]
+    pub fn builder(code: NtStatus) -> ExceptionRecordBuilder {
+        return ExceptionRecordBuilder {
+            record: ExceptionRecord { code, flags: EXCEPTION_FLAGS::empty(), address: 0, nested: None, parameters: Vec::new() },
+        };
+    }
+
+    /// Creates a Builder for a new Record with a well-known Parameter Layout
+    ///
+    /// # Arguments
+    ///
+    /// * `details` - The Details, which determine the Exception Code and the Parameters
+    #[inline]
+    pub fn builder_from_details(details: ExceptionDetails) -> ExceptionRecordBuilder {
+        let mut builder = ExceptionRecord::builder(details.code());
+        builder.record.parameters = details.parameters();
+        return builder;
+    }
+
+    /// Returns the Exception Code
+    #[inline]
+    pub fn code(&self) -> NtStatus {
+        return self.code;
+    }
+
+    /// Returns the Exception Flags
+    #[inline]
+    pub fn flags(&self) -> EXCEPTION_FLAGS {
+        return self.flags;
+    }
+
+    /// Returns whether Execution can continue after the Exception
+    #[inline]
+    pub fn is_continuable(&self) -> bool {
+        return !self.flags.contains(EXCEPTION_FLAGS::NONCONTINUABLE);
+    }
+
+    /// Returns the Address where the Exception occurred
+    #[inline]
+    pub fn address(&self) -> u64 {
+        return self.address;
+    }
+
+    /// Returns the Record of an Exception that occurred while this Exception was handled
+    #[inline]
+    pub fn nested(&self) -> Option<&ExceptionRecord> {
+        return self.nested.as_deref();
+    }
+
+    /// Returns the Parameters of the Exception
+    #[inline]
+    pub fn parameters(&self) -> &[u64] {
+        return &self.parameters;
+    }
+
+    /// Decodes the Parameters, if the Exception Code has a well-known Parameter Layout
+    #[inline]
+    pub fn details(&self) -> Option<ExceptionDetails> {
+        return ExceptionDetails::decode(self.code, &self.parameters);
+    }
+
+    /// Decodes a Record from the raw Bytes of an EXCEPTION_RECORD32 or EXCEPTION_RECORD64
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The Bytes of the Record
+    /// * `architecture` - The Architecture the Record belongs to, x86 uses EXCEPTION_RECORD32 and the others EXCEPTION_RECORD64
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Bytes are too small or the Number of Parameters is too large
+    ///
+    /// # Note
+    ///
+    /// The Pointer to the nested Record is not followed, since it refers to the Memory of the Process the Bytes were taken from.
+    /// Nested Records can be attached with the Builder.
+    pub fn from_bytes(bytes: &[u8], architecture: Architecture) -> Result<ExceptionRecord, Error> {
+        let pointer_size = architecture.pointer_size();
+        let size = match pointer_size {
+            4 => EXCEPTION_RECORD32_SIZE,
+            _ => EXCEPTION_RECORD64_SIZE,
+        };
+        check_size(bytes, size)?;
+        let pointer = |offset: usize| -> u64 {
+            return match pointer_size {
+                4 => u32::read(bytes, offset) as u64,
+                _ => u64::read(bytes, offset),
+            };
+        };
+        // The Code and Flags are followed by the nested Record, the Address and the Number of Parameters, which is padded to the Pointer Size
+        let address_offset = 8 + pointer_size;
+        let count_offset = address_offset + pointer_size;
+        let parameters_offset = count_offset + pointer_size;
+
+        let count = u32::read(bytes, count_offset) as usize;
+        if count > EXCEPTION_MAXIMUM_PARAMETERS {
+            return Err(Error::new(ErrorKind::InvalidArgument("the number of exception parameters is too large")));
+        }
+        return Ok(ExceptionRecord {
+            code: NtStatus::from(u32::read(bytes, 0)),
+            flags: EXCEPTION_FLAGS::from_bits_truncate(u32::read(bytes, 4)),
+            address: pointer(address_offset),
+            nested: None,
+            parameters: (0..count).map(|index| pointer(parameters_offset + index * pointer_size)).collect(),
+        });
+    }
+
+    /// Encodes the Record into the raw Bytes of an EXCEPTION_RECORD32 or EXCEPTION_RECORD64
+    ///
+    /// # Arguments
+    ///
+    /// * `architecture` - The Architecture whose Layout should be used
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Address or a Parameter does not fit into a 32-Bit Layout.
+    /// The Pointer to the nested Record is always written as Null.
+    pub fn to_bytes(&self, architecture: Architecture) -> Result<Vec<u8>, Error> {
+        let pointer_size = architecture.pointer_size();
+        let size = match pointer_size {
+            4 => EXCEPTION_RECORD32_SIZE,
+            _ => EXCEPTION_RECORD64_SIZE,
+        };
+        let write_pointer = |bytes: &mut [u8], offset: usize, value: u64| -> Result<(), Error> {
+            match pointer_size {
+                4 => u32::try_from(value)
+                    .map_err(|_| Error::new(ErrorKind::InvalidArgument("the value does not fit into a 32-bit pointer")))?
+                    .write(bytes, offset),
+                _ => value.write(bytes, offset),
+            }
+            return Ok(());
+        };
+
+        let mut bytes = vec![0u8; size];
+        u32::from(self.code).write(&mut bytes, 0);
+        self.flags.bits().write(&mut bytes, 4);
+        write_pointer(&mut bytes, 8 + pointer_size, self.address)?;
+        (self.parameters.len() as u32).write(&mut bytes, 8 + 2 * pointer_size);
+        for (index, parameter) in self.parameters.iter().enumerate() {
+            write_pointer(&mut bytes, 8 + 3 * pointer_size + index * pointer_size, *parameter)?;
+        }
+        return Ok(bytes);
+    }
+
+    /// Reads a Record and its nested Records from an EXCEPTION_RECORD of the running Process
+    ///
+    /// # Safety
+    ///
+    /// The Pointer must point to a valid EXCEPTION_RECORD, whose nested Records are valid as well
+    #[cfg(windows)]
+    pub unsafe fn from_raw(record: *const winapi::um::winnt::EXCEPTION_RECORD) -> ExceptionRecord {
+        let raw = &*record;
+        let count = (raw.NumberParameters as usize).min(EXCEPTION_MAXIMUM_PARAMETERS);
+        return ExceptionRecord {
+            code: NtStatus::from(raw.ExceptionCode),
+            flags: EXCEPTION_FLAGS::from_bits_truncate(raw.ExceptionFlags),
+            address: raw.ExceptionAddress as usize as u64,
+            nested: match raw.ExceptionRecord.is_null() {
+                true => None,
+                false => Some(Box::new(ExceptionRecord::from_raw(raw.ExceptionRecord))),
+            },
+            parameters: raw.ExceptionInformation[..count].iter().map(|parameter| *parameter as u64).collect(),
+        };
+    }
+
+    /// Converts the Record and its nested Records into linked EXCEPTION_RECORDs of the running Process
+    ///
+    /// # Return
+    ///
+    /// This Function returns the Records in the Order of Nesting, each one pointing to the next one.
+    /// The Pointers stay valid as long as the returned Vector is neither dropped nor resized.
+    /// The Pointer to the first Record has to be taken with `as_mut_ptr`, since a Reference into the Vector invalidates the Pointers.
+    /// This Function returns `ErrorKind::InvalidArgument` if the Address or a Parameter does not fit into a Pointer of the Process.
+    #[cfg(windows)]
+    pub(crate) fn to_native_chain(&self) -> Result<Vec<winapi::um::winnt::EXCEPTION_RECORD>, Error> {
+        let to_pointer = |value: u64| usize::try_from(value)
+            .map_err(|_| Error::new(ErrorKind::InvalidArgument("the value does not fit into a pointer of the process")));
+
+        let mut records = Vec::new();
+        let mut current = Some(self);
+        while let Some(record) = current {
+            let mut information = [0usize; EXCEPTION_MAXIMUM_PARAMETERS];
+            for (slot, parameter) in information.iter_mut().zip(record.parameters.iter()) {
+                *slot = to_pointer(*parameter)?;
+            }
+            records.push(winapi::um::winnt::EXCEPTION_RECORD {
+                ExceptionCode: record.code.into(),
+                ExceptionFlags: record.flags.bits(),
+                ExceptionRecord: std::ptr::null_mut(),
+                ExceptionAddress: to_pointer(record.address)? as winapi::um::winnt::PVOID,
+                NumberParameters: record.parameters.len() as u32,
+                ExceptionInformation: information,
+            });
+            current = record.nested();
+        }
+        // The Vector is not resized anymore, so all Pointers are derived from its Base Pointer and stay valid.
+        // Taking them from `&mut records[index]` would invalidate the earlier ones with every following Write.
+        let base = records.as_mut_ptr();
+        for index in 1..records.len() {
+            unsafe {
+                (*base.add(index - 1)).ExceptionRecord = base.add(index);
+            }
+        }
+        return Ok(records);
+    }
+}
+
+/// Builder for Exception Records
+///
+/// # Example
+///
+/// ```ignore
+/// let record = ExceptionRecord::builder(NtStatus(0xE0000001u32 as i32)).flags(EXCEPTION_FLAGS::NONCONTINUABLE).parameter(42)?.build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExceptionRecordBuilder {
+    record: ExceptionRecord,
+}
+
+impl ExceptionRecordBuilder {
+    /// Sets the Exception Flags
+    #[inline]
+    pub fn flags(mut self, flags: EXCEPTION_FLAGS) -> ExceptionRecordBuilder {
+        self.record.flags = flags;
+        return self;
+    }
+
+    /// Sets the Address where the Exception occurred
+    #[inline]
+    pub fn address(mut self, address: u64) -> ExceptionRecordBuilder {
+        self.record.address = address;
+        return self;
+    }
+
+    /// Sets the Record of an Exception that occurred while this Exception was handled
+    #[inline]
+    pub fn nested(mut self, nested: ExceptionRecord) -> ExceptionRecordBuilder {
+        self.record.nested = Some(Box::new(nested));
+        return self;
+    }
+
+    /// Appends a Parameter
+    ///
+    /// # Return
+    ///
+    /// This Function returns `ErrorKind::InvalidArgument` if the Record already holds `EXCEPTION_MAXIMUM_PARAMETERS` Parameters
+    pub fn parameter(mut self, parameter: u64) -> Result<ExceptionRecordBuilder, Error> {
+        if self.record.parameters.len() >= EXCEPTION_MAXIMUM_PARAMETERS {
+            return Err(Error::new(ErrorKind::InvalidArgument("the number of exception parameters is too large")));
+        }
+        self.record.parameters.push(parameter);
+        return Ok(self);
+    }
+
+    /// Finishes the Record
+    #[inline]
+    pub fn build(self) -> ExceptionRecord {
+        return self.record;
+    }
+}
+
+/// Tests for the Decoding and Encoding of Exception Records
+#[cfg(test)]
+mod test_exception_record {
+    use crate::error::{ErrorKind, NtStatus};
+    use crate::type_wrappers::exception::Architecture;
+    use crate::type_wrappers::exception::exception_record::*;
+    use crate::type_wrappers::exception::fixture;
+
+    /// Tests that an EXCEPTION_RECORD64 of a write Access Violation is decoded
+    #[test]
+    fn test_decode_access_violation_64() {
+        let bytes = fixture(EXCEPTION_RECORD64_SIZE, &[
+            (0, &0xC000_0005u32.to_le_bytes()),
+            (4, &EXCEPTION_NONCONTINUABLE.to_le_bytes()),
+            (8, &0x0000_7FF6_1000_0000u64.to_le_bytes()),
+            (16, &0x0000_7FF6_1234_5678u64.to_le_bytes()),
+            (24, &2u32.to_le_bytes()),
+            (32, &1u64.to_le_bytes()),
+            (40, &0xDEAD_BEEFu64.to_le_bytes()),
+        ]);
+        let record = ExceptionRecord::from_bytes(&bytes, Architecture::Amd64).unwrap();
+        assert_eq!(record.code().name(), Some("STATUS_ACCESS_VIOLATION"));
+        assert!(!record.is_continuable());
+        assert_eq!(record.address(), 0x0000_7FF6_1234_5678);
+        assert_eq!(record.nested(), None);
+        assert_eq!(record.details(), Some(ExceptionDetails::AccessViolation { operation: AccessOperation::Write, target: 0xDEAD_BEEF }));
+    }
+
+    /// Tests that an EXCEPTION_RECORD32 of an Execute Access Violation is decoded
+    #[test]
+    fn test_decode_access_violation_32() {
+        let bytes = fixture(EXCEPTION_RECORD32_SIZE, &[
+            (0, &0xC000_0005u32.to_le_bytes()),
+            (12, &0x0040_1000u32.to_le_bytes()),
+            (16, &2u32.to_le_bytes()),
+            (20, &8u32.to_le_bytes()),
+            (24, &0x0040_1000u32.to_le_bytes()),
+        ]);
+        let record = ExceptionRecord::from_bytes(&bytes, Architecture::X86).unwrap();
+        assert!(record.is_continuable());
+        assert_eq!(record.parameters(), &[8, 0x0040_1000]);
+        assert_eq!(record.details(), Some(ExceptionDetails::AccessViolation { operation: AccessOperation::Execute, target: 0x0040_1000 }));
+    }
+
+    /// Tests that In-Page Errors, Fast Fails and Codes without well-known Layout are decoded
+    #[test]
+    fn test_decode_details() {
+        let in_page = ExceptionDetails::decode(NtStatus::from(0xC000_0006u32), &[0, 0x1000, 0xC000_000E]);
+        assert_eq!(in_page, Some(ExceptionDetails::InPageError {
+            operation: AccessOperation::Read,
+            target: 0x1000,
+            status: NtStatus::from(0xC000_000Eu32),
+        }));
+        assert_eq!(ExceptionDetails::decode(NtStatus::from(0xC000_0409u32), &[2]), Some(ExceptionDetails::FastFail { code: 2 }));
+        assert_eq!(ExceptionDetails::decode(NtStatus::from(0xC000_0005u32), &[1]), None);
+        assert_eq!(ExceptionDetails::decode(NtStatus::from(0xE000_0001u32), &[1, 2]), None);
+        assert_eq!(AccessOperation::from(3), AccessOperation::Unknown(3));
+    }
+
+    /// Tests that too small Buffers and too many Parameters are rejected
+    #[test]
+    fn test_decode_invalid() {
+        let error = ExceptionRecord::from_bytes(&[0u8; EXCEPTION_RECORD64_SIZE - 1], Architecture::Arm64).unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::InvalidArgument(_)));
+
+        let bytes = fixture(EXCEPTION_RECORD32_SIZE, &[(16, &16u32.to_le_bytes())]);
+        assert!(ExceptionRecord::from_bytes(&bytes, Architecture::X86).is_err());
+    }
+
+    /// Tests that built Records round trip through both Layouts
+    #[test]
+    fn test_builder_round_trip() {
+        let nested = ExceptionRecord::builder(NtStatus::from(0xC000_0094u32)).build();
+        let record = ExceptionRecord::builder_from_details(ExceptionDetails::AccessViolation { operation: AccessOperation::Read, target: 0x10 })
+            .flags(EXCEPTION_FLAGS::NONCONTINUABLE)
+            .address(0x0040_2000)
+            .nested(nested.clone())
+            .parameter(7).unwrap()
+            .build();
+        assert_eq!(record.parameters(), &[0, 0x10, 7]);
+        assert_eq!(record.nested(), Some(&nested));
+
+        for architecture in [Architecture::X86, Architecture::Amd64].iter() {
+            let decoded = ExceptionRecord::from_bytes(&record.to_bytes(*architecture).unwrap(), *architecture).unwrap();
+            assert_eq!(decoded.code(), record.code());
+            assert_eq!(decoded.flags(), record.flags());
+            assert_eq!(decoded.address(), record.address());
+            assert_eq!(decoded.parameters(), record.parameters());
+        }
+
+        let wide = ExceptionRecord::builder(NtStatus::from(0xE000_0001u32)).address(1 << 40).build();
+        assert!(wide.to_bytes(Architecture::X86).is_err());
+    }
+
+    /// Tests that the Builder rejects more than EXCEPTION_MAXIMUM_PARAMETERS Parameters
+    #[test]
+    fn test_builder_parameter_limit() {
+        let mut builder = ExceptionRecord::builder(NtStatus::from(0xE000_0001u32));
+        for parameter in 0..EXCEPTION_MAXIMUM_PARAMETERS as u64 {
+            builder = builder.parameter(parameter).unwrap();
+        }
+        assert!(builder.parameter(15).is_err());
+    }
+}
+
+/// Tests for the Constants defined in this Module
+#[cfg(all(test, windows))]
+mod test_constants {
+    use crate::type_wrappers::exception::exception_record::*;
+    use crate::type_wrappers::exception::fixture;
+
+    /// Tests that the Constants and Layouts match the Definitions of the winnt.h Header in WINAPI
+    #[test]
+    fn test_match_winapi() {
+        assert_eq!(EXCEPTION_NONCONTINUABLE, winapi::um::winnt::EXCEPTION_NONCONTINUABLE);
+        assert_eq!(EXCEPTION_UNWINDING, winapi::um::winnt::EXCEPTION_UNWINDING);
+        assert_eq!(EXCEPTION_EXIT_UNWIND, winapi::um::winnt::EXCEPTION_EXIT_UNWIND);
+        assert_eq!(EXCEPTION_STACK_INVALID, winapi::um::winnt::EXCEPTION_STACK_INVALID);
+        assert_eq!(EXCEPTION_NESTED_CALL, winapi::um::winnt::EXCEPTION_NESTED_CALL);
+        assert_eq!(EXCEPTION_TARGET_UNWIND, winapi::um::winnt::EXCEPTION_TARGET_UNWIND);
+        assert_eq!(EXCEPTION_COLLIDED_UNWIND, winapi::um::winnt::EXCEPTION_COLLIDED_UNWIND);
+        assert_eq!(EXCEPTION_MAXIMUM_PARAMETERS, winapi::um::winnt::EXCEPTION_MAXIMUM_PARAMETERS);
+        assert_eq!(EXCEPTION_RECORD32_SIZE, std::mem::size_of::<winapi::um::winnt::EXCEPTION_RECORD32>());
+        assert_eq!(EXCEPTION_RECORD64_SIZE, std::mem::size_of::<winapi::um::winnt::EXCEPTION_RECORD64>());
+    }
+
+    /// Tests that Records round trip through the native Structures
+    #[test]
+    fn test_native_round_trip() {
+        let nested = ExceptionRecord::builder(crate::error::NtStatus::from(0xC000_0094u32)).build();
+        let record = ExceptionRecord::builder(crate::error::NtStatus::from(0xE000_0001u32)).nested(nested).parameter(3).unwrap().build();
+        let chain = record.to_native_chain().unwrap();
+        assert_eq!(chain.len(), 2);
+        assert_eq!(unsafe { ExceptionRecord::from_raw(&chain[0]) }, record);
+    }
+}
@@ -0,0 +1,216 @@
+//! # Exceptions
+//!
+//! Typed Views of the Structures that describe Structured Exceptions: The Exception Record and the Processor Context.
+//!
+//! The Views are plain Rust Types, that can be built, decoded from the raw Bytes of the Windows Structures and encoded back into them on every Host.
+//! This allows inspecting Exception Records and Contexts of other Architectures, for example from Minidumps or Crash Reports.
+//! On Windows they are converted into the native Structures, which is used by `RaiseFailFastException`.
+
+use crate::error::{Error, ErrorKind};
+
+/// The Exception Record with Decoding of well-known Parameter Layouts
+pub mod exception_record;
+
+/// Architecture specific Views of the Processor Context
+pub mod context;
+
+/// Application defined Exceptions that carry Rust Values
+pub mod custom_exception;
+
+/// Encoding of Rust Panics into the Parameters of Exceptions
+pub mod panic_report;
+
+pub use exception_record::{AccessOperation, ExceptionDetails, ExceptionRecord, ExceptionRecordBuilder, EXCEPTION_FLAGS};
+pub use context::{Context, ContextAmd64, ContextAmd64Builder, ContextArm64, ContextArm64Builder, ContextX86, ContextX86Builder};
+pub use custom_exception::{CustomException, CUSTOMER_BIT};
+pub use panic_report::{PanicReport, PANIC_EXCEPTION_CODE};
+
+/// The Processor Architectures whose Layouts are known to this Module
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Architecture {
+    /// 32-Bit x86, which uses the 32-Bit Layout of the Exception Record
+    X86,
+
+    /// x64, also known as AMD64
+    Amd64,
+
+    /// 64-Bit ARM
+    Arm64,
+}
+
+impl Architecture {
+    /// Returns the Architecture of the running Process
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the Process runs on an Architecture whose Layouts are not known
+    #[inline]
+    pub fn native() -> Option<Architecture> {
+        if cfg!(target_arch = "x86") {
+            return Some(Architecture::X86);
+        }
+        if cfg!(target_arch = "x86_64") {
+            return Some(Architecture::Amd64);
+        }
+        if cfg!(target_arch = "aarch64") {
+            return Some(Architecture::Arm64);
+        }
+        return None;
+    }
+
+    /// Returns the Size of Pointers on the Architecture in Bytes
+    #[inline]
+    pub fn pointer_size(self) -> usize {
+        return match self {
+            Architecture::X86 => 4,
+            Architecture::Amd64 | Architecture::Arm64 => 8,
+        };
+    }
+}
+
+/// Safe View of the EXCEPTION_POINTERS Struct, which is passed to Exception Handlers and Filters
+///
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-exception_pointers)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExceptionInfo {
+    /// The Exception that occurred
+    pub record: ExceptionRecord,
+
+    /// The Register State of the Thread when the Exception occurred.
+    /// Changes to the Registers of the View are applied when a Handler continues the Execution
+    pub context: Option<Context>,
+}
+
+impl ExceptionInfo {
+    /// Reads the Exception and the Register State from an EXCEPTION_POINTERS of the running Process
+    ///
+    /// # Safety
+    ///
+    /// The Pointer must point to a valid EXCEPTION_POINTERS, whose Exception Record must not be Null
+    #[cfg(windows)]
+    pub unsafe fn from_raw(pointers: *const winapi::um::winnt::EXCEPTION_POINTERS) -> ExceptionInfo {
+        let pointers = &*pointers;
+        return ExceptionInfo {
+            record: ExceptionRecord::from_raw(pointers.ExceptionRecord),
+            context: match pointers.ContextRecord.is_null() {
+                true => None,
+                false => Some(Context::from_raw(pointers.ContextRecord)),
+            },
+        };
+    }
+
+    /// Writes the Registers of the Context back into an EXCEPTION_POINTERS of the running Process
+    ///
+    /// # Safety
+    ///
+    /// The Pointer must point to a valid EXCEPTION_POINTERS, whose Context can be written
+    #[cfg(windows)]
+    pub(crate) unsafe fn write_context_to_raw(&self, pointers: *const winapi::um::winnt::EXCEPTION_POINTERS) -> Result<(), Error> {
+        let pointers = &*pointers;
+        return match (&self.context, pointers.ContextRecord.is_null()) {
+            (Some(context), false) => context.write_to_raw(pointers.ContextRecord),
+            _ => Ok(()),
+        };
+    }
+}
+
+/// Fields of the raw Layouts, which are stored in Little Endian on every supported Architecture
+pub(crate) trait LayoutField: Sized {
+    /// Reads the Field at an Offset, the Caller has to make sure that the Bytes are large enough
+    fn read(bytes: &[u8], offset: usize) -> Self;
+
+    /// Writes the Field at an Offset, the Caller has to make sure that the Bytes are large enough
+    fn write(&self, bytes: &mut [u8], offset: usize);
+}
+
+macro_rules! layout_field {
+    ($($type:ty),*) => {
+        $(
+            impl LayoutField for $type {
+                #[inline]
+                fn read(bytes: &[u8], offset: usize) -> $type {
+                    let mut buffer = [0u8; std::mem::size_of::<$type>()];
+                    buffer.copy_from_slice(&bytes[offset..offset + std::mem::size_of::<$type>()]);
+                    return <$type>::from_le_bytes(buffer);
+                }
+
+                #[inline]
+                fn write(&self, bytes: &mut [u8], offset: usize) {
+                    bytes[offset..offset + std::mem::size_of::<$type>()].copy_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+layout_field!(u16, u32, u64);
+
+impl<const N: usize> LayoutField for [u64; N] {
+    fn read(bytes: &[u8], offset: usize) -> [u64; N] {
+        let mut values = [0u64; N];
+        for (index, value) in values.iter_mut().enumerate() {
+            *value = u64::read(bytes, offset + index * 8);
+        }
+        return values;
+    }
+
+    fn write(&self, bytes: &mut [u8], offset: usize) {
+        for (index, value) in self.iter().enumerate() {
+            value.write(bytes, offset + index * 8);
+        }
+    }
+}
+
+/// Checks that Bytes are large enough for a Layout
+///
+/// # Return
+///
+/// This Function returns `ErrorKind::InvalidArgument` if the Bytes are smaller than the Layout
+#[inline]
+pub(crate) fn check_size(bytes: &[u8], size: usize) -> Result<(), Error> {
+    if bytes.len() < size {
+        return Err(Error::new(ErrorKind::InvalidArgument("the buffer is smaller than the layout")));
+    }
+    return Ok(());
+}
+
+/// Builds a Fixture of the given Size with little endian Values written at their Offsets
+#[cfg(test)]
+pub(crate) fn fixture(size: usize, fields: &[(usize, &[u8])]) -> Vec<u8> {
+    let mut bytes = vec![0u8; size];
+    for (offset, value) in fields {
+        bytes[*offset..*offset + value.len()].copy_from_slice(value);
+    }
+    return bytes;
+}
+
+/// Tests for the Architectures and Layout Fields
+#[cfg(test)]
+mod test_exception {
+    use crate::type_wrappers::exception::{check_size, Architecture, LayoutField};
+
+    /// Tests that Fields are read and written in Little Endian
+    #[test]
+    fn test_layout_field_little_endian() {
+        let mut bytes = [0u8; 16];
+        0x1122_3344u32.write(&mut bytes, 2);
+        assert_eq!(&bytes[2..6], &[0x44, 0x33, 0x22, 0x11]);
+        assert_eq!(u16::read(&bytes, 2), 0x3344);
+
+        [1u64, 2u64].write(&mut bytes, 0);
+        assert_eq!(<[u64; 2]>::read(&bytes, 0), [1, 2]);
+        assert_eq!(bytes[8], 2);
+    }
+
+    /// Tests that too small Buffers are rejected and Pointer Sizes match the Architectures
+    #[test]
+    fn test_check_size() {
+        assert!(check_size(&[0u8; 4], 4).is_ok());
+        assert!(check_size(&[0u8; 3], 4).is_err());
+        assert_eq!(Architecture::X86.pointer_size(), 4);
+        assert_eq!(Architecture::Arm64.pointer_size(), 8);
+        if cfg!(target_arch = "x86_64") {
+            assert_eq!(Architecture::native(), Some(Architecture::Amd64));
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use std::any::Any;
+use std::panic::{Location, PanicHookInfo};
+use std::thread;
+use crate::error::NtStatus;
+use crate::type_wrappers::exception::{Architecture, ExceptionRecord};
+use crate::type_wrappers::exception::exception_record::EXCEPTION_MAXIMUM_PARAMETERS;
+
+/// The Exception Code of Rust Panics, an Error with the Customer Bit set and "RST" in the Code
+pub const PANIC_EXCEPTION_CODE: u32 = 0xE052_5354;
+
+// The Version of the Encoding, stored in the low Bits of the Header Byte
+const VERSION: u8 = 1;
+const VERSION_MASK: u8 = 0x0F;
+const WIDE_WORDS: u8 = 0x10;
+const TRUNCATED: u8 = 0x20;
+
+// The Header Byte, the Line, the Column and the Lengths of the File, the Thread Name and the Message
+const HEADER_SIZE: usize = 1 + 4 + 4 + 3;
+
+// The maximum Length of the Thread Name, so the Message keeps most of the Space
+const MAXIMUM_THREAD_LENGTH: usize = 16;
+
+/// A Rust Panic, encoded into the Parameters of an Exception so Crash Reports can show it
+///
+/// The Panic is encoded as Bytes that are packed into the Parameters in Little Endian.
+/// Since an Exception has at most 15 Parameters, only 120 Bytes are available on 64-Bit Architectures and 60 Bytes on x86.
+/// Longer Messages, File Names and Thread Names are truncated, File Names keep their End since it names the File.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PanicReport {
+    /// The Message of the Panic
+    pub message: String,
+
+    /// The Source File where the Panic occurred
+    pub file: String,
+
+    /// The Line where the Panic occurred
+    pub line: u32,
+
+    /// The Column where the Panic occurred
+    pub column: u32,
+
+    /// The Name of the panicking Thread, if it has one
+    pub thread: Option<String>,
+
+    /// Whether Parts of the Report were truncated to fit into the Parameters
+    pub truncated: bool,
+}
+
+impl PanicReport {
+    /// Creates a Report of a Panic of the current Thread
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - The Information passed to the Panic Hook
+    pub fn from_panic_info(info: &PanicHookInfo) -> PanicReport {
+        return PanicReport::from_parts(info.payload(), info.location(), thread::current().name());
+    }
+
+    /// Creates a Report from the Parts of a Panic
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The Payload of the Panic, its Message if it is a String
+    /// * `location` - The Location where the Panic occurred
+    /// * `thread` - The Name of the panicking Thread
+    fn from_parts(payload: &(dyn Any + Send), location: Option<&Location>, thread: Option<&str>) -> PanicReport {
+        let message = match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+            (Some(message), _) => message.to_string(),
+            (_, Some(message)) => message.clone(),
+            _ => String::from("Box<dyn Any>"),
+        };
+        let (file, line, column) = match location {
+            Some(location) => (location.file().to_string(), location.line(), location.column()),
+            None => (String::new(), 0, 0),
+        };
+        return PanicReport {
+            message,
+            file,
+            line,
+            column,
+            thread: thread.map(str::to_string),
+            truncated: false,
+        };
+    }
+
+    /// Encodes the Report into the Parameters of an Exception with `PANIC_EXCEPTION_CODE`
+    ///
+    /// # Arguments
+    ///
+    /// * `architecture` - The Architecture whose Pointer Size determines the Size of the Parameters
+    pub fn to_parameters(&self, architecture: Architecture) -> Vec<u64> {
+        let word_size = architecture.pointer_size();
+        let mut budget = EXCEPTION_MAXIMUM_PARAMETERS * word_size - HEADER_SIZE;
+        let thread = truncate_end(self.thread.as_deref().unwrap_or(""), MAXIMUM_THREAD_LENGTH.min(budget));
+        budget -= thread.len();
+        let file = truncate_start(&self.file, budget / 3);
+        budget -= file.len();
+        let message = truncate_end(&self.message, budget);
+        let truncated = self.truncated
+            || thread.len() < self.thread.as_ref().map_or(0, String::len)
+            || file.len() < self.file.len()
+            || message.len() < self.message.len();
+
+        let mut header = VERSION;
+        if word_size == 8 {
+            header |= WIDE_WORDS;
+        }
+        if truncated {
+            header |= TRUNCATED;
+        }
+        let mut bytes = vec![header];
+        bytes.extend_from_slice(&self.line.to_le_bytes());
+        bytes.extend_from_slice(&self.column.to_le_bytes());
+        bytes.extend_from_slice(&[file.len() as u8, thread.len() as u8, message.len() as u8]);
+        bytes.extend_from_slice(file.as_bytes());
+        bytes.extend_from_slice(thread.as_bytes());
+        bytes.extend_from_slice(message.as_bytes());
+
+        return bytes.chunks(word_size).map(|chunk| {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            return u64::from_le_bytes(word);
+        }).collect();
+    }
+
+    /// Decodes a Report from the Parameters of an Exception
+    ///
+    /// # Arguments
+    ///
+    /// * `parameters` - The Parameters, as encoded by `to_parameters`
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the Parameters are not a Report of a known Version
+    pub fn from_parameters(parameters: &[u64]) -> Option<PanicReport> {
+        let header = *parameters.first()? as u8;
+        if header & VERSION_MASK != VERSION {
+            return None;
+        }
+        let word_size = match header & WIDE_WORDS {
+            0 => 4,
+            _ => 8,
+        };
+        let bytes: Vec<u8> = parameters.iter().flat_map(|parameter| parameter.to_le_bytes()[..word_size].to_vec()).collect();
+        let header_bytes = bytes.get(..HEADER_SIZE)?;
+        let line = u32::from_le_bytes([header_bytes[1], header_bytes[2], header_bytes[3], header_bytes[4]]);
+        let column = u32::from_le_bytes([header_bytes[5], header_bytes[6], header_bytes[7], header_bytes[8]]);
+        let (file_length, thread_length, message_length) = (header_bytes[9] as usize, header_bytes[10] as usize, header_bytes[11] as usize);
+
+        let mut rest = &bytes[HEADER_SIZE..];
+        let mut take = |length: usize| -> Option<String> {
+            let part = rest.get(..length)?;
+            rest = &rest[length..];
+            return String::from_utf8(part.to_vec()).ok();
+        };
+        let file = take(file_length)?;
+        let thread = take(thread_length)?;
+        let message = take(message_length)?;
+        return Some(PanicReport {
+            message,
+            file,
+            line,
+            column,
+            thread: match thread.is_empty() {
+                true => None,
+                false => Some(thread),
+            },
+            truncated: header & TRUNCATED != 0,
+        });
+    }
+
+    /// Decodes a Report from an Exception Record
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the Exception is not a Rust Panic or its Parameters are not a Report of a known Version
+    pub fn from_exception_record(record: &ExceptionRecord) -> Option<PanicReport> {
+        if record.code() != NtStatus::from(PANIC_EXCEPTION_CODE) {
+            return None;
+        }
+        return PanicReport::from_parameters(record.parameters());
+    }
+}
+
+/// Returns the longest Prefix of a String with at most `length` Bytes
+fn truncate_end(value: &str, length: usize) -> &str {
+    let mut end = value.len().min(length);
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    return &value[..end];
+}
+
+/// Returns the longest Suffix of a String with at most `length` Bytes
+fn truncate_start(value: &str, length: usize) -> &str {
+    let mut start = value.len().saturating_sub(length);
+    while !value.is_char_boundary(start) {
+        start += 1;
+    }
+    return &value[start..];
+}
+
+/// Tests for the Encoding of Panics into Exception Parameters
+#[cfg(test)]
+mod test_panic_report {
+    use crate::error::NtStatus;
+    use crate::type_wrappers::exception::{Architecture, ExceptionRecord};
+    use crate::type_wrappers::exception::exception_record::EXCEPTION_MAXIMUM_PARAMETERS;
+    use crate::type_wrappers::exception::panic_report::*;
+
+    fn report() -> PanicReport {
+        return PanicReport {
+            message: String::from("index out of bounds"),
+            file: String::from("src/main.rs"),
+            line: 42,
+            column: 7,
+            thread: Some(String::from("worker")),
+            truncated: false,
+        };
+    }
+
+    /// Tests that Reports round trip through the Parameters of both Pointer Sizes
+    #[test]
+    fn test_round_trip() {
+        for architecture in [Architecture::X86, Architecture::Amd64].iter() {
+            let parameters = report().to_parameters(*architecture);
+            assert!(parameters.len() <= EXCEPTION_MAXIMUM_PARAMETERS);
+            assert_eq!(PanicReport::from_parameters(&parameters), Some(report()));
+        }
+
+        let unnamed = PanicReport { thread: None, ..report() };
+        assert_eq!(PanicReport::from_parameters(&unnamed.to_parameters(Architecture::Arm64)), Some(unnamed));
+    }
+
+    /// Tests that long Reports are truncated at Character Boundaries, keeping the End of the File Name
+    #[test]
+    fn test_truncation() {
+        let long = PanicReport {
+            message: "ä".repeat(100),
+            file: format!("{}/lib.rs", "very/long/path".repeat(10)),
+            thread: Some("t".repeat(40)),
+            ..report()
+        };
+        let parameters = long.to_parameters(Architecture::X86);
+        assert_eq!(parameters.len(), EXCEPTION_MAXIMUM_PARAMETERS);
+        let decoded = PanicReport::from_parameters(&parameters).unwrap();
+        assert!(decoded.truncated);
+        assert!(decoded.file.ends_with("/lib.rs"));
+        assert!(decoded.message.chars().all(|character| character == 'ä'));
+        assert_eq!(decoded.thread.unwrap().len(), 16);
+        assert_eq!(decoded.line, 42);
+    }
+
+    /// Tests that Reports are decoded from Exception Records with the Panic Exception Code only
+    #[test]
+    fn test_from_exception_record() {
+        let mut builder = ExceptionRecord::builder(NtStatus::from(PANIC_EXCEPTION_CODE));
+        for parameter in report().to_parameters(Architecture::Amd64) {
+            builder = builder.parameter(parameter).unwrap();
+        }
+        let record = builder.build();
+        assert_eq!(PanicReport::from_exception_record(&record), Some(report()));
+
+        let other = ExceptionRecord::builder(NtStatus::from(0xE000_0001u32)).parameter(record.parameters()[0]).unwrap().build();
+        assert_eq!(PanicReport::from_exception_record(&other), None);
+        assert_eq!(PanicReport::from_parameters(&[0x0F]), None);
+        assert_eq!(PanicReport::from_parameters(&[0x11]), None);
+    }
+
+    /// Tests that Reports are created from the Payload and Location of a Panic
+    #[test]
+    fn test_from_parts() {
+        let location = std::panic::Location::caller();
+        let payload: Box<dyn std::any::Any + Send> = Box::new(format!("failed with {}", 3));
+        let report = PanicReport::from_parts(payload.as_ref(), Some(location), Some("reporter"));
+        assert_eq!(report.message, "failed with 3");
+        assert!(report.file.ends_with("panic_report.rs"));
+        assert_eq!((report.line, report.column), (location.line(), location.column()));
+        assert_eq!(report.thread.as_deref(), Some("reporter"));
+
+        let payload: Box<dyn std::any::Any + Send> = Box::new("static message");
+        assert_eq!(PanicReport::from_parts(payload.as_ref(), None, None).message, "static message");
+        let payload: Box<dyn std::any::Any + Send> = Box::new(42);
+        let report = PanicReport::from_parts(payload.as_ref(), None, None);
+        assert_eq!((report.message.as_str(), report.file.as_str(), report.thread), ("Box<dyn Any>", "", None));
+    }
+}
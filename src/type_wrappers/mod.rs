@@ -17,6 +17,9 @@ pub mod window_class;
 /// Type Wrappers for the errhandlingapi.h Header
 pub mod error_handling_api;
 
+/// Typed Views of Exception Records and Processor Contexts
+pub mod exception;
+
 /// Type Wrappers for the Windows Error Reporting Technology
 ///
 /// See Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/_wer/)
@@ -0,0 +1,384 @@
+//! # Code Pages
+//!
+//! Conversion between Rust Strings and the ANSI Code Pages used by the `A` Functions of the Win32 API.
+//!
+//! The `A` Functions interpret their Strings in the active ANSI Code Page of the System, which is rarely UTF-8.
+//! Passing the Bytes of a Rust String directly therefore produces Mojibake for every non-ASCII Character.
+//! This Module encodes Rust Strings into a chosen Code Page and reports Characters that the Code Page cannot represent as Errors.
+//!
+//! The Conversion Tables are written in plain Rust, so the Conversion works the same way on every Platform.
+
+use std::error::Error;
+use std::ffi::{CStr, CString};
+use std::fmt;
+
+mod single_byte;
+mod cp932;
+
+/// Errors that can occur while converting between Rust Strings and Code Pages
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CodePageError {
+    /// A Character of the String cannot be represented in the Code Page
+    UnmappableCharacter {
+        /// The Character that cannot be represented
+        character: char,
+        /// The Byte Position of the Character in the String
+        position: usize
+    },
+
+    /// The Bytes are not a valid Sequence in the Code Page
+    InvalidSequence {
+        /// The Byte Position of the invalid Sequence
+        position: usize
+    },
+
+    /// The String contains a Null-Character and cannot be converted into a CString
+    InteriorNul {
+        /// The Byte Position of the Null-Character
+        position: usize
+    },
+}
+
+impl fmt::Display for CodePageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            CodePageError::UnmappableCharacter { character, position } => write!(f, "character {:?} at position {} cannot be represented in the code page", character, position),
+            CodePageError::InvalidSequence { position } => write!(f, "invalid byte sequence at position {}", position),
+            CodePageError::InteriorNul { position } => write!(f, "string contains an interior nul at position {}", position),
+        };
+    }
+}
+
+impl Error for CodePageError {}
+
+/// Supported ANSI and OEM Code Pages
+///
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/intl/code-page-identifiers)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CodePage {
+    /// Windows-1250, Central European
+    Windows1250,
+
+    /// Windows-1251, Cyrillic
+    Windows1251,
+
+    /// Windows-1252, Western European (the Default on English Systems)
+    Windows1252,
+
+    /// OEM 437, the Code Page of the Console on English Systems
+    Oem437,
+
+    /// 932, Japanese Shift-JIS (Windows-31J)
+    ShiftJis,
+
+    /// 65001, UTF-8
+    Utf8,
+}
+
+impl CodePage {
+    /// Returns the Code Page for a Code Page Identifier
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - The Code Page Identifier, as returned by GetACP or GetOEMCP
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the Code Page is not supported
+    pub fn from_identifier(identifier: u32) -> Option<CodePage> {
+        return match identifier {
+            1250 => Some(CodePage::Windows1250),
+            1251 => Some(CodePage::Windows1251),
+            1252 => Some(CodePage::Windows1252),
+            437 => Some(CodePage::Oem437),
+            932 => Some(CodePage::ShiftJis),
+            65001 => Some(CodePage::Utf8),
+            _ => None,
+        };
+    }
+
+    /// Returns the Code Page Identifier
+    pub fn identifier(self) -> u32 {
+        return match self {
+            CodePage::Windows1250 => 1250,
+            CodePage::Windows1251 => 1251,
+            CodePage::Windows1252 => 1252,
+            CodePage::Oem437 => 437,
+            CodePage::ShiftJis => 932,
+            CodePage::Utf8 => 65001,
+        };
+    }
+
+    /// Returns the Table for the upper Half of a Single-Byte Code Page
+    fn single_byte_table(self) -> Option<&'static [u16; 128]> {
+        return match self {
+            CodePage::Windows1250 => Some(&single_byte::WINDOWS_1250),
+            CodePage::Windows1251 => Some(&single_byte::WINDOWS_1251),
+            CodePage::Windows1252 => Some(&single_byte::WINDOWS_1252),
+            CodePage::Oem437 => Some(&single_byte::OEM_437),
+            CodePage::ShiftJis | CodePage::Utf8 => None,
+        };
+    }
+
+    /// Encodes a single non-ASCII Character
+    fn encode_character(self, character: char, output: &mut Vec<u8>) -> bool {
+        if let Some(table) = self.single_byte_table() {
+            return match table.iter().position(|unit| *unit as u32 == character as u32) {
+                Some(index) => {
+                    output.push(0x80 + index as u8);
+                    true
+                }
+                None => false,
+            };
+        }
+        return match self {
+            CodePage::ShiftJis => {
+                if (character as u32) > 0xFFFF {
+                    return false;
+                }
+                let value = character as u32 as u16;
+                if let Some(index) = cp932::SINGLE_BYTE.iter().position(|unit| *unit == value) {
+                    output.push(0x80 + index as u8);
+                    return true;
+                }
+                match cp932::ENCODE.binary_search_by_key(&value, |(unit, _)| *unit) {
+                    Ok(index) => {
+                        let code = cp932::ENCODE[index].1;
+                        output.push((code >> 8) as u8);
+                        output.push(code as u8);
+                        true
+                    }
+                    Err(_) => false,
+                }
+            }
+            _ => {
+                let mut buffer = [0u8; 4];
+                output.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                true
+            }
+        };
+    }
+
+    /// Encodes a Rust String into the Code Page
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The String that should be encoded
+    ///
+    /// # Return
+    ///
+    /// This Function returns the encoded Bytes or an Error for the first Character that cannot be represented
+    pub fn encode(self, value: &str) -> Result<Vec<u8>, CodePageError> {
+        if self == CodePage::Utf8 {
+            return Ok(Vec::from(value.as_bytes()));
+        }
+        let mut output = Vec::with_capacity(value.len());
+        for (position, character) in value.char_indices() {
+            if character.is_ascii() {
+                output.push(character as u8);
+            } else if !self.encode_character(character, &mut output) {
+                return Err(CodePageError::UnmappableCharacter { character, position });
+            }
+        }
+        return Ok(output);
+    }
+
+    /// Encodes a Rust String into a Null-Terminated String in the Code Page
+    ///
+    /// This is the Conversion needed to pass Rust Strings to the `A` Functions of the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The String that should be encoded
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if a Character cannot be represented or if the String contains a Null-Character
+    pub fn encode_c_string(self, value: &str) -> Result<CString, CodePageError> {
+        if let Some(position) = value.find('\0') {
+            return Err(CodePageError::InteriorNul { position });
+        }
+        let bytes = self.encode(value)?;
+        return Ok(CString::new(bytes).expect("Encoded Strings without Null-Characters contain no Null-Bytes"));
+    }
+
+    /// Decodes a single Character starting at a Byte Position
+    ///
+    /// # Return
+    ///
+    /// This Function returns the Character (or None for an invalid Sequence) and the Number of Bytes that were consumed
+    fn decode_character(self, bytes: &[u8], position: usize) -> (Option<char>, usize) {
+        let byte = bytes[position];
+        if byte < 0x80 {
+            return (Some(byte as char), 1);
+        }
+        if let Some(table) = self.single_byte_table() {
+            return (std::char::from_u32(table[(byte - 0x80) as usize] as u32), 1);
+        }
+        if cp932::is_lead_byte(byte) {
+            let trail = bytes.get(position + 1).and_then(|trail| cp932::trail_byte_index(*trail));
+            return match trail {
+                Some(trail) => {
+                    let value = cp932::DOUBLE_BYTE[cp932::lead_byte_index(byte) * cp932::TRAIL_BYTE_COUNT + trail];
+                    match value {
+                        0 => (None, 2),
+                        _ => (std::char::from_u32(value as u32), 2),
+                    }
+                }
+                None => (None, 1),
+            };
+        }
+        return (std::char::from_u32(cp932::SINGLE_BYTE[(byte - 0x80) as usize] as u32), 1);
+    }
+
+    /// Decodes Bytes in the Code Page into a Rust String
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The encoded Bytes (without a Null-Terminator)
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error for the first Sequence that is not valid in the Code Page
+    pub fn decode(self, bytes: &[u8]) -> Result<String, CodePageError> {
+        if self == CodePage::Utf8 {
+            return match std::str::from_utf8(bytes) {
+                Ok(value) => Ok(String::from(value)),
+                Err(error) => Err(CodePageError::InvalidSequence { position: error.valid_up_to() }),
+            };
+        }
+        let mut result = String::with_capacity(bytes.len());
+        let mut position = 0;
+        while position < bytes.len() {
+            let (character, byte_count) = self.decode_character(bytes, position);
+            match character {
+                Some(character) => result.push(character),
+                None => return Err(CodePageError::InvalidSequence { position }),
+            }
+            position += byte_count;
+        }
+        return Ok(result);
+    }
+
+    /// Decodes Bytes in the Code Page into a Rust String, replacing invalid Sequences with U+FFFD
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The encoded Bytes (without a Null-Terminator)
+    pub fn decode_lossy(self, bytes: &[u8]) -> String {
+        if self == CodePage::Utf8 {
+            return String::from_utf8_lossy(bytes).into_owned();
+        }
+        let mut result = String::with_capacity(bytes.len());
+        let mut position = 0;
+        while position < bytes.len() {
+            let (character, byte_count) = self.decode_character(bytes, position);
+            result.push(character.unwrap_or(std::char::REPLACEMENT_CHARACTER));
+            position += byte_count;
+        }
+        return result;
+    }
+
+    /// Decodes a Null-Terminated String returned by an `A` Function
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The String in the Code Page
+    #[inline]
+    pub fn decode_c_str(self, value: &CStr) -> Result<String, CodePageError> {
+        return self.decode(value.to_bytes());
+    }
+
+    /// Returns whether the Bytes are the Start of a Character that needs more Bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The Bytes received so far
+    pub(crate) fn is_incomplete(self, bytes: &[u8]) -> bool {
+        return match self {
+            CodePage::Utf8 => match std::str::from_utf8(bytes) {
+                Ok(_) => false,
+                Err(error) => error.error_len().is_none(),
+            },
+            CodePage::ShiftJis => bytes.len() == 1 && cp932::is_lead_byte(bytes[0]),
+            _ => false,
+        };
+    }
+}
+
+/// Wrapping Function for the GetACP Function
+/// See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/api/winnls/nf-winnls-getacp)
+///
+/// # Return
+///
+/// This Function returns the active ANSI Code Page of the System, or None if the Code Page is not supported by this Module
+// This Function is already Safe
+#[cfg(windows)]
+#[inline]
+pub fn active_code_page() -> Option<CodePage> {
+    let identifier: u32;
+    unsafe {
+        identifier = winapi::um::winnls::GetACP();
+    }
+    return CodePage::from_identifier(identifier);
+}
+
+/// Tests for the Code Page Conversion
+#[cfg(test)]
+mod test_code_page {
+    use crate::type_wrappers::type_conversion::code_page::{CodePage, CodePageError};
+
+    /// Tests that Characters are encoded with the correct Byte Values
+    #[test]
+    fn test_encode_single_byte() {
+        assert_eq!(CodePage::Windows1252.encode("Grüße €").unwrap(), b"Gr\xFC\xDFe \x80");
+        assert_eq!(CodePage::Windows1250.encode("Łódź").unwrap(), b"\xA3\xF3d\x9F");
+        assert_eq!(CodePage::Windows1251.encode("Привет").unwrap(), b"\xCF\xF0\xE8\xE2\xE5\xF2");
+        assert_eq!(CodePage::Oem437.encode("╔═╗ ü").unwrap(), b"\xC9\xCD\xBB \x81");
+    }
+
+    /// Tests that Characters outside of the Code Page are reported instead of producing Mojibake
+    #[test]
+    fn test_unmappable_character() {
+        assert_eq!(CodePage::Windows1252.encode("ab Привет"), Err(CodePageError::UnmappableCharacter { character: 'П', position: 3 }));
+        assert_eq!(CodePage::ShiftJis.encode("🦀"), Err(CodePageError::UnmappableCharacter { character: '🦀', position: 0 }));
+    }
+
+    /// Tests the Conversion of Shift-JIS Double-Byte and Half-Width Characters
+    #[test]
+    fn test_shift_jis() {
+        let encoded = CodePage::ShiftJis.encode("日本語ｱ").unwrap();
+
+        assert_eq!(encoded, b"\x93\xFA\x96\x7B\x8C\xEA\xB1");
+        assert_eq!(CodePage::ShiftJis.decode(&encoded).unwrap(), "日本語ｱ");
+        assert_eq!(CodePage::ShiftJis.decode(b"\x93"), Err(CodePageError::InvalidSequence { position: 0 }));
+        assert_eq!(CodePage::ShiftJis.decode_lossy(b"a\x93"), "a\u{FFFD}");
+    }
+
+    /// Tests that every Single-Byte Code Page round trips all of its Bytes
+    #[test]
+    fn test_single_byte_round_trip() {
+        let bytes: Vec<u8> = (1..=255).collect();
+        for code_page in [CodePage::Windows1250, CodePage::Windows1251, CodePage::Windows1252, CodePage::Oem437].iter() {
+            let decoded = code_page.decode(&bytes).unwrap();
+            assert_eq!(code_page.encode(&decoded).unwrap(), bytes);
+        }
+    }
+
+    /// Tests the Conversion into Null-Terminated Strings for the `A` Functions
+    #[test]
+    fn test_encode_c_string() {
+        assert_eq!(CodePage::Windows1252.encode_c_string("Café").unwrap().as_bytes_with_nul(), b"Caf\xE9\0");
+        assert_eq!(CodePage::Utf8.encode_c_string("Café").unwrap().as_bytes(), "Café".as_bytes());
+        assert_eq!(CodePage::Windows1252.encode_c_string("a\0b"), Err(CodePageError::InteriorNul { position: 1 }));
+    }
+
+    /// Tests the Mapping between Code Pages and their Identifiers
+    #[test]
+    fn test_identifier() {
+        for identifier in [1250, 1251, 1252, 437, 932, 65001].iter() {
+            assert_eq!(CodePage::from_identifier(*identifier).unwrap().identifier(), *identifier);
+        }
+        assert_eq!(CodePage::from_identifier(1253), None);
+    }
+}
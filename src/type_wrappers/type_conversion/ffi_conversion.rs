@@ -0,0 +1,71 @@
+//! # FFI Conversion
+//!
+//! Conversion Traits between Rust Types and the C FFI Types expected by the Win32 API.
+//!
+//! These Traits are used by the `#[ffi(<type>)]` and `#[out(<type>)]` Parameter Attributes of `ffi_wrap`.
+//! Implementing them for a Type allows it to be used as an Argument or Out-Parameter of any wrapped Function.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::ptr::null;
+use crate::type_wrappers::type_conversion::{convert_c_bool, convert_rust_bool};
+use crate::type_wrappers::type_conversion::wide_string::WideCStr;
+use crate::type_wrappers::type_conversion::win_types::BOOL;
+
+/// Conversion of a Rust Type into an FFI Type that is passed to an FFI Function
+pub trait ToFfi<F> {
+    /// Converts the Value into the FFI Type
+    fn to_ffi(self) -> F;
+}
+
+/// Conversion of an FFI Type written by an FFI Function into a Rust Type
+pub trait FromFfi<F> {
+    /// Converts the FFI Value into the Rust Type
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The Value written by the FFI Function
+    fn from_ffi(value: F) -> Self;
+}
+
+impl ToFfi<BOOL> for bool {
+    #[inline]
+    fn to_ffi(self) -> BOOL {
+        return convert_rust_bool(self);
+    }
+}
+
+impl FromFfi<BOOL> for bool {
+    #[inline]
+    fn from_ffi(value: BOOL) -> bool {
+        return convert_c_bool(value);
+    }
+}
+
+impl ToFfi<*const u16> for &WideCStr {
+    #[inline]
+    fn to_ffi(self) -> *const u16 {
+        return self.as_ptr();
+    }
+}
+
+impl ToFfi<*const u16> for Option<&WideCStr> {
+    #[inline]
+    fn to_ffi(self) -> *const u16 {
+        return self.map_or(null(), WideCStr::as_ptr);
+    }
+}
+
+impl ToFfi<*const c_char> for &CStr {
+    #[inline]
+    fn to_ffi(self) -> *const c_char {
+        return self.as_ptr();
+    }
+}
+
+impl ToFfi<*const c_char> for Option<&CStr> {
+    #[inline]
+    fn to_ffi(self) -> *const c_char {
+        return self.map_or(null(), CStr::as_ptr);
+    }
+}
@@ -0,0 +1,384 @@
+//! # Conversion
+//!
+//! This Module Contains functions and Macros to make Conversions between C FFI Types and Rust Types easier
+
+use crate::type_wrappers::type_conversion::win_types::BOOL;
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::win_types::PVOID;
+
+/// The basic Windows Data Types, available on every Host
+pub mod win_types;
+
+/// Owned and borrowed Null-Terminated UTF-16 Strings
+#[macro_use]
+pub mod wide_string;
+
+/// Lossless WTF-8 Representation of Strings returned by the API
+pub mod wtf8;
+
+/// Double-Null-Terminated String Lists
+pub mod multi_string;
+
+/// Conversion between Rust Strings and ANSI Code Pages
+pub mod code_page;
+
+/// Selection between the `A` and `W` Entry Points
+pub mod win32_str;
+
+/// Names of the Win32 Constants behind Enums and Bitflags
+pub mod reflection;
+
+/// Conversion Traits between Rust Types and FFI Types
+pub mod ffi_conversion;
+
+/// Policies that check the Return Values of FFI Functions
+pub mod success;
+
+
+/// Converts C Constant Based Enums into Rust Enums
+///
+/// # Forms
+///
+/// * `const_enum` - The Resulting Enum Supports the From Trait into the original Type as well as TryFrom the original Type.
+///   TryFrom is needed because C APIs may return non-specified Values.
+///
+/// * `const_enum_open` - The Resulting Enum has an additional `Unknown(raw)` Variant that holds non-specified Values,
+///   so it supports lossless From Conversions in both Directions.
+///   This should be used for Values returned by the OS, which may be newer than WINAPI.
+///   Equality and Hashing are based on the raw Value, so `Unknown` holding a specified Value equals the matching Variant.
+///
+/// Both Forms implement `ConstantEnum`, `Display` and `FromStr` using the Names of the original Constants, like `SW_SHOWMAXIMIZED`.
+/// `Unknown` Values are displayed and parsed as their raw Value.
+#[macro_export]
+macro_rules! CONST_TO_ENUM {
+    {const_enum $name:ident, $original:path {$($new:ident = $old:path,)*}} =>
+    {
+        #[derive(Debug, Copy, Clone)]
+        pub enum $name {
+            $($new,)*
+        }
+
+        CONST_TO_ENUM!{const_enum_into $name, $original {$($new = $old,)*}}
+
+        CONST_TO_ENUM!{const_enum_from $name, $original {$($new = $old,)*}}
+
+        CONST_TO_ENUM!{const_enum_names $name, $original {$($new = $old,)*}}
+    };
+
+    {const_enum_open $name:ident, $original:path {$($new:ident = $old:path,)*}} =>
+    {
+        #[derive(Debug, Copy, Clone)]
+        pub enum $name {
+            $($new,)*
+            /// A Value that is not specified by this Crate
+            Unknown($original),
+        }
+
+        impl From<$name> for $original {
+            fn from(value: $name) -> $original {
+                return match value {
+                    $($name::$new => $old,)*
+                    $name::Unknown(raw) => raw,
+                };
+            }
+        }
+
+        impl From<$original> for $name {
+            // Constants sharing a Value convert into the first Variant
+            #[allow(unreachable_patterns)]
+            fn from(value: $original) -> $name {
+                return match value {
+                    $($old => $name::$new,)*
+                    _ => $name::Unknown(value),
+                };
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &$name) -> bool {
+                let this: $original = (*self).into();
+                let other: $original = (*other).into();
+                return this == other;
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl std::hash::Hash for $name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                let raw: $original = (*self).into();
+                raw.hash(state);
+            }
+        }
+
+        impl $crate::reflection::ConstantEnum for $name {
+            const TYPE_NAME: &'static str = stringify!($name);
+            const VARIANTS: &'static [$name] = &[$($name::$new,)*];
+
+            fn constant_name(&self) -> Option<&'static str> {
+                let raw: $original = (*self).into();
+                return match $name::from(raw) {
+                    $($name::$new => Some($crate::reflection::constant_name_of(stringify!($old))),)*
+                    $name::Unknown(_) => None,
+                };
+            }
+
+            fn from_constant_name(name: &str) -> Result<$name, $crate::reflection::ParseConstantError> {
+                $(
+                    if name == $crate::reflection::constant_name_of(stringify!($old)) {
+                        return Ok($name::$new);
+                    }
+                )*
+                return Err($crate::reflection::ParseConstantError::new(stringify!($name), name));
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                return match $crate::reflection::ConstantEnum::constant_name(self) {
+                    Some(name) => f.write_str(name),
+                    None => {
+                        let raw: $original = (*self).into();
+                        write!(f, "{}", raw)
+                    }
+                };
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::reflection::ParseConstantError;
+
+            fn from_str(name: &str) -> Result<$name, Self::Err> {
+                return <$name as $crate::reflection::ConstantEnum>::from_constant_name(name)
+                    .or_else(|error| name.parse::<$original>().map($name::from).map_err(|_| error));
+            }
+        }
+    };
+
+    {const_enum_into $name:ident, $original:path {$($new:ident = $old:path,)*}} =>
+    {
+        impl From<$name> for $original {
+            fn from(value: $name) -> $original {
+                return match value {
+                    $($name::$new => $old,)*
+                };
+            }
+        }
+    };
+
+    {const_enum_from $name:ident, $original:path {$($new:ident = $old:path,)*}} =>
+    {
+        impl std::convert::TryFrom<$original> for $name {
+            type Error = $original;
+
+            // Constants sharing a Value convert into the first Variant
+            #[allow(unreachable_patterns)]
+            fn try_from(value: $original) -> Result<Self, Self::Error> {
+                return match value {
+                    $($old => Ok($name::$new),)*
+                    _ => Err(value),
+                }
+            }
+        }
+    };
+
+    {const_enum_names $name:ident, $original:path {$($new:ident = $old:path,)*}} =>
+    {
+        impl $crate::reflection::ConstantEnum for $name {
+            const TYPE_NAME: &'static str = stringify!($name);
+            const VARIANTS: &'static [$name] = &[$($name::$new,)*];
+
+            fn constant_name(&self) -> Option<&'static str> {
+                return match self {
+                    $($name::$new => Some($crate::reflection::constant_name_of(stringify!($old))),)*
+                };
+            }
+
+            fn from_constant_name(name: &str) -> Result<$name, $crate::reflection::ParseConstantError> {
+                $(
+                    if name == $crate::reflection::constant_name_of(stringify!($old)) {
+                        return Ok($name::$new);
+                    }
+                )*
+                return Err($crate::reflection::ParseConstantError::new(stringify!($name), name));
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                return match self {
+                    $($name::$new => f.write_str($crate::reflection::constant_name_of(stringify!($old))),)*
+                };
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::reflection::ParseConstantError;
+
+            fn from_str(name: &str) -> Result<$name, Self::Err> {
+                return <$name as $crate::reflection::ConstantEnum>::from_constant_name(name);
+            }
+        }
+    }
+}
+
+/// Generates Bitflags that know the Names of the Win32 Constants behind their Flags
+///
+/// Takes the same Input as `bitflags!`, but every Flag has to be defined by a Path to its Constant, like `winapi::um::winuser::WS_CAPTION`.
+/// The generated Type implements `ConstantFlags`, `Display` and `FromStr` using Lists of Constant Names, like `WS_CAPTION|WS_SYSMENU`.
+///
+/// # Note
+///
+/// `Display` writes Bits that do not belong to any Flag as a hexadecimal Number, these cannot be parsed back
+#[macro_export]
+macro_rules! NAMED_BITFLAGS {
+    (
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident : $bits:ty {
+            $(
+                $(#[$inner:meta])*
+                const $flag:ident = $value:path;
+            )*
+        }
+    ) =>
+    {
+        bitflags::bitflags! {
+            $(#[$outer])*
+            $vis struct $name : $bits {
+                $(
+                    $(#[$inner])*
+                    const $flag = $value;
+                )*
+            }
+        }
+
+        impl $crate::reflection::ConstantFlags for $name {
+            const TYPE_NAME: &'static str = stringify!($name);
+            const FLAGS: &'static [(&'static str, $name)] = &[$(($crate::reflection::constant_name_of(stringify!($value)), $name::$flag),)*];
+
+            fn constant_names(&self) -> Vec<&'static str> {
+                return $crate::reflection::decompose_flags(Self::FLAGS, self.bits() as u64, |flag: $name| flag.bits() as u64).0;
+            }
+
+            fn from_constant_names(names: &str) -> Result<$name, $crate::reflection::ParseConstantError> {
+                let bits = $crate::reflection::compose_flags(Self::TYPE_NAME, Self::FLAGS, names, |flag: $name| flag.bits() as u64)?;
+                return Ok($name::from_bits_truncate(bits as $bits));
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let flags = <$name as $crate::reflection::ConstantFlags>::FLAGS;
+                let (names, remaining) = $crate::reflection::decompose_flags(flags, self.bits() as u64, |flag: $name| flag.bits() as u64);
+                let mut parts: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+                if remaining != 0 {
+                    parts.push(format!("{:#x}", remaining));
+                }
+                return match parts.is_empty() {
+                    true => f.write_str("0"),
+                    false => f.write_str(&parts.join("|")),
+                };
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::reflection::ParseConstantError;
+
+            fn from_str(names: &str) -> Result<$name, Self::Err> {
+                return <$name as $crate::reflection::ConstantFlags>::from_constant_names(names);
+            }
+        }
+    };
+}
+
+/// Converts C Style BOOLs to Rust booleans.
+///
+/// # Arguments
+///
+/// * `value` - The C Style BOOL that should be converted
+///
+/// # Return
+///
+/// This Function returns true for a non-zero value and false for a zero value
+#[inline]
+pub fn convert_c_bool(value: BOOL) -> bool {
+    return value != 0;
+}
+
+/// Converts Rust Style booleans to C BOOLs.
+///
+/// # Arguments
+///
+/// * `value` - The Rust Style bool that should be converted
+///
+/// # Return
+///
+/// This Function returns 1 for a true value and 0 for a false value
+#[inline]
+pub fn convert_rust_bool(value: bool) -> BOOL {
+    return match value {
+        true => 1,
+        false => 0
+    };
+}
+
+/// Converts References into void Pointers
+///
+/// # Arguments
+///
+/// * `reference` - The reference that should be converted
+#[cfg(windows)]
+#[inline]
+pub fn convert_reference_to_pvoid<T>(reference: &mut T) -> PVOID {
+    return (reference as *mut T) as PVOID;
+}
+
+/// Tests for the CONST_TO_ENUM Macro
+#[cfg(test)]
+#[allow(clippy::upper_case_acronyms)]
+mod test_const_to_enum {
+    use std::collections::HashSet;
+    use std::convert::TryFrom;
+
+    const FIRST: u32 = 1;
+    const SECOND: u32 = 2;
+
+    CONST_TO_ENUM!(const_enum CLOSED, u32 {
+        First = FIRST,
+        Second = SECOND,
+    });
+
+    CONST_TO_ENUM!(const_enum_open OPEN, u32 {
+        First = FIRST,
+        Second = SECOND,
+    });
+
+    /// Tests that closed Enums reject non-specified Values
+    #[test]
+    fn test_closed_rejects_unknown_values() {
+        assert!(matches!(CLOSED::try_from(2), Ok(CLOSED::Second)));
+        assert_eq!(CLOSED::try_from(12).unwrap_err(), 12);
+    }
+
+    /// Tests that open Enums round trip every Value
+    #[test]
+    fn test_open_round_trips_all_values() {
+        for raw in [0u32, 1, 2, 12, u32::MAX].iter() {
+            let value = OPEN::from(*raw);
+            assert_eq!(u32::from(value), *raw);
+        }
+        assert_eq!(OPEN::from(1), OPEN::First);
+        assert_eq!(OPEN::from(12), OPEN::Unknown(12));
+    }
+
+    /// Tests that Equality and Hashing are based on the raw Value
+    #[test]
+    fn test_open_equality_uses_raw_value() {
+        assert_eq!(OPEN::Unknown(2), OPEN::Second);
+        assert_ne!(OPEN::Unknown(3), OPEN::Second);
+
+        let mut set = HashSet::new();
+        set.insert(OPEN::Second);
+        assert!(set.contains(&OPEN::Unknown(2)));
+    }
+}
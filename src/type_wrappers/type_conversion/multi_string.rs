@@ -0,0 +1,511 @@
+//! # Multi Strings
+//!
+//! Encoder and Decoder for Double-Null-Terminated String Lists.
+//!
+//! A Multi String is a Sequence of Null-Terminated Strings followed by an additional Null-Character, for example `"first\0second\0\0"`.
+//! This Format is used by `REG_MULTI_SZ` Registry Values (for example WER Settings), PDH Counter Lists, EvtQuery Channel Lists,
+//! Performance Counter Name Tables and Environment Blocks.
+//!
+//! Because an empty Entry would be indistinguishable from the final Terminator, empty Entries are rejected.
+//! Both the ANSI (`u8`) and the wide (`u16`) Flavour are provided through `MultiAnsiString` and `MultiWideString`.
+
+use std::error::Error;
+use std::ffi::CStr;
+use std::fmt;
+use crate::type_wrappers::type_conversion::wide_string::{encode_utf16, WideCStr};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+}
+
+/// Code Unit of a Multi String, implemented for `u8` (ANSI) and `u16` (wide)
+pub trait CodeUnit: private::Sealed + Copy + Eq + fmt::Debug + 'static {
+    /// The Null-Character
+    const NUL: Self;
+}
+
+impl CodeUnit for u8 {
+    const NUL: u8 = 0;
+}
+
+impl CodeUnit for u16 {
+    const NUL: u16 = 0;
+}
+
+/// Errors that can occur while building, parsing or validating Multi Strings
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MultiStringError {
+    /// An Entry is empty, which would terminate the List early
+    EmptyEntry {
+        /// The Index of the empty Entry
+        index: usize
+    },
+
+    /// An Entry that should be added contains a Null-Character
+    InteriorNul {
+        /// The Index of the Entry
+        index: usize,
+        /// The Position of the Null-Character inside the Entry
+        position: usize
+    },
+
+    /// The Data does not end with the Double-Null-Terminator
+    MissingTerminator,
+}
+
+impl fmt::Display for MultiStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            MultiStringError::EmptyEntry { index } => write!(f, "multi string entry {} is empty", index),
+            MultiStringError::InteriorNul { index, position } => write!(f, "multi string entry {} contains a nul at position {}", index, position),
+            MultiStringError::MissingTerminator => write!(f, "multi string is not double-nul-terminated"),
+        };
+    }
+}
+
+impl Error for MultiStringError {}
+
+/// Values that can be added as an Entry to a Multi String
+pub trait MultiStringEntry<C: CodeUnit> {
+    /// Returns the Code Units of the Entry (without a Null-Terminator)
+    fn to_units(&self) -> Vec<C>;
+}
+
+impl<C: CodeUnit> MultiStringEntry<C> for [C] {
+    #[inline]
+    fn to_units(&self) -> Vec<C> {
+        return self.to_vec();
+    }
+}
+
+impl<C: CodeUnit> MultiStringEntry<C> for Vec<C> {
+    #[inline]
+    fn to_units(&self) -> Vec<C> {
+        return self.clone();
+    }
+}
+
+impl MultiStringEntry<u16> for str {
+    #[inline]
+    fn to_units(&self) -> Vec<u16> {
+        return encode_utf16(self);
+    }
+}
+
+impl MultiStringEntry<u16> for WideCStr {
+    #[inline]
+    fn to_units(&self) -> Vec<u16> {
+        return self.as_slice().to_vec();
+    }
+}
+
+/// The Bytes of the String are used as they are, this is only correct for ASCII or if the ANSI Code Page is UTF-8
+impl MultiStringEntry<u8> for str {
+    #[inline]
+    fn to_units(&self) -> Vec<u8> {
+        return self.as_bytes().to_vec();
+    }
+}
+
+impl MultiStringEntry<u8> for CStr {
+    #[inline]
+    fn to_units(&self) -> Vec<u8> {
+        return self.to_bytes().to_vec();
+    }
+}
+
+impl<C: CodeUnit, T: MultiStringEntry<C> + ?Sized> MultiStringEntry<C> for &T {
+    #[inline]
+    fn to_units(&self) -> Vec<C> {
+        return (**self).to_units();
+    }
+}
+
+/// Double-Null-Terminated List of Strings
+///
+/// The Buffer always ends with the Double-Null-Terminator, so `as_ptr()` can be passed to the API directly.
+/// An empty List is stored as two Null-Characters.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MultiString<C: CodeUnit> {
+    units: Vec<C>,
+    count: usize,
+}
+
+/// Double-Null-Terminated List of ANSI Strings
+pub type MultiAnsiString = MultiString<u8>;
+
+/// Double-Null-Terminated List of wide Strings
+pub type MultiWideString = MultiString<u16>;
+
+impl<C: CodeUnit> MultiString<C> {
+    /// Creates an empty List
+    #[inline]
+    pub fn new() -> MultiString<C> {
+        return MultiString { units: vec![C::NUL, C::NUL], count: 0 };
+    }
+
+    /// Creates a Builder for a new List
+    #[inline]
+    pub fn builder() -> MultiStringBuilder<C> {
+        return MultiStringBuilder { units: Vec::new(), count: 0 };
+    }
+
+    /// Creates a List from an Iterator of Entries
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if one of the Entries is empty or contains a Null-Character
+    pub fn from_entries<I>(entries: I) -> Result<MultiString<C>, MultiStringError> where I: IntoIterator, I::Item: MultiStringEntry<C> {
+        let mut builder = MultiString::builder();
+        for entry in entries {
+            builder = builder.push(entry)?;
+        }
+        return Ok(builder.build());
+    }
+
+    /// Parses a List from Code Units
+    ///
+    /// Missing final Terminators are tolerated, so `"a\0b\0"` and `"a\0b"` are both accepted.
+    /// Null-Characters after the Double-Null-Terminator (for example Padding of a Buffer) are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The Code Units of the List
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if there is an empty Entry followed by further Entries
+    pub fn from_units(units: &[C]) -> Result<MultiString<C>, MultiStringError> {
+        let end = find_terminator(units);
+        let (list, rest) = match end {
+            Some(end) => (&units[..end], &units[end..]),
+            None => (units, &units[units.len()..]),
+        };
+        if rest.iter().any(|unit| *unit != C::NUL) {
+            return Err(MultiStringError::EmptyEntry { index: count_entries(list) });
+        }
+        return Ok(MultiString::from_list_unchecked(list));
+    }
+
+    /// Parses a List from Code Units, ignoring everything after the first Double-Null-Terminator
+    ///
+    /// This is useful for Buffers filled by the API, which may contain arbitrary Data after the List.
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The Code Units of the List, missing final Terminators are tolerated
+    pub fn from_units_until_terminator(units: &[C]) -> MultiString<C> {
+        let list = match find_terminator(units) {
+            Some(end) => &units[..end],
+            None => units,
+        };
+        return MultiString::from_list_unchecked(list);
+    }
+
+    /// Reads a List from a Pointer returned by the API
+    ///
+    /// # Safety
+    ///
+    /// The Pointer must be non-null and point to a valid Double-Null-Terminated List
+    pub unsafe fn from_ptr(pointer: *const C) -> MultiString<C> {
+        let mut length = 0;
+        // The empty List may consist of a single Null-Character, in which case the List ends there
+        if *pointer != C::NUL {
+            while !(*pointer.add(length) == C::NUL && *pointer.add(length + 1) == C::NUL) {
+                length += 1;
+            }
+            length += 1;
+        }
+        return MultiString::from_list_unchecked(std::slice::from_raw_parts(pointer, length));
+    }
+
+    /// Validates that Code Units form a complete List that can be passed to the API as is
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The Code Units that should be validated
+    ///
+    /// # Return
+    ///
+    /// This Function returns the Number of Entries in the List,
+    /// or an Error if the Double-Null-Terminator is missing or there is Data after it
+    pub fn validate(units: &[C]) -> Result<usize, MultiStringError> {
+        return match find_terminator(units) {
+            Some(end) if end + 1 == units.len() || (end == 0 && units.len() == 2) => Ok(count_entries(&units[..end])),
+            Some(end) => Err(MultiStringError::EmptyEntry { index: count_entries(&units[..end]) }),
+            None => Err(MultiStringError::MissingTerminator),
+        };
+    }
+
+    /// Builds the List from Entries separated by Null-Characters, without the final Terminator
+    fn from_list_unchecked(list: &[C]) -> MultiString<C> {
+        if list.iter().all(|unit| *unit == C::NUL) {
+            return MultiString::new();
+        }
+        let mut units = Vec::with_capacity(list.len() + 2);
+        units.extend_from_slice(list);
+        if list.last() != Some(&C::NUL) {
+            units.push(C::NUL);
+        }
+        units.push(C::NUL);
+        return MultiString { units, count: count_entries(list) };
+    }
+
+    /// Returns an Iterator over the Entries (without their Null-Terminators)
+    #[inline]
+    pub fn iter(&self) -> Entries<'_, C> {
+        return Entries { units: &self.units[..self.units.len() - 1], remaining: self.count };
+    }
+
+    /// Returns the Number of Entries
+    #[inline]
+    pub fn len(&self) -> usize {
+        return self.count;
+    }
+
+    /// Returns whether the List has no Entries
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        return self.count == 0;
+    }
+
+    /// Returns the Code Units including the Double-Null-Terminator
+    #[inline]
+    pub fn as_units_with_terminator(&self) -> &[C] {
+        return &self.units;
+    }
+
+    /// Returns a Pointer to the List that can be passed to the API
+    #[inline]
+    pub fn as_ptr(&self) -> *const C {
+        return self.units.as_ptr();
+    }
+
+    /// Returns the Code Units including the Double-Null-Terminator
+    #[inline]
+    pub fn into_units(self) -> Vec<C> {
+        return self.units;
+    }
+}
+
+impl MultiWideString {
+    /// Returns an Iterator over the Entries as Null-Terminated wide Strings
+    pub fn iter_wide_c_str(&self) -> impl Iterator<Item=&WideCStr> {
+        return self.iter().map(|entry| {
+            // Every Entry is directly followed by its Null-Terminator inside the Buffer
+            let units = unsafe { std::slice::from_raw_parts(entry.as_ptr(), entry.len() + 1) };
+            unsafe { WideCStr::from_slice_with_nul_unchecked(units) }
+        });
+    }
+}
+
+impl MultiAnsiString {
+    /// Returns an Iterator over the Entries as Null-Terminated C Strings
+    pub fn iter_c_str(&self) -> impl Iterator<Item=&CStr> {
+        return self.iter().map(|entry| {
+            // Every Entry is directly followed by its Null-Terminator inside the Buffer
+            let bytes = unsafe { std::slice::from_raw_parts(entry.as_ptr(), entry.len() + 1) };
+            unsafe { CStr::from_bytes_with_nul_unchecked(bytes) }
+        });
+    }
+}
+
+impl<C: CodeUnit> Default for MultiString<C> {
+    #[inline]
+    fn default() -> Self {
+        return MultiString::new();
+    }
+}
+
+impl<C: CodeUnit> fmt::Debug for MultiString<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return f.debug_list().entries(self.iter()).finish();
+    }
+}
+
+impl<'a, C: CodeUnit> IntoIterator for &'a MultiString<C> {
+    type Item = &'a [C];
+    type IntoIter = Entries<'a, C>;
+
+    #[inline]
+    fn into_iter(self) -> Entries<'a, C> {
+        return self.iter();
+    }
+}
+
+/// Finds the Index of the final Null-Character of the Double-Null-Terminator
+///
+/// A leading Null-Character counts as the Terminator of an empty List
+fn find_terminator<C: CodeUnit>(units: &[C]) -> Option<usize> {
+    if units.first() == Some(&C::NUL) {
+        return Some(0);
+    }
+    return units.windows(2).position(|pair| pair[0] == C::NUL && pair[1] == C::NUL).map(|index| index + 1);
+}
+
+/// Counts the Entries in a List without its final Terminator
+fn count_entries<C: CodeUnit>(list: &[C]) -> usize {
+    return list.split(|unit| *unit == C::NUL).filter(|entry| !entry.is_empty()).count();
+}
+
+/// Builder for Multi Strings
+///
+/// # Example
+///
+/// ```ignore
+/// let channels = MultiWideString::builder().push("Application")?.push("System")?.build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultiStringBuilder<C: CodeUnit> {
+    units: Vec<C>,
+    count: usize,
+}
+
+impl<C: CodeUnit> MultiStringBuilder<C> {
+    /// Adds an Entry to the List
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if the Entry is empty or contains a Null-Character
+    pub fn push<E: MultiStringEntry<C>>(mut self, entry: E) -> Result<MultiStringBuilder<C>, MultiStringError> {
+        let units = entry.to_units();
+        if units.is_empty() {
+            return Err(MultiStringError::EmptyEntry { index: self.count });
+        }
+        if let Some(position) = units.iter().position(|unit| *unit == C::NUL) {
+            return Err(MultiStringError::InteriorNul { index: self.count, position });
+        }
+        self.units.extend_from_slice(&units);
+        self.units.push(C::NUL);
+        self.count += 1;
+        return Ok(self);
+    }
+
+    /// Finishes the List by appending the final Terminator
+    pub fn build(mut self) -> MultiString<C> {
+        if self.count == 0 {
+            return MultiString::new();
+        }
+        self.units.push(C::NUL);
+        return MultiString { units: self.units, count: self.count };
+    }
+}
+
+/// Iterator over the Entries of a Multi String
+#[derive(Clone, Debug)]
+pub struct Entries<'a, C: CodeUnit> {
+    units: &'a [C],
+    remaining: usize,
+}
+
+impl<'a, C: CodeUnit> Iterator for Entries<'a, C> {
+    type Item = &'a [C];
+
+    fn next(&mut self) -> Option<&'a [C]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let end = self.units.iter().position(|unit| *unit == C::NUL).unwrap_or(self.units.len());
+        let entry = &self.units[..end];
+        self.units = &self.units[(end + 1).min(self.units.len())..];
+        self.remaining -= 1;
+        return Some(entry);
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.remaining, Some(self.remaining));
+    }
+}
+
+impl<C: CodeUnit> ExactSizeIterator for Entries<'_, C> {}
+
+/// Tests for the Multi String Encoder and Decoder
+#[cfg(test)]
+mod test_multi_string {
+    use std::ffi::CString;
+    use crate::type_wrappers::type_conversion::multi_string::{MultiAnsiString, MultiStringError, MultiWideString};
+    use crate::type_wrappers::type_conversion::wide_string::WideCString;
+
+    /// Tests that the Builder produces a Double-Null-Terminated List
+    #[test]
+    fn test_builder() {
+        let list = MultiAnsiString::builder().push("first").unwrap().push("second").unwrap().build();
+
+        assert_eq!(list.as_units_with_terminator(), b"first\0second\0\0");
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![&b"first"[..], &b"second"[..]]);
+    }
+
+    /// Tests that empty Entries and Entries with Null-Characters are rejected by the Builder
+    #[test]
+    fn test_builder_rejects_invalid_entries() {
+        let builder = MultiWideString::builder().push("first").unwrap();
+
+        assert_eq!(builder.clone().push("").unwrap_err(), MultiStringError::EmptyEntry { index: 1 });
+        assert_eq!(builder.push("a\0b").unwrap_err(), MultiStringError::InteriorNul { index: 1, position: 1 });
+    }
+
+    /// Tests that the empty List is represented by two Null-Characters
+    #[test]
+    fn test_empty_list() {
+        let list = MultiWideString::new();
+
+        assert_eq!(list.as_units_with_terminator(), &[0, 0]);
+        assert_eq!(MultiWideString::builder().build(), list);
+        assert_eq!(MultiWideString::from_units(&[]).unwrap(), list);
+        assert_eq!(MultiWideString::from_units(&[0]).unwrap(), list);
+        assert_eq!(MultiWideString::validate(&[0, 0]), Ok(0));
+    }
+
+    /// Tests that missing final Terminators are tolerated while parsing
+    #[test]
+    fn test_missing_terminator_is_tolerated() {
+        let expected = MultiAnsiString::from_entries(vec!["a", "b"]).unwrap();
+
+        assert_eq!(MultiAnsiString::from_units(b"a\0b\0\0").unwrap(), expected);
+        assert_eq!(MultiAnsiString::from_units(b"a\0b\0").unwrap(), expected);
+        assert_eq!(MultiAnsiString::from_units(b"a\0b").unwrap(), expected);
+        assert_eq!(MultiAnsiString::from_units(b"a\0b\0\0\0\0").unwrap(), expected);
+    }
+
+    /// Tests that empty inner Entries are rejected while parsing
+    #[test]
+    fn test_empty_inner_entry_is_rejected() {
+        assert_eq!(MultiAnsiString::from_units(b"a\0\0b\0\0"), Err(MultiStringError::EmptyEntry { index: 1 }));
+        assert_eq!(MultiAnsiString::from_units_until_terminator(b"a\0\0b\0\0").len(), 1);
+    }
+
+    /// Tests the strict Validation
+    #[test]
+    fn test_validate() {
+        assert_eq!(MultiAnsiString::validate(b"a\0bc\0\0"), Ok(2));
+        assert_eq!(MultiAnsiString::validate(b"a\0bc\0"), Err(MultiStringError::MissingTerminator));
+        assert_eq!(MultiAnsiString::validate(b"a\0\0bc\0\0"), Err(MultiStringError::EmptyEntry { index: 1 }));
+    }
+
+    /// Tests that a List can be read from a raw Pointer
+    #[test]
+    fn test_from_ptr() {
+        let units: Vec<u16> = "PATH=C:\\\0TEMP=D:\\\0\0garbage".encode_utf16().collect();
+        let list = unsafe { MultiWideString::from_ptr(units.as_ptr()) };
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(unsafe { MultiWideString::from_ptr([0u16, 0].as_ptr()) }, MultiWideString::new());
+    }
+
+    /// Tests that the Entries can be borrowed as Null-Terminated Strings
+    #[test]
+    fn test_null_terminated_entries() {
+        let wide = MultiWideString::from_entries(vec!["Application", "System"]).unwrap();
+        let ansi = MultiAnsiString::from_entries(vec!["Application", "System"]).unwrap();
+
+        let wide_entries: Vec<WideCString> = wide.iter_wide_c_str().map(|entry| entry.to_wide_c_string()).collect();
+        let ansi_entries: Vec<CString> = ansi.iter_c_str().map(|entry| entry.to_owned()).collect();
+        assert_eq!(wide_entries, vec![WideCString::new("Application").unwrap(), WideCString::new("System").unwrap()]);
+        assert_eq!(ansi_entries, vec![CString::new("Application").unwrap(), CString::new("System").unwrap()]);
+    }
+}
@@ -0,0 +1,326 @@
+//! # Reflection
+//!
+//! Names of the Win32 Constants behind the Enums generated by `CONST_TO_ENUM!` and the Bitflags generated by `NAMED_BITFLAGS!`.
+//!
+//! Enum Values map to a single Constant Name, like `SW_SHOWMAXIMIZED`.
+//! Bitflags map to a List of Constant Names separated by `|`, like `WS_CAPTION|WS_SYSMENU`.
+//! Both Kinds of Types implement `Display` and `FromStr` using these Names, so they can be used in Configuration Files and Diagnostics.
+
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when a String does not name a Constant of a Type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseConstantError {
+    type_name: &'static str,
+    name: String,
+}
+
+impl ParseConstantError {
+    /// Creates a new Error for a Name that is not a Constant of a Type
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The Name of the Type that was parsed
+    /// * `name` - The Name that could not be found
+    #[inline]
+    pub fn new(type_name: &'static str, name: &str) -> ParseConstantError {
+        return ParseConstantError {
+            type_name,
+            name: name.to_string(),
+        };
+    }
+
+    /// Returns the Name of the Type that was parsed
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        return self.type_name;
+    }
+
+    /// Returns the Name that could not be found
+    #[inline]
+    pub fn name(&self) -> &str {
+        return &self.name;
+    }
+}
+
+impl fmt::Display for ParseConstantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self.name.is_empty() {
+            true => write!(f, "empty constant name for {}", self.type_name),
+            false => write!(f, "unknown constant name {:?} for {}", self.name, self.type_name),
+        };
+    }
+}
+
+impl Error for ParseConstantError {}
+
+/// Enums whose Variants correspond to Win32 Constants
+///
+/// This Trait is implemented by every Enum generated by `CONST_TO_ENUM!`.
+pub trait ConstantEnum: Copy + 'static {
+    /// The Name of the Type, used in Error Messages
+    const TYPE_NAME: &'static str;
+
+    /// All Variants with a Constant, in the Order of their Declaration
+    ///
+    /// # Note
+    ///
+    /// The `Unknown` Variant of open Enums is not included, since it does not correspond to a single Constant.
+    const VARIANTS: &'static [Self];
+
+    /// Returns the Name of the Constant of this Value, like `SW_SHOWMAXIMIZED`
+    ///
+    /// # Return
+    ///
+    /// This Function returns None for `Unknown` Values of open Enums
+    fn constant_name(&self) -> Option<&'static str>;
+
+    /// Returns the Value of the Constant with the given Name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The Name of the Constant, like `SW_SHOWMAXIMIZED`
+    fn from_constant_name(name: &str) -> Result<Self, ParseConstantError>;
+}
+
+/// Bitflags whose Flags correspond to Win32 Constants
+///
+/// This Trait is implemented by every Bitflags Type generated by `NAMED_BITFLAGS!`.
+pub trait ConstantFlags: Copy + 'static {
+    /// The Name of the Type, used in Error Messages
+    const TYPE_NAME: &'static str;
+
+    /// All Flags with the Name of their Constant, in the Order of their Declaration
+    const FLAGS: &'static [(&'static str, Self)];
+
+    /// Returns the Names of the Constants that make up this Value
+    ///
+    /// Composite Flags are preferred over their Parts, so `WS_CAPTION` is returned instead of `WS_BORDER` and `WS_DLGFRAME`.
+    /// An empty Value returns the Name of a Flag without Bits if there is one.
+    ///
+    /// # Note
+    ///
+    /// Bits that do not belong to any Flag are not represented in the returned Names
+    fn constant_names(&self) -> Vec<&'static str>;
+
+    /// Returns the Value made up of the given Constants
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The Names of the Constants separated by `|`, like `WS_CAPTION|WS_SYSMENU`, or `0` for an empty Value
+    fn from_constant_names(names: &str) -> Result<Self, ParseConstantError>;
+}
+
+/// Returns the last Segment of a stringified Path, which is the Name of the Constant
+///
+/// # Arguments
+///
+/// * `path` - The Path as produced by `stringify!`, like `winapi::um::winuser::SW_HIDE`
+#[doc(hidden)]
+pub const fn constant_name_of(path: &'static str) -> &'static str {
+    let mut rest = path.as_bytes();
+    let mut segment = rest;
+    while let [first, tail @ ..] = rest {
+        if *first == b':' {
+            segment = tail;
+        }
+        rest = tail;
+    }
+    while let [b' ', tail @ ..] = segment {
+        segment = tail;
+    }
+    while let [head @ .., b' '] = segment {
+        segment = head;
+    }
+    return match std::str::from_utf8(segment) {
+        Ok(name) => name,
+        Err(_) => panic!("constant path is not valid UTF-8"),
+    };
+}
+
+/// Splits Bits into the Names of the Flags that make them up, preferring Flags with more Bits
+///
+/// # Arguments
+///
+/// * `flags` - The named Flags of the Type
+/// * `bits` - The Bits that should be split
+/// * `flag_bits` - Returns the Bits of a Flag
+///
+/// # Return
+///
+/// This Function returns the Names and the Bits that do not belong to any Flag
+#[doc(hidden)]
+pub fn decompose_flags<T: Copy>(flags: &[(&'static str, T)], bits: u64, flag_bits: impl Fn(T) -> u64) -> (Vec<&'static str>, u64) {
+    if bits == 0 {
+        let names = flags.iter()
+            .find(|(_, flag)| flag_bits(*flag) == 0)
+            .map(|(name, _)| vec![*name])
+            .unwrap_or_default();
+        return (names, 0);
+    }
+
+    let mut candidates: Vec<(&'static str, u64)> = flags.iter()
+        .map(|(name, flag)| (*name, flag_bits(*flag)))
+        .filter(|(_, flag)| *flag != 0)
+        .collect();
+    // Stable Sort, so Aliases are resolved to the first declared Flag
+    candidates.sort_by_key(|(_, flag)| std::cmp::Reverse(flag.count_ones()));
+
+    let mut names = Vec::new();
+    let mut remaining = bits;
+    for (name, flag) in candidates {
+        if remaining & flag == flag {
+            names.push(name);
+            remaining &= !flag;
+        }
+    }
+    return (names, remaining);
+}
+
+/// Joins the Bits of the Flags named in a String
+///
+/// # Arguments
+///
+/// * `type_name` - The Name of the Type, used in Errors
+/// * `flags` - The named Flags of the Type
+/// * `names` - The Names separated by `|`, or `0` for no Flags
+/// * `flag_bits` - Returns the Bits of a Flag
+#[doc(hidden)]
+pub fn compose_flags<T: Copy>(type_name: &'static str, flags: &[(&'static str, T)], names: &str, flag_bits: impl Fn(T) -> u64) -> Result<u64, ParseConstantError> {
+    if names.trim() == "0" {
+        return Ok(0);
+    }
+
+    let mut bits = 0;
+    for name in names.split('|').map(str::trim) {
+        let flag = flags.iter()
+            .find(|(flag_name, _)| *flag_name == name)
+            .ok_or_else(|| ParseConstantError::new(type_name, name))?;
+        bits |= flag_bits(flag.1);
+    }
+    return Ok(bits);
+}
+
+/// Tests for the Constant Names of Enums and Bitflags
+#[cfg(test)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+mod test_reflection {
+    use std::convert::TryFrom;
+    use crate::{CONST_TO_ENUM, NAMED_BITFLAGS};
+    use crate::type_wrappers::type_conversion::reflection::{ConstantEnum, ConstantFlags, ParseConstantError};
+
+    mod constants {
+        pub const SHOW_HIDE: i32 = 0;
+        pub const SHOW_NORMAL: i32 = 1;
+        pub const SHOW_MAXIMIZED: i32 = 3;
+
+        pub const STYLE_NONE: u32 = 0;
+        pub const STYLE_BORDER: u32 = 0x1;
+        pub const STYLE_FRAME: u32 = 0x2;
+        pub const STYLE_CAPTION: u32 = 0x3;
+        pub const STYLE_MENU: u32 = 0x4;
+        pub const STYLE_MENU_ALIAS: u32 = 0x4;
+    }
+
+    CONST_TO_ENUM!(const_enum SHOW, i32 {
+        HIDE = constants::SHOW_HIDE,
+        NORMAL = constants::SHOW_NORMAL,
+        MAXIMIZED = constants::SHOW_MAXIMIZED,
+    });
+
+    CONST_TO_ENUM!(const_enum_open OPEN_SHOW, i32 {
+        HIDE = constants::SHOW_HIDE,
+        NORMAL = constants::SHOW_NORMAL,
+    });
+
+    NAMED_BITFLAGS!(
+        pub struct Style : u32 {
+            const NONE = constants::STYLE_NONE;
+            const BORDER = constants::STYLE_BORDER;
+            const FRAME = constants::STYLE_FRAME;
+            const CAPTION = constants::STYLE_CAPTION;
+            const MENU = constants::STYLE_MENU;
+            const MENU_ALIAS = constants::STYLE_MENU_ALIAS;
+    });
+
+    NAMED_BITFLAGS!(
+        pub struct Plain : u32 {
+            const BORDER = constants::STYLE_BORDER;
+    });
+
+    /// Tests that Enum Values map to and from their Constant Names
+    #[test]
+    fn test_enum_names() {
+        assert_eq!(SHOW::MAXIMIZED.constant_name(), Some("SHOW_MAXIMIZED"));
+        assert_eq!(SHOW::MAXIMIZED.to_string(), "SHOW_MAXIMIZED");
+        assert!(matches!("SHOW_NORMAL".parse::<SHOW>(), Ok(SHOW::NORMAL)));
+        assert!(matches!(SHOW::from_constant_name("SHOW_HIDE"), Ok(SHOW::HIDE)));
+    }
+
+    /// Tests that all Variants are enumerated in the Order of their Declaration
+    #[test]
+    fn test_enum_variants() {
+        let names: Vec<&str> = SHOW::VARIANTS.iter().map(|variant| variant.constant_name().unwrap()).collect();
+
+        assert_eq!(names, vec!["SHOW_HIDE", "SHOW_NORMAL", "SHOW_MAXIMIZED"]);
+        assert_eq!(OPEN_SHOW::VARIANTS, &[OPEN_SHOW::HIDE, OPEN_SHOW::NORMAL]);
+        for variant in SHOW::VARIANTS {
+            assert_eq!(SHOW::try_from(Into::<i32>::into(*variant)).unwrap().constant_name(), variant.constant_name());
+        }
+    }
+
+    /// Tests that unknown Names are rejected with a descriptive Error
+    #[test]
+    fn test_enum_unknown_name() {
+        let error = "SHOW_FULLSCREEN".parse::<SHOW>().unwrap_err();
+
+        assert_eq!(error, ParseConstantError::new("SHOW", "SHOW_FULLSCREEN"));
+        assert_eq!(error.to_string(), "unknown constant name \"SHOW_FULLSCREEN\" for SHOW");
+        assert_eq!("NORMAL".parse::<SHOW>().unwrap_err().name(), "NORMAL");
+    }
+
+    /// Tests that Unknown Values of open Enums are displayed and parsed as their raw Value
+    #[test]
+    fn test_open_enum_unknown_values() {
+        assert_eq!(OPEN_SHOW::Unknown(12).constant_name(), None);
+        assert_eq!(OPEN_SHOW::Unknown(12).to_string(), "12");
+        assert_eq!(OPEN_SHOW::Unknown(1).to_string(), "SHOW_NORMAL");
+        assert_eq!("12".parse::<OPEN_SHOW>(), Ok(OPEN_SHOW::Unknown(12)));
+        assert_eq!("SHOW_HIDE".parse::<OPEN_SHOW>(), Ok(OPEN_SHOW::HIDE));
+        assert!("SHOW_MAXIMIZED".parse::<OPEN_SHOW>().is_err());
+    }
+
+    /// Tests that Bitflags are displayed with composite Flags first
+    #[test]
+    fn test_flags_display() {
+        assert_eq!((Style::CAPTION | Style::MENU).to_string(), "STYLE_CAPTION|STYLE_MENU");
+        assert_eq!(Style::BORDER.to_string(), "STYLE_BORDER");
+        assert_eq!(Style::empty().to_string(), "STYLE_NONE");
+        assert_eq!(Plain::empty().to_string(), "0");
+        assert_eq!(Style::all().constant_names(), vec!["STYLE_CAPTION", "STYLE_MENU"]);
+    }
+
+    /// Tests that Bitflags are parsed from Lists of Constant Names
+    #[test]
+    fn test_flags_parse() {
+        assert_eq!("STYLE_CAPTION|STYLE_MENU".parse::<Style>(), Ok(Style::CAPTION | Style::MENU));
+        assert_eq!("STYLE_BORDER | STYLE_FRAME".parse::<Style>(), Ok(Style::CAPTION));
+        assert_eq!("STYLE_MENU_ALIAS".parse::<Style>(), Ok(Style::MENU));
+        assert_eq!("0".parse::<Style>(), Ok(Style::empty()));
+        assert_eq!("STYLE_NONE".parse::<Style>(), Ok(Style::empty()));
+        for (_, flag) in Style::FLAGS {
+            assert_eq!(flag.to_string().parse::<Style>(), Ok(*flag));
+        }
+    }
+
+    /// Tests that unknown and empty Flag Names are rejected with a descriptive Error
+    #[test]
+    fn test_flags_unknown_name() {
+        let error = "STYLE_CAPTION|STYLE_HUGE".parse::<Style>().unwrap_err();
+
+        assert_eq!(error.to_string(), "unknown constant name \"STYLE_HUGE\" for Style");
+        assert_eq!("STYLE_CAPTION||STYLE_MENU".parse::<Style>().unwrap_err().to_string(), "empty constant name for Style");
+        assert_eq!("".parse::<Style>().unwrap_err().type_name(), "Style");
+    }
+}
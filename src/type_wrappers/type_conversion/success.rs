@@ -0,0 +1,267 @@
+//! # Success Conventions
+//!
+//! Policies that decide whether the Return Value of an FFI Function indicates Success.
+//!
+//! Win32 Functions report Failure in different Ways, depending on the Header they come from:
+//!
+//! * `HResultPolicy` - An HRESULT, negative Values are Failures
+//! * `NtStatusPolicy` - An NTSTATUS Code, negative Values are Failures
+//! * `BoolPolicy` - A BOOL, FALSE is a Failure described by GetLastError
+//! * `NonNullPolicy` - A Handle or Pointer, Null is a Failure described by GetLastError
+//! * `SentinelPolicy` - A Value, one specific Value is a Failure described by GetLastError, like the `-1` of GetMessage
+//!
+//! Every fallible Wrapper routes the Return Value of its FFI Function through one of these Policies,
+//! so that all Failures are returned as `windings::Error`.
+//! The Policies take the Source of the last Error Code as an Argument, so their Decisions can be tested without calling the API.
+
+use crate::error::{Error, HResult, NtStatus, Win32Error};
+#[cfg(any(windows, test))]
+use crate::error::last_error::{ActiveLastError, LastErrorStore};
+use crate::type_wrappers::type_conversion::win_types::BOOL;
+
+/// Decides whether a Return Value of type `R` indicates Success
+pub trait SuccessPolicy<R> {
+    /// The Value returned on Success
+    type Output;
+
+    /// Checks a Return Value
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The Return Value of the FFI Function
+    /// * `last_error` - Fetches the last Error Code, only called on Failure and only by Policies that use it
+    fn check<E: FnOnce() -> u32>(result: R, last_error: E) -> Result<Self::Output, Error>;
+}
+
+/// Policy for Functions returning an HRESULT
+#[derive(Debug, Copy, Clone)]
+pub struct HResultPolicy;
+
+impl SuccessPolicy<i32> for HResultPolicy {
+    type Output = ();
+
+    #[inline]
+    fn check<E: FnOnce() -> u32>(result: i32, _last_error: E) -> Result<(), Error> {
+        return match result >= 0 {
+            true => Ok(()),
+            false => Err(Error::from(HResult(result))),
+        };
+    }
+}
+
+/// Policy for Functions returning an NTSTATUS Code
+#[derive(Debug, Copy, Clone)]
+pub struct NtStatusPolicy;
+
+impl SuccessPolicy<i32> for NtStatusPolicy {
+    type Output = ();
+
+    #[inline]
+    fn check<E: FnOnce() -> u32>(status: i32, _last_error: E) -> Result<(), Error> {
+        return match status >= 0 {
+            true => Ok(()),
+            false => Err(Error::from(NtStatus(status))),
+        };
+    }
+}
+
+/// Policy for Functions returning a BOOL and setting the last Error Code on Failure
+#[derive(Debug, Copy, Clone)]
+pub struct BoolPolicy;
+
+impl SuccessPolicy<BOOL> for BoolPolicy {
+    type Output = ();
+
+    #[inline]
+    fn check<E: FnOnce() -> u32>(result: BOOL, last_error: E) -> Result<(), Error> {
+        return match result != 0 {
+            true => Ok(()),
+            false => Err(Error::from(Win32Error(last_error()))),
+        };
+    }
+}
+
+/// Policy for Functions returning a Handle or Pointer and setting the last Error Code on Failure
+#[derive(Debug, Copy, Clone)]
+pub struct NonNullPolicy;
+
+impl<T> SuccessPolicy<*mut T> for NonNullPolicy {
+    type Output = *mut T;
+
+    #[inline]
+    fn check<E: FnOnce() -> u32>(result: *mut T, last_error: E) -> Result<*mut T, Error> {
+        return match result.is_null() {
+            false => Ok(result),
+            true => Err(Error::from(Win32Error(last_error()))),
+        };
+    }
+}
+
+/// Return Values that can be compared with the Sentinel of a SentinelPolicy
+pub trait SentinelValue: Copy + PartialEq {
+    /// Converts the Sentinel into this Type, wrapping it like an `as` Cast
+    ///
+    /// # Arguments
+    ///
+    /// * `sentinel` - The Sentinel of the Policy
+    fn from_sentinel(sentinel: i128) -> Self;
+}
+
+macro_rules! sentinel_value {
+    ($($type:ty),*) => {
+        $(
+            impl SentinelValue for $type {
+                #[inline]
+                fn from_sentinel(sentinel: i128) -> $type {
+                    return sentinel as $type;
+                }
+            }
+        )*
+    };
+}
+
+sentinel_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Policy for Functions returning a Value, where one specific Value indicates Failure and sets the last Error Code
+///
+/// # Note
+///
+/// RegisterClassEx returns `0` on Failure, so it uses `SentinelPolicy<0>`.
+/// GetMessage returns `-1` on Failure, but `0` for WM_QUIT, so it uses `SentinelPolicy<-1>`.
+///
+/// The Sentinel is compared in the Width of the Return Value,
+/// so `SentinelPolicy<-1>` matches `0xFFFFFFFF` of a `u32` and `SentinelPolicy<0xFFFFFFFF>` matches `-1` of an `i32`.
+#[derive(Debug, Copy, Clone)]
+pub struct SentinelPolicy<const SENTINEL: i128>;
+
+impl<R: SentinelValue, const SENTINEL: i128> SuccessPolicy<R> for SentinelPolicy<SENTINEL> {
+    type Output = R;
+
+    #[inline]
+    fn check<E: FnOnce() -> u32>(result: R, last_error: E) -> Result<R, Error> {
+        return match result == R::from_sentinel(SENTINEL) {
+            false => Ok(result),
+            true => Err(Error::from(Win32Error(last_error()))),
+        };
+    }
+}
+
+/// Checks the Return Value of an FFI Function that was just called, using the last Error Code of the calling Thread
+///
+/// The last Error Code is fetched from the active Store of this Build, see `error::last_error`.
+/// That is GetLastError on Windows and a thread-local Emulation in Test Builds on other Hosts.
+///
+/// # Arguments
+///
+/// * `api` - The Name of the FFI Function, which the Error is attributed to
+/// * `result` - The Return Value of the FFI Function
+///
+/// # Note
+///
+/// This has to be called directly after the FFI Function, before anything else can overwrite the last Error Code
+#[cfg(any(windows, test))]
+#[inline]
+pub fn check<P: SuccessPolicy<R>, R>(api: &'static str, result: R) -> Result<P::Output, Error> {
+    return check_with::<P, ActiveLastError, R>(api, result);
+}
+
+/// Checks the Return Value of an FFI Function that was just called, using the last Error Code of a given Store
+///
+/// # Arguments
+///
+/// * `api` - The Name of the FFI Function, which the Error is attributed to
+/// * `result` - The Return Value of the FFI Function
+#[cfg(any(windows, test))]
+#[inline]
+pub(crate) fn check_with<P: SuccessPolicy<R>, S: LastErrorStore, R>(api: &'static str, result: R) -> Result<P::Output, Error> {
+    return P::check(result, S::get).map_err(|error| error.with_api(api));
+}
+
+/// Tests for the Decisions of the Success Policies
+#[cfg(test)]
+mod test_success {
+    use std::cell::Cell;
+    use std::ptr::null_mut;
+    use crate::error::{Error, HResult, NtStatus, Win32Error};
+    use crate::type_wrappers::type_conversion::success::{BoolPolicy, HResultPolicy, NonNullPolicy, NtStatusPolicy, SentinelPolicy, SuccessPolicy};
+
+    const ERROR_ACCESS_DENIED: u32 = 5;
+
+    /// Returns a fake last Error Code
+    fn access_denied() -> u32 {
+        return ERROR_ACCESS_DENIED;
+    }
+
+    /// Returns a last Error Source that fails the Test if it is called
+    fn unused() -> u32 {
+        panic!("the last error code was fetched on success");
+    }
+
+    /// Tests that HRESULTs fail for negative Values only
+    #[test]
+    fn test_hresult() {
+        assert_eq!(HResultPolicy::check(0, unused), Ok(()));
+        assert_eq!(HResultPolicy::check(1, unused), Ok(()));
+        assert_eq!(HResultPolicy::check(0x80070005u32 as i32, unused), Err(Error::from(HResult(0x80070005u32 as i32))));
+    }
+
+    /// Tests that NTSTATUS Codes fail for negative Values only
+    #[test]
+    fn test_ntstatus() {
+        assert_eq!(NtStatusPolicy::check(0, unused), Ok(()));
+        assert_eq!(NtStatusPolicy::check(0x40000000, unused), Ok(()));
+        assert_eq!(NtStatusPolicy::check(0xC0000005u32 as i32, unused), Err(Error::from(NtStatus(0xC0000005u32 as i32))));
+    }
+
+    /// Tests that BOOLs fail for FALSE with the last Error Code
+    #[test]
+    fn test_bool() {
+        assert_eq!(BoolPolicy::check(1, unused), Ok(()));
+        assert_eq!(BoolPolicy::check(-1, unused), Ok(()));
+        assert_eq!(BoolPolicy::check(0, access_denied), Err(Error::from(Win32Error(ERROR_ACCESS_DENIED))));
+    }
+
+    /// Tests that Handles fail for Null with the last Error Code
+    #[test]
+    fn test_non_null() {
+        let mut value = 0u8;
+        let pointer: *mut u8 = &mut value;
+
+        assert_eq!(NonNullPolicy::check(pointer, unused), Ok(pointer));
+        assert_eq!(NonNullPolicy::check(null_mut::<u8>(), access_denied), Err(Error::from(Win32Error(ERROR_ACCESS_DENIED))));
+    }
+
+    /// Tests that Sentinels fail for exactly their Value
+    #[test]
+    fn test_sentinel() {
+        assert_eq!(<SentinelPolicy<-1>>::check(0i32, unused), Ok(0));
+        assert_eq!(<SentinelPolicy<-1>>::check(1i32, unused), Ok(1));
+        assert_eq!(<SentinelPolicy<-1>>::check(-1i32, access_denied), Err(Error::from(Win32Error(ERROR_ACCESS_DENIED))));
+        assert_eq!(<SentinelPolicy<0>>::check(0xC001u16, unused), Ok(0xC001));
+        assert_eq!(<SentinelPolicy<0>>::check(0u16, access_denied), Err(Error::from(Win32Error(ERROR_ACCESS_DENIED))));
+    }
+
+    /// Tests that Sentinels are compared in the Width of the Return Value
+    #[test]
+    fn test_sentinel_width() {
+        assert_eq!(<SentinelPolicy<-1>>::check(0xFFFF_FFFEu32, unused), Ok(0xFFFF_FFFE));
+        assert_eq!(<SentinelPolicy<-1>>::check(0xFFFF_FFFFu32, access_denied), Err(Error::from(Win32Error(ERROR_ACCESS_DENIED))));
+        assert_eq!(<SentinelPolicy<-1>>::check(u64::MAX, access_denied), Err(Error::from(Win32Error(ERROR_ACCESS_DENIED))));
+        assert_eq!(<SentinelPolicy<0xFFFF_FFFF>>::check(-1i32, access_denied), Err(Error::from(Win32Error(ERROR_ACCESS_DENIED))));
+        assert_eq!(<SentinelPolicy<0xFFFF_FFFF>>::check(0xFFFF_FFFFi64, access_denied), Err(Error::from(Win32Error(ERROR_ACCESS_DENIED))));
+        assert_eq!(<SentinelPolicy<0xFFFF_FFFF>>::check(-1i64, unused), Ok(-1));
+    }
+
+    /// Tests that the last Error Code is fetched exactly once on Failure
+    #[test]
+    fn test_last_error_fetched_once() {
+        let calls = Cell::new(0);
+        let last_error = || {
+            calls.set(calls.get() + 1);
+            return ERROR_ACCESS_DENIED;
+        };
+
+        let _ = BoolPolicy::check(0, last_error);
+        assert_eq!(calls.get(), 1);
+    }
+}
@@ -0,0 +1,665 @@
+//! # Wide Strings
+//!
+//! Owned and borrowed Null-Terminated UTF-16 Strings for the wide (`W`) Functions of the Win32 API.
+//!
+//! The Types in this Module mirror `CString` and `CStr` from the Standard Library:
+//! `WideCString` owns its Code Units while `WideCStr` is a borrowed view that can be created from FFI Pointers.
+//! Both always carry their Null-Terminator, so `as_ptr()` can be passed to the API directly.
+//!
+//! The UTF-16 Encoder in this Module is written in plain Rust, so it works on every Platform and can be used in constant contexts by the `widestr!` Macro.
+
+use std::borrow::{Borrow, Cow};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::ops::Deref;
+
+/// Errors that can occur while creating or converting Wide Strings
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WideStringError {
+    /// The Data contains a Null-Character before its end
+    InteriorNul {
+        /// The Position of the first Null-Character in Code Units
+        position: usize
+    },
+
+    /// The Data does not end with a Null-Character
+    NotNulTerminated,
+
+    /// The Data is not valid UTF-16 because of an unpaired Surrogate
+    InvalidUtf16 {
+        /// The Position of the unpaired Surrogate in Code Units
+        position: usize
+    },
+}
+
+impl fmt::Display for WideStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            WideStringError::InteriorNul { position } => write!(f, "wide string contains an interior nul at position {}", position),
+            WideStringError::NotNulTerminated => write!(f, "wide string is not nul-terminated"),
+            WideStringError::InvalidUtf16 { position } => write!(f, "wide string contains an unpaired surrogate at position {}", position),
+        };
+    }
+}
+
+impl Error for WideStringError {}
+
+/// Decodes a single Character from valid UTF-8
+///
+/// # Arguments
+///
+/// * `bytes` - The UTF-8 Bytes, these have to be valid UTF-8 (for example the Bytes of a `str`)
+/// * `index` - The Index of the first Byte of the Character
+///
+/// # Return
+///
+/// This Function returns the Unicode Scalar Value and the Number of Bytes it occupied
+#[inline]
+const fn decode_utf8_character(bytes: &[u8], index: usize) -> (u32, usize) {
+    let first = bytes[index] as u32;
+    if first < 0x80 {
+        return (first, 1);
+    }
+    if first < 0xE0 {
+        let value = ((first & 0x1F) << 6) | (bytes[index + 1] as u32 & 0x3F);
+        return (value, 2);
+    }
+    if first < 0xF0 {
+        let value = ((first & 0x0F) << 12)
+            | ((bytes[index + 1] as u32 & 0x3F) << 6)
+            | (bytes[index + 2] as u32 & 0x3F);
+        return (value, 3);
+    }
+    let value = ((first & 0x07) << 18)
+        | ((bytes[index + 1] as u32 & 0x3F) << 12)
+        | ((bytes[index + 2] as u32 & 0x3F) << 6)
+        | (bytes[index + 3] as u32 & 0x3F);
+    return (value, 4);
+}
+
+/// Encodes a single Unicode Scalar Value as UTF-16
+///
+/// # Arguments
+///
+/// * `value` - The Unicode Scalar Value that should be encoded
+///
+/// # Return
+///
+/// This Function returns the UTF-16 Code Units and how many of them are used (1 or 2)
+#[inline]
+pub(crate) const fn encode_utf16_character(value: u32) -> ([u16; 2], usize) {
+    if value < 0x10000 {
+        return ([value as u16, 0], 1);
+    }
+    let value = value - 0x10000;
+    let high = 0xD800 | (value >> 10) as u16;
+    let low = 0xDC00 | (value & 0x3FF) as u16;
+    return ([high, low], 2);
+}
+
+/// Computes the Number of UTF-16 Code Units needed to encode a String (without a Null-Terminator)
+///
+/// # Arguments
+///
+/// * `value` - The String that should be measured
+pub const fn utf16_length(value: &str) -> usize {
+    let bytes = value.as_bytes();
+    let mut index = 0;
+    let mut length = 0;
+    while index < bytes.len() {
+        let (character, byte_count) = decode_utf8_character(bytes, index);
+        let (_, unit_count) = encode_utf16_character(character);
+        index += byte_count;
+        length += unit_count;
+    }
+    return length;
+}
+
+/// Encodes a String as UTF-16 (without a Null-Terminator)
+///
+/// # Arguments
+///
+/// * `value` - The String that should be encoded
+///
+/// # Return
+///
+/// This Function returns the UTF-16 Code Units of the String
+pub fn encode_utf16(value: &str) -> Vec<u16> {
+    let bytes = value.as_bytes();
+    let mut units = Vec::with_capacity(utf16_length(value) + 1);
+    let mut index = 0;
+    while index < bytes.len() {
+        let (character, byte_count) = decode_utf8_character(bytes, index);
+        let (encoded, unit_count) = encode_utf16_character(character);
+        units.extend_from_slice(&encoded[..unit_count]);
+        index += byte_count;
+    }
+    return units;
+}
+
+/// Decodes UTF-16 Code Units into a String
+///
+/// # Arguments
+///
+/// * `units` - The UTF-16 Code Units that should be decoded
+/// * `lossy` - Whether unpaired Surrogates should be replaced by U+FFFD instead of producing an Error
+///
+/// # Return
+///
+/// This Function returns the decoded String or the Position of the first unpaired Surrogate
+pub(crate) fn decode_utf16(units: &[u16], lossy: bool) -> Result<String, WideStringError> {
+    let mut result = String::with_capacity(units.len());
+    let mut index = 0;
+    while index < units.len() {
+        let unit = units[index] as u32;
+        let value = match unit {
+            0xD800..=0xDBFF if index + 1 < units.len() && (0xDC00..=0xDFFF).contains(&(units[index + 1] as u32)) => {
+                let low = units[index + 1] as u32;
+                index += 1;
+                Some(0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00))
+            }
+            0xD800..=0xDFFF => None,
+            _ => Some(unit),
+        };
+        match value.and_then(std::char::from_u32) {
+            Some(character) => result.push(character),
+            None if lossy => result.push(std::char::REPLACEMENT_CHARACTER),
+            None => return Err(WideStringError::InvalidUtf16 { position: index }),
+        }
+        index += 1;
+    }
+    return Ok(result);
+}
+
+/// Finds the first Null-Character in a Slice of Code Units
+#[inline]
+fn find_nul(units: &[u16]) -> Option<usize> {
+    return units.iter().position(|unit| *unit == 0);
+}
+
+/// Encodes a String Literal into a Null-Terminated UTF-16 Array at compile time
+///
+/// This is an implementation detail of the `widestr!` Macro and should not be used directly.
+/// Compilation fails if the Literal contains an interior Null-Character.
+#[doc(hidden)]
+pub const fn encode_literal<const LENGTH: usize>(value: &str) -> [u16; LENGTH] {
+    let bytes = value.as_bytes();
+    let mut units = [0u16; LENGTH];
+    let mut byte_index = 0;
+    let mut unit_index = 0;
+    while byte_index < bytes.len() {
+        let (character, byte_count) = decode_utf8_character(bytes, byte_index);
+        if character == 0 {
+            panic!("widestr! literal contains an interior nul");
+        }
+        let (encoded, unit_count) = encode_utf16_character(character);
+        units[unit_index] = encoded[0];
+        if unit_count == 2 {
+            units[unit_index + 1] = encoded[1];
+        }
+        byte_index += byte_count;
+        unit_index += unit_count;
+    }
+    return units;
+}
+
+/// Creates a `&'static WideCStr` from a String Literal
+///
+/// The Literal is encoded as UTF-16 at compile time and a Null-Terminator is appended.
+/// Literals containing an interior Null-Character are rejected at compile time.
+///
+/// # Example
+///
+/// ```ignore
+/// let class_name: &'static WideCStr = widestr!("WindingsWindowClass");
+/// ```
+#[macro_export]
+macro_rules! widestr {
+    ($value:literal) => {{
+        const WIDESTR_LENGTH: usize = $crate::wide_string::utf16_length($value) + 1;
+        static WIDESTR_UNITS: [u16; WIDESTR_LENGTH] = $crate::wide_string::encode_literal::<WIDESTR_LENGTH>($value);
+        // The Array is Null-Terminated and free of interior Null-Characters, this is checked by encode_literal
+        unsafe { $crate::wide_string::WideCStr::from_slice_with_nul_unchecked(&WIDESTR_UNITS) }
+    }};
+}
+
+/// Borrowed Null-Terminated UTF-16 String
+///
+/// This is the wide Equivalent of `CStr`.
+/// The underlying Slice always ends with exactly one Null-Character.
+#[repr(transparent)]
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WideCStr {
+    inner: [u16]
+}
+
+impl WideCStr {
+    /// Creates a WideCStr from a Slice that ends with a Null-Character
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The Code Units including the Null-Terminator
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if the Slice is not Null-Terminated or contains an interior Null-Character
+    pub fn from_slice_with_nul(units: &[u16]) -> Result<&WideCStr, WideStringError> {
+        return match find_nul(units) {
+            None => Err(WideStringError::NotNulTerminated),
+            Some(position) if position + 1 == units.len() => {
+                Ok(unsafe { WideCStr::from_slice_with_nul_unchecked(units) })
+            }
+            Some(position) => Err(WideStringError::InteriorNul { position }),
+        };
+    }
+
+    /// Creates a WideCStr from a Slice that contains at least one Null-Character
+    ///
+    /// Everything after the first Null-Character is ignored, which makes this useful for Buffers filled by the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The Code Units, containing at least one Null-Character
+    pub fn from_slice_until_nul(units: &[u16]) -> Result<&WideCStr, WideStringError> {
+        return match find_nul(units) {
+            None => Err(WideStringError::NotNulTerminated),
+            Some(position) => Ok(unsafe { WideCStr::from_slice_with_nul_unchecked(&units[..=position]) }),
+        };
+    }
+
+    /// Creates a WideCStr from a Slice without checking it
+    ///
+    /// # Safety
+    ///
+    /// The Slice must end with a Null-Character and must not contain any other Null-Characters
+    #[inline]
+    pub const unsafe fn from_slice_with_nul_unchecked(units: &[u16]) -> &WideCStr {
+        return &*(units as *const [u16] as *const WideCStr);
+    }
+
+    /// Creates a WideCStr from a Pointer returned by the API
+    ///
+    /// # Safety
+    ///
+    /// The Pointer must be non-null and point to a valid Null-Terminated UTF-16 String,
+    /// which must stay valid and unmodified for the chosen Lifetime
+    pub unsafe fn from_ptr<'a>(pointer: *const u16) -> &'a WideCStr {
+        let mut length = 0;
+        while *pointer.add(length) != 0 {
+            length += 1;
+        }
+        let units = std::slice::from_raw_parts(pointer, length + 1);
+        return WideCStr::from_slice_with_nul_unchecked(units);
+    }
+
+    /// Returns a Pointer to the Null-Terminated String that can be passed to the API
+    #[inline]
+    pub fn as_ptr(&self) -> *const u16 {
+        return self.inner.as_ptr();
+    }
+
+    /// Returns the Code Units without the Null-Terminator
+    #[inline]
+    pub fn as_slice(&self) -> &[u16] {
+        return &self.inner[..self.inner.len() - 1];
+    }
+
+    /// Returns the Code Units including the Null-Terminator
+    #[inline]
+    pub fn as_slice_with_nul(&self) -> &[u16] {
+        return &self.inner;
+    }
+
+    /// Returns the Number of Code Units without the Null-Terminator
+    #[inline]
+    pub fn len(&self) -> usize {
+        return self.inner.len() - 1;
+    }
+
+    /// Returns whether the String is empty (only consists of the Null-Terminator)
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    /// Converts the String into a Rust String
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if the String contains unpaired Surrogates
+    pub fn to_string(&self) -> Result<String, WideStringError> {
+        return decode_utf16(self.as_slice(), false);
+    }
+
+    /// Converts the String into a Rust String, replacing unpaired Surrogates with U+FFFD
+    pub fn to_string_lossy(&self) -> String {
+        return decode_utf16(self.as_slice(), true).expect("Lossy Decoding cannot fail");
+    }
+
+    /// Converts the String into an OsString
+    ///
+    /// # Note
+    ///
+    /// On Windows this Conversion is lossless.
+    /// On other Platforms unpaired Surrogates are replaced with U+FFFD.
+    pub fn to_os_string(&self) -> OsString {
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStringExt;
+            return OsString::from_wide(self.as_slice());
+        }
+        #[cfg(not(windows))]
+        {
+            return OsString::from(self.to_string_lossy());
+        }
+    }
+
+    /// Copies the String into an owned WideCString
+    #[inline]
+    pub fn to_wide_c_string(&self) -> WideCString {
+        return WideCString { inner: Box::from(&self.inner) };
+    }
+}
+
+impl fmt::Debug for WideCStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return fmt::Debug::fmt(&self.to_string_lossy(), f);
+    }
+}
+
+impl AsRef<WideCStr> for WideCStr {
+    #[inline]
+    fn as_ref(&self) -> &WideCStr {
+        return self;
+    }
+}
+
+impl ToOwned for WideCStr {
+    type Owned = WideCString;
+
+    #[inline]
+    fn to_owned(&self) -> WideCString {
+        return self.to_wide_c_string();
+    }
+}
+
+impl Default for &WideCStr {
+    #[inline]
+    fn default() -> Self {
+        const EMPTY: &[u16] = &[0];
+        return unsafe { WideCStr::from_slice_with_nul_unchecked(EMPTY) };
+    }
+}
+
+/// Owned Null-Terminated UTF-16 String
+///
+/// This is the wide Equivalent of `CString`.
+/// The underlying Buffer always ends with exactly one Null-Character.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WideCString {
+    inner: Box<[u16]>
+}
+
+impl WideCString {
+    /// Creates a WideCString by encoding a Rust String as UTF-16
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The String that should be encoded
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if the String contains a Null-Character
+    pub fn new<S: AsRef<str>>(value: S) -> Result<WideCString, WideStringError> {
+        return WideCString::from_vec(encode_utf16(value.as_ref()));
+    }
+
+    /// Creates a WideCString from UTF-16 Code Units without a Null-Terminator
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The Code Units, a Null-Terminator will be appended
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if the Code Units contain a Null-Character
+    pub fn from_vec(mut units: Vec<u16>) -> Result<WideCString, WideStringError> {
+        if let Some(position) = find_nul(&units) {
+            return Err(WideStringError::InteriorNul { position });
+        }
+        units.push(0);
+        return Ok(WideCString { inner: units.into_boxed_slice() });
+    }
+
+    /// Creates a WideCString from UTF-16 Code Units that already end with a Null-Terminator
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The Code Units including the Null-Terminator
+    pub fn from_vec_with_nul(units: Vec<u16>) -> Result<WideCString, WideStringError> {
+        WideCStr::from_slice_with_nul(&units)?;
+        return Ok(WideCString { inner: units.into_boxed_slice() });
+    }
+
+    /// Creates a WideCString from an OsStr
+    ///
+    /// # Note
+    ///
+    /// On Windows this Conversion is lossless.
+    /// On other Platforms OsStrs are not UTF-16 based, so invalid Unicode is replaced with U+FFFD.
+    pub fn from_os_str<S: AsRef<OsStr>>(value: S) -> Result<WideCString, WideStringError> {
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStrExt;
+            return WideCString::from_vec(value.as_ref().encode_wide().collect());
+        }
+        #[cfg(not(windows))]
+        {
+            return WideCString::new(value.as_ref().to_string_lossy());
+        }
+    }
+
+    /// Returns the borrowed WideCStr
+    #[inline]
+    pub fn as_wide_c_str(&self) -> &WideCStr {
+        return unsafe { WideCStr::from_slice_with_nul_unchecked(&self.inner) };
+    }
+
+    /// Returns the Code Units without the Null-Terminator
+    pub fn into_vec(self) -> Vec<u16> {
+        let mut units = self.inner.into_vec();
+        units.pop();
+        return units;
+    }
+
+    /// Returns the Code Units including the Null-Terminator
+    #[inline]
+    pub fn into_vec_with_nul(self) -> Vec<u16> {
+        return self.inner.into_vec();
+    }
+}
+
+impl Deref for WideCString {
+    type Target = WideCStr;
+
+    #[inline]
+    fn deref(&self) -> &WideCStr {
+        return self.as_wide_c_str();
+    }
+}
+
+impl fmt::Debug for WideCString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return fmt::Debug::fmt(self.as_wide_c_str(), f);
+    }
+}
+
+impl Default for WideCString {
+    #[inline]
+    fn default() -> Self {
+        return WideCString { inner: Box::new([0]) };
+    }
+}
+
+impl Borrow<WideCStr> for WideCString {
+    #[inline]
+    fn borrow(&self) -> &WideCStr {
+        return self.as_wide_c_str();
+    }
+}
+
+impl AsRef<WideCStr> for WideCString {
+    #[inline]
+    fn as_ref(&self) -> &WideCStr {
+        return self.as_wide_c_str();
+    }
+}
+
+impl From<&WideCStr> for WideCString {
+    #[inline]
+    fn from(value: &WideCStr) -> Self {
+        return value.to_wide_c_string();
+    }
+}
+
+impl<'a> From<&'a WideCStr> for Cow<'a, WideCStr> {
+    #[inline]
+    fn from(value: &'a WideCStr) -> Self {
+        return Cow::Borrowed(value);
+    }
+}
+
+impl From<WideCString> for Cow<'_, WideCStr> {
+    #[inline]
+    fn from(value: WideCString) -> Self {
+        return Cow::Owned(value);
+    }
+}
+
+impl TryFrom<&str> for WideCString {
+    type Error = WideStringError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        return WideCString::new(value);
+    }
+}
+
+impl TryFrom<String> for WideCString {
+    type Error = WideStringError;
+
+    #[inline]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        return WideCString::new(value);
+    }
+}
+
+impl TryFrom<&OsStr> for WideCString {
+    type Error = WideStringError;
+
+    #[inline]
+    fn try_from(value: &OsStr) -> Result<Self, Self::Error> {
+        return WideCString::from_os_str(value);
+    }
+}
+
+/// Tests for the UTF-16 Encoder
+#[cfg(test)]
+mod test_encode_utf16 {
+    use crate::type_wrappers::type_conversion::wide_string::{encode_utf16, utf16_length};
+
+    /// Tests that the Encoder produces the same Code Units as the Standard Library
+    #[test]
+    fn test_matches_standard_library() {
+        let value = "Hello Wörld, Привет, 日本語, 🦀";
+        let expected: Vec<u16> = value.encode_utf16().collect();
+
+        assert_eq!(encode_utf16(value), expected);
+        assert_eq!(utf16_length(value), expected.len());
+    }
+
+    /// Tests that Characters outside of the BMP are encoded as Surrogate Pairs
+    #[test]
+    fn test_surrogate_pair() {
+        assert_eq!(encode_utf16("\u{1F980}"), vec![0xD83E, 0xDD80]);
+    }
+}
+
+/// Tests for the WideCString and WideCStr Types
+#[cfg(test)]
+mod test_wide_c_string {
+    use std::convert::TryFrom;
+    use std::ffi::OsStr;
+    use crate::type_wrappers::type_conversion::wide_string::{WideCString, WideCStr, WideStringError};
+
+    /// Tests that the Converted String is Null-Terminated
+    #[test]
+    fn test_result_is_null_terminated() {
+        let wide_string = WideCString::new("Hello World").unwrap();
+
+        assert!(wide_string.as_slice_with_nul().ends_with(&[0u16]));
+        assert_eq!(wide_string.len(), 11);
+    }
+
+    /// Tests that interior Null-Characters are rejected
+    #[test]
+    fn test_interior_nul_is_rejected() {
+        assert_eq!(WideCString::new("Hello\0World"), Err(WideStringError::InteriorNul { position: 5 }));
+        assert_eq!(WideCStr::from_slice_with_nul(&[72, 0, 72, 0]), Err(WideStringError::InteriorNul { position: 1 }));
+    }
+
+    /// Tests that Slices without a Null-Terminator are rejected
+    #[test]
+    fn test_missing_terminator_is_rejected() {
+        assert_eq!(WideCStr::from_slice_with_nul(&[72, 73]), Err(WideStringError::NotNulTerminated));
+        assert_eq!(WideCString::from_vec_with_nul(vec![72, 73]), Err(WideStringError::NotNulTerminated));
+    }
+
+    /// Tests that from_slice_until_nul ignores everything after the first Null-Character
+    #[test]
+    fn test_from_slice_until_nul() {
+        let wide_str = WideCStr::from_slice_until_nul(&[72, 73, 0, 74, 0]).unwrap();
+
+        assert_eq!(wide_str.as_slice(), &[72, 73]);
+    }
+
+    /// Tests that Strings survive the Round Trip through UTF-16
+    #[test]
+    fn test_round_trip() {
+        let value = "Windings 🦀 Fenster";
+        let wide_string = WideCString::try_from(value).unwrap();
+
+        assert_eq!(wide_string.to_string().unwrap(), value);
+        assert_eq!(wide_string.to_os_string(), OsStr::new(value));
+        assert_eq!(WideCString::from_os_str(OsStr::new(value)).unwrap(), wide_string);
+    }
+
+    /// Tests that unpaired Surrogates are reported or replaced
+    #[test]
+    fn test_unpaired_surrogate() {
+        let wide_string = WideCString::from_vec(vec![72, 0xD800, 73]).unwrap();
+
+        assert_eq!(wide_string.to_string(), Err(WideStringError::InvalidUtf16 { position: 1 }));
+        assert_eq!(wide_string.to_string_lossy(), "H\u{FFFD}I");
+    }
+
+    /// Tests that a WideCStr can be read from a raw Pointer
+    #[test]
+    fn test_from_ptr() {
+        let wide_string = WideCString::new("Pointer").unwrap();
+        let wide_str = unsafe { WideCStr::from_ptr(wide_string.as_ptr()) };
+
+        assert_eq!(wide_str, wide_string.as_wide_c_str());
+    }
+
+    /// Tests that the widestr! Macro produces the same String as the runtime Encoder
+    #[test]
+    fn test_widestr_macro() {
+        let literal: &'static WideCStr = widestr!("Hello 🦀");
+
+        assert_eq!(literal, WideCString::new("Hello 🦀").unwrap().as_wide_c_str());
+        assert!(widestr!("").is_empty());
+    }
+}
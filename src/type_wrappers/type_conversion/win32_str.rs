@@ -0,0 +1,235 @@
+//! # Win32 Strings
+//!
+//! Selection between the `A` and `W` Entry Points of Functions that take Strings.
+//!
+//! Most Win32 Functions that take Strings exist twice: an `A` Version for Strings in the active ANSI Code Page and a `W` Version for UTF-16 Strings.
+//! The sealed `Win32Str` Trait lets a single generic Function choose the right Entry Point from the Type of its String Argument:
+//!
+//! * `str` and `String` are converted to UTF-16 and use the `W` Entry Point, since that Conversion is lossless
+//! * `CStr` and `CString` are passed as they are to the `A` Entry Point, they have to be encoded in the ANSI Code Page already
+//! * `WideCStr` and `WideCString` are passed as they are to the `W` Entry Point
+
+use std::borrow::Cow;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use crate::type_wrappers::type_conversion::wide_string::{WideCStr, WideCString, WideStringError};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for str {}
+    impl Sealed for String {}
+    impl Sealed for std::ffi::CStr {}
+    impl Sealed for std::ffi::CString {}
+    impl Sealed for crate::type_wrappers::type_conversion::wide_string::WideCStr {}
+    impl Sealed for crate::type_wrappers::type_conversion::wide_string::WideCString {}
+    impl<T: Sealed + ?Sized> Sealed for &T {}
+}
+
+/// The Character Set of a Win32 Entry Point
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CharacterSet {
+    /// The `A` Entry Point, taking Strings in the active ANSI Code Page
+    Ansi,
+
+    /// The `W` Entry Point, taking UTF-16 Strings
+    Wide,
+}
+
+/// A String converted for one of the two Entry Points
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Win32String<'a> {
+    /// A Null-Terminated String for the `A` Entry Point
+    Ansi(Cow<'a, CStr>),
+
+    /// A Null-Terminated UTF-16 String for the `W` Entry Point
+    Wide(Cow<'a, WideCStr>),
+}
+
+impl Win32String<'_> {
+    /// Returns the Character Set of the converted String
+    #[inline]
+    pub fn character_set(&self) -> CharacterSet {
+        return match self {
+            Win32String::Ansi(_) => CharacterSet::Ansi,
+            Win32String::Wide(_) => CharacterSet::Wide,
+        };
+    }
+
+    /// Returns a Pointer for the `A` Entry Point, or None if this is a wide String
+    #[inline]
+    pub fn as_ansi_ptr(&self) -> Option<*const c_char> {
+        return match self {
+            Win32String::Ansi(string) => Some(string.as_ptr()),
+            Win32String::Wide(_) => None,
+        };
+    }
+
+    /// Returns a Pointer for the `W` Entry Point, or None if this is an ANSI String
+    #[inline]
+    pub fn as_wide_ptr(&self) -> Option<*const u16> {
+        return match self {
+            Win32String::Ansi(_) => None,
+            Win32String::Wide(string) => Some(string.as_ptr()),
+        };
+    }
+}
+
+/// String Types that can be passed to Functions with an `A` and a `W` Entry Point
+///
+/// This Trait is sealed and cannot be implemented outside of this Crate.
+pub trait Win32Str: private::Sealed {
+    /// The Character Set whose Entry Point is used for this String Type
+    const CHARACTER_SET: CharacterSet;
+
+    /// Converts the String for the Entry Point given by `CHARACTER_SET`
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if the String contains a Null-Character
+    fn to_win32_string(&self) -> Result<Win32String<'_>, WideStringError>;
+}
+
+impl Win32Str for str {
+    const CHARACTER_SET: CharacterSet = CharacterSet::Wide;
+
+    #[inline]
+    fn to_win32_string(&self) -> Result<Win32String<'_>, WideStringError> {
+        return Ok(Win32String::Wide(Cow::Owned(WideCString::new(self)?)));
+    }
+}
+
+impl Win32Str for String {
+    const CHARACTER_SET: CharacterSet = CharacterSet::Wide;
+
+    #[inline]
+    fn to_win32_string(&self) -> Result<Win32String<'_>, WideStringError> {
+        return self.as_str().to_win32_string();
+    }
+}
+
+impl Win32Str for CStr {
+    const CHARACTER_SET: CharacterSet = CharacterSet::Ansi;
+
+    #[inline]
+    fn to_win32_string(&self) -> Result<Win32String<'_>, WideStringError> {
+        return Ok(Win32String::Ansi(Cow::Borrowed(self)));
+    }
+}
+
+impl Win32Str for CString {
+    const CHARACTER_SET: CharacterSet = CharacterSet::Ansi;
+
+    #[inline]
+    fn to_win32_string(&self) -> Result<Win32String<'_>, WideStringError> {
+        return self.as_c_str().to_win32_string();
+    }
+}
+
+impl Win32Str for WideCStr {
+    const CHARACTER_SET: CharacterSet = CharacterSet::Wide;
+
+    #[inline]
+    fn to_win32_string(&self) -> Result<Win32String<'_>, WideStringError> {
+        return Ok(Win32String::Wide(Cow::Borrowed(self)));
+    }
+}
+
+impl Win32Str for WideCString {
+    const CHARACTER_SET: CharacterSet = CharacterSet::Wide;
+
+    #[inline]
+    fn to_win32_string(&self) -> Result<Win32String<'_>, WideStringError> {
+        return self.as_wide_c_str().to_win32_string();
+    }
+}
+
+impl<T: Win32Str + ?Sized> Win32Str for &T {
+    const CHARACTER_SET: CharacterSet = T::CHARACTER_SET;
+
+    #[inline]
+    fn to_win32_string(&self) -> Result<Win32String<'_>, WideStringError> {
+        return (**self).to_win32_string();
+    }
+}
+
+/// Calls the Entry Point matching the Type of a String
+///
+/// # Arguments
+///
+/// * `value` - The String that should be passed
+/// * `ansi` - Calls the `A` Entry Point with a Pointer to the Null-Terminated ANSI String
+/// * `wide` - Calls the `W` Entry Point with a Pointer to the Null-Terminated UTF-16 String
+///
+/// # Return
+///
+/// This Function returns the Result of the called Entry Point, or an Error if the String could not be converted.
+/// The Pointer is only valid for the Duration of the Call.
+pub fn call_with_win32_str<S, R, A, W>(value: &S, ansi: A, wide: W) -> Result<R, WideStringError>
+    where S: Win32Str + ?Sized, A: FnOnce(*const c_char) -> R, W: FnOnce(*const u16) -> R {
+    return match value.to_win32_string()? {
+        Win32String::Ansi(string) => Ok(ansi(string.as_ptr())),
+        Win32String::Wide(string) => Ok(wide(string.as_ptr())),
+    };
+}
+
+/// Tests for the Selection of the Entry Point
+#[cfg(test)]
+mod test_win32_str {
+    use std::ffi::{CStr, CString};
+    use crate::type_wrappers::type_conversion::win32_str::{call_with_win32_str, CharacterSet, Win32Str, Win32String};
+    use crate::type_wrappers::type_conversion::wide_string::{WideCString, WideStringError};
+
+    /// Returns which Entry Point a String would use
+    fn selected_entry_point<S: Win32Str + ?Sized>(value: &S) -> CharacterSet {
+        return call_with_win32_str(value, |_| CharacterSet::Ansi, |_| CharacterSet::Wide).unwrap();
+    }
+
+    /// Tests that Rust Strings use the W Entry Point
+    #[test]
+    fn test_rust_strings_use_wide() {
+        assert_eq!(selected_entry_point("message"), CharacterSet::Wide);
+        assert_eq!(selected_entry_point(&String::from("message")), CharacterSet::Wide);
+        assert_eq!(<&str as Win32Str>::CHARACTER_SET, CharacterSet::Wide);
+    }
+
+    /// Tests that C Strings use the A Entry Point
+    #[test]
+    fn test_c_strings_use_ansi() {
+        let c_string = CString::new("message").unwrap();
+
+        assert_eq!(selected_entry_point(c_string.as_c_str()), CharacterSet::Ansi);
+        assert_eq!(selected_entry_point(&c_string), CharacterSet::Ansi);
+        assert_eq!(<CStr as Win32Str>::CHARACTER_SET, CharacterSet::Ansi);
+    }
+
+    /// Tests that wide Strings use the W Entry Point without Conversion
+    #[test]
+    fn test_wide_strings_use_wide() {
+        let wide_string = WideCString::new("message").unwrap();
+
+        assert_eq!(selected_entry_point(wide_string.as_wide_c_str()), CharacterSet::Wide);
+        assert_eq!(wide_string.to_win32_string().unwrap(), Win32String::Wide(wide_string.as_wide_c_str().into()));
+    }
+
+    /// Tests that the Entry Points receive correctly converted Null-Terminated Strings
+    #[test]
+    fn test_pointer_contents() {
+        let units = call_with_win32_str("Fenster", |_| Vec::new(), |pointer| unsafe {
+            crate::type_wrappers::type_conversion::wide_string::WideCStr::from_ptr(pointer).as_slice().to_vec()
+        }).unwrap();
+        let c_string = CString::new("Fenster").unwrap();
+        let bytes = call_with_win32_str(c_string.as_c_str(), |pointer| unsafe { CStr::from_ptr(pointer).to_bytes().to_vec() }, |_| Vec::new()).unwrap();
+
+        assert_eq!(units, "Fenster".encode_utf16().collect::<Vec<u16>>());
+        assert_eq!(bytes, b"Fenster");
+    }
+
+    /// Tests that Strings that cannot be converted do not call any Entry Point
+    #[test]
+    fn test_conversion_error() {
+        let result = call_with_win32_str("a\0b", |_| panic!("A called"), |_| panic!("W called"));
+
+        assert_eq!(result, Err::<(), _>(WideStringError::InteriorNul { position: 1 }));
+    }
+}
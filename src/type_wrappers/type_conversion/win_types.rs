@@ -0,0 +1,40 @@
+//! # Windows Data Types
+//!
+//! The basic Windows Data Types used by the Types of this Crate.
+//!
+//! WINAPI only provides its Definitions when building for Windows.
+//! These Aliases have the same fixed Widths as the Definitions of WINAPI, so they are identical to them on Windows,
+//! while Enums, Bitflags and Conversions built on top of them stay available on every Host.
+//!
+//! See the Documentation [here](https://docs.microsoft.com/en-us/windows/win32/winprog/windows-data-types)
+
+#![allow(non_camel_case_types, clippy::upper_case_acronyms)]
+
+use std::ffi::c_void;
+
+pub type c_int = i32;
+pub type BOOL = c_int;
+pub type UINT = u32;
+pub type DWORD = u32;
+pub type ULONG = u32;
+pub type LONG = i32;
+pub type HRESULT = LONG;
+pub type PVOID = *mut c_void;
+
+/// Tests that the Aliases are identical to the Definitions of WINAPI
+#[cfg(all(test, windows))]
+mod test_win_types {
+    use std::ptr::null_mut;
+    use crate::type_wrappers::type_conversion::win_types::{BOOL, DWORD, HRESULT, PVOID, UINT};
+
+    /// Tests that Values of the Aliases can be used where WINAPI Types are expected, which only compiles for identical Types
+    #[test]
+    fn test_identical_to_winapi() {
+        let pointer: PVOID = null_mut();
+        let _: winapi::um::winnt::PVOID = pointer;
+        let _: winapi::shared::minwindef::BOOL = 0 as BOOL;
+        let _: winapi::shared::minwindef::UINT = 0 as UINT;
+        let _: winapi::shared::minwindef::DWORD = 0 as DWORD;
+        let _: winapi::shared::winerror::HRESULT = 0 as HRESULT;
+    }
+}
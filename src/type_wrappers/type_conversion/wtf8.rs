@@ -0,0 +1,457 @@
+//! # WTF-8
+//!
+//! Lossless Representation of potentially ill-formed UTF-16 Strings returned by the Win32 API.
+//!
+//! Strings returned by the API (Window Titles, Event Log Strings, File Paths) are sequences of arbitrary 16-bit Code Units and may contain unpaired Surrogates.
+//! [WTF-8](https://simonsapin.github.io/wtf-8/) is a superset of UTF-8 that can encode those Surrogates, so the Conversion from and to UTF-16 never loses Data.
+//! Valid Strings are stored as plain UTF-8, which means they can be borrowed as `&str` without any Conversion.
+//!
+//! Unlike the OsString of the Standard Library, this Type behaves the same way on every Platform.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use crate::type_wrappers::type_conversion::wide_string::{encode_utf16_character, WideCStr, WideCString, WideStringError};
+
+/// Error returned when a WTF-8 String cannot be converted into valid UTF-8
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Wtf8Error {
+    position: usize,
+    surrogate: u16,
+}
+
+impl Wtf8Error {
+    /// Returns the Byte Position of the first unpaired Surrogate
+    #[inline]
+    pub fn position(&self) -> usize {
+        return self.position;
+    }
+
+    /// Returns the Value of the first unpaired Surrogate
+    #[inline]
+    pub fn surrogate(&self) -> u16 {
+        return self.surrogate;
+    }
+}
+
+impl fmt::Display for Wtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "unpaired surrogate U+{:04X} at byte position {}", self.surrogate, self.position);
+    }
+}
+
+impl Error for Wtf8Error {}
+
+/// Checks whether a Code Point is a high (leading) Surrogate
+#[inline]
+fn is_high_surrogate(value: u32) -> bool {
+    return (0xD800..=0xDBFF).contains(&value);
+}
+
+/// Checks whether a Code Point is a low (trailing) Surrogate
+#[inline]
+fn is_low_surrogate(value: u32) -> bool {
+    return (0xDC00..=0xDFFF).contains(&value);
+}
+
+/// Decodes a single Code Point from WTF-8 Bytes
+///
+/// # Arguments
+///
+/// * `bytes` - Well-formed WTF-8 Bytes
+/// * `index` - The Index of the first Byte of the Code Point
+///
+/// # Return
+///
+/// This Function returns the Code Point and the Number of Bytes it occupied
+#[inline]
+fn decode_code_point(bytes: &[u8], index: usize) -> (u32, usize) {
+    let first = bytes[index] as u32;
+    return match first {
+        0x00..=0x7F => (first, 1),
+        0x80..=0xDF => (((first & 0x1F) << 6) | (bytes[index + 1] as u32 & 0x3F), 2),
+        0xE0..=0xEF => (((first & 0x0F) << 12) | ((bytes[index + 1] as u32 & 0x3F) << 6) | (bytes[index + 2] as u32 & 0x3F), 3),
+        _ => (((first & 0x07) << 18) | ((bytes[index + 1] as u32 & 0x3F) << 12) | ((bytes[index + 2] as u32 & 0x3F) << 6) | (bytes[index + 3] as u32 & 0x3F), 4),
+    };
+}
+
+/// Owned WTF-8 String
+///
+/// Created from UTF-16 Code Units with `from_wide` and converted back with `to_wide` without any loss.
+/// Use `try_to_string` or `to_string_lossy` to explicitly leave the WTF-8 World.
+#[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Wtf8String {
+    bytes: Vec<u8>
+}
+
+impl Wtf8String {
+    /// Creates an empty WTF-8 String
+    #[inline]
+    pub fn new() -> Wtf8String {
+        return Wtf8String { bytes: Vec::new() };
+    }
+
+    /// Creates a WTF-8 String from UTF-16 Code Units
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - The UTF-16 Code Units, which may contain unpaired Surrogates
+    pub fn from_wide(units: &[u16]) -> Wtf8String {
+        let mut result = Wtf8String { bytes: Vec::with_capacity(units.len()) };
+        let mut index = 0;
+        while index < units.len() {
+            let unit = units[index] as u32;
+            if is_high_surrogate(unit) && index + 1 < units.len() && is_low_surrogate(units[index + 1] as u32) {
+                let low = units[index + 1] as u32;
+                result.push_code_point_unchecked(0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00));
+                index += 2;
+            } else {
+                result.push_code_point_unchecked(unit);
+                index += 1;
+            }
+        }
+        return result;
+    }
+
+    /// Creates a WTF-8 String from an OsStr
+    ///
+    /// # Note
+    ///
+    /// On Windows this Conversion is lossless.
+    /// On other Platforms invalid Unicode is replaced with U+FFFD.
+    pub fn from_os_str<S: AsRef<OsStr>>(value: S) -> Wtf8String {
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStrExt;
+            let units: Vec<u16> = value.as_ref().encode_wide().collect();
+            return Wtf8String::from_wide(&units);
+        }
+        #[cfg(not(windows))]
+        {
+            return Wtf8String::from(value.as_ref().to_string_lossy().into_owned());
+        }
+    }
+
+    /// Appends a Code Point without joining Surrogates
+    fn push_code_point_unchecked(&mut self, value: u32) {
+        match value {
+            0x00..=0x7F => self.bytes.push(value as u8),
+            0x80..=0x7FF => {
+                self.bytes.push(0xC0 | (value >> 6) as u8);
+                self.bytes.push(0x80 | (value & 0x3F) as u8);
+            }
+            0x800..=0xFFFF => {
+                self.bytes.push(0xE0 | (value >> 12) as u8);
+                self.bytes.push(0x80 | ((value >> 6) & 0x3F) as u8);
+                self.bytes.push(0x80 | (value & 0x3F) as u8);
+            }
+            _ => {
+                self.bytes.push(0xF0 | (value >> 18) as u8);
+                self.bytes.push(0x80 | ((value >> 12) & 0x3F) as u8);
+                self.bytes.push(0x80 | ((value >> 6) & 0x3F) as u8);
+                self.bytes.push(0x80 | (value & 0x3F) as u8);
+            }
+        }
+    }
+
+    /// Returns the trailing high Surrogate, if the String ends with one
+    fn final_high_surrogate(&self) -> Option<u32> {
+        let length = self.bytes.len();
+        if length < 3 || self.bytes[length - 3] != 0xED {
+            return None;
+        }
+        let (value, _) = decode_code_point(&self.bytes, length - 3);
+        return match is_high_surrogate(value) {
+            true => Some(value),
+            false => None,
+        };
+    }
+
+    /// Appends a Code Point
+    ///
+    /// If a low Surrogate is appended directly after a high Surrogate, both are joined into a single supplementary Character,
+    /// so that concatenating WTF-8 Strings behaves like concatenating their UTF-16 Code Units.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A Unicode Code Point (`0..=0x10FFFF`), Surrogates are allowed
+    pub fn push_code_point(&mut self, value: u32) {
+        assert!(value <= 0x10FFFF, "Code Point out of range");
+        if is_low_surrogate(value) {
+            if let Some(high) = self.final_high_surrogate() {
+                self.bytes.truncate(self.bytes.len() - 3);
+                self.push_code_point_unchecked(0x10000 + ((high - 0xD800) << 10) + (value - 0xDC00));
+                return;
+            }
+        }
+        self.push_code_point_unchecked(value);
+    }
+
+    /// Appends a Rust String
+    #[inline]
+    pub fn push_str(&mut self, value: &str) {
+        self.bytes.extend_from_slice(value.as_bytes());
+    }
+
+    /// Appends another WTF-8 String, joining Surrogates at the Boundary
+    pub fn push_wtf8(&mut self, other: &Wtf8String) {
+        match other.code_points().next() {
+            Some(first) if is_low_surrogate(first) && self.final_high_surrogate().is_some() => {
+                self.push_code_point(first);
+                self.bytes.extend_from_slice(&other.bytes[3..]);
+            }
+            _ => self.bytes.extend_from_slice(&other.bytes),
+        }
+    }
+
+    /// Returns an Iterator over the Code Points of the String, including unpaired Surrogates
+    pub fn code_points(&self) -> CodePoints<'_> {
+        return CodePoints { bytes: &self.bytes, index: 0 };
+    }
+
+    /// Returns the WTF-8 Bytes of the String
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        return &self.bytes;
+    }
+
+    /// Returns the Length of the String in Bytes
+    #[inline]
+    pub fn len(&self) -> usize {
+        return self.bytes.len();
+    }
+
+    /// Returns whether the String is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        return self.bytes.is_empty();
+    }
+
+    /// Finds the first unpaired Surrogate
+    fn first_surrogate(&self) -> Option<Wtf8Error> {
+        let mut index = 0;
+        while index < self.bytes.len() {
+            let (value, byte_count) = decode_code_point(&self.bytes, index);
+            if (0xD800..=0xDFFF).contains(&value) {
+                return Some(Wtf8Error { position: index, surrogate: value as u16 });
+            }
+            index += byte_count;
+        }
+        return None;
+    }
+
+    /// Borrows the String as a `&str`
+    ///
+    /// # Return
+    ///
+    /// This Function returns None if the String contains unpaired Surrogates
+    pub fn as_str(&self) -> Option<&str> {
+        return match self.first_surrogate() {
+            // Without Surrogates WTF-8 is valid UTF-8
+            None => Some(unsafe { std::str::from_utf8_unchecked(&self.bytes) }),
+            Some(_) => None,
+        };
+    }
+
+    /// Converts the String into a Rust String
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error describing the first unpaired Surrogate if the String is not valid Unicode
+    pub fn try_to_string(&self) -> Result<String, Wtf8Error> {
+        return match self.first_surrogate() {
+            None => Ok(String::from(unsafe { std::str::from_utf8_unchecked(&self.bytes) })),
+            Some(error) => Err(error),
+        };
+    }
+
+    /// Converts the String into a Rust String without copying if it is valid Unicode
+    ///
+    /// # Return
+    ///
+    /// This Function returns the unchanged WTF-8 String if it contains unpaired Surrogates
+    pub fn into_string(self) -> Result<String, Wtf8String> {
+        return match self.first_surrogate() {
+            None => Ok(unsafe { String::from_utf8_unchecked(self.bytes) }),
+            Some(_) => Err(self),
+        };
+    }
+
+    /// Converts the String into a Rust String, replacing unpaired Surrogates with U+FFFD
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        if let Some(valid) = self.as_str() {
+            return Cow::Borrowed(valid);
+        }
+        let mut result = String::with_capacity(self.bytes.len());
+        for value in self.code_points() {
+            result.push(std::char::from_u32(value).unwrap_or(std::char::REPLACEMENT_CHARACTER));
+        }
+        return Cow::Owned(result);
+    }
+
+    /// Converts the String back into UTF-16 Code Units (without a Null-Terminator)
+    pub fn to_wide(&self) -> Vec<u16> {
+        let mut units = Vec::with_capacity(self.bytes.len() + 1);
+        for value in self.code_points() {
+            let (encoded, unit_count) = encode_utf16_character(value);
+            units.extend_from_slice(&encoded[..unit_count]);
+        }
+        return units;
+    }
+
+    /// Converts the String into a Null-Terminated Wide String for passing it back to the API
+    ///
+    /// # Return
+    ///
+    /// This Function returns an Error if the String contains a Null-Character
+    #[inline]
+    pub fn to_wide_c_string(&self) -> Result<WideCString, WideStringError> {
+        return WideCString::from_vec(self.to_wide());
+    }
+
+    /// Converts the String into an OsString
+    ///
+    /// # Note
+    ///
+    /// On Windows this Conversion is lossless.
+    /// On other Platforms unpaired Surrogates are replaced with U+FFFD.
+    pub fn to_os_string(&self) -> OsString {
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStringExt;
+            return OsString::from_wide(&self.to_wide());
+        }
+        #[cfg(not(windows))]
+        {
+            return OsString::from(self.to_string_lossy().into_owned());
+        }
+    }
+}
+
+impl fmt::Display for Wtf8String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return fmt::Display::fmt(&self.to_string_lossy(), f);
+    }
+}
+
+impl fmt::Debug for Wtf8String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for value in self.code_points() {
+            match std::char::from_u32(value) {
+                Some(character) => write!(f, "{}", character.escape_debug())?,
+                None => write!(f, "\\u{{{:X}}}", value)?,
+            }
+        }
+        return f.write_str("\"");
+    }
+}
+
+impl From<&str> for Wtf8String {
+    /// This Conversion only copies, since valid UTF-8 is also valid WTF-8
+    #[inline]
+    fn from(value: &str) -> Self {
+        return Wtf8String { bytes: Vec::from(value.as_bytes()) };
+    }
+}
+
+impl From<String> for Wtf8String {
+    #[inline]
+    fn from(value: String) -> Self {
+        return Wtf8String { bytes: value.into_bytes() };
+    }
+}
+
+impl From<&WideCStr> for Wtf8String {
+    #[inline]
+    fn from(value: &WideCStr) -> Self {
+        return Wtf8String::from_wide(value.as_slice());
+    }
+}
+
+impl WideCStr {
+    /// Converts the String into WTF-8 without losing unpaired Surrogates
+    #[inline]
+    pub fn to_wtf8(&self) -> Wtf8String {
+        return Wtf8String::from_wide(self.as_slice());
+    }
+}
+
+/// Iterator over the Code Points of a WTF-8 String
+pub struct CodePoints<'a> {
+    bytes: &'a [u8],
+    index: usize,
+}
+
+impl Iterator for CodePoints<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.index >= self.bytes.len() {
+            return None;
+        }
+        let (value, byte_count) = decode_code_point(self.bytes, self.index);
+        self.index += byte_count;
+        return Some(value);
+    }
+}
+
+/// Tests for the Conversion between UTF-16 and WTF-8
+#[cfg(test)]
+mod test_wtf8_string {
+    use crate::type_wrappers::type_conversion::wtf8::Wtf8String;
+    use crate::type_wrappers::type_conversion::wide_string::WideCString;
+
+    /// Tests that valid UTF-16 is stored as plain UTF-8
+    #[test]
+    fn test_valid_utf16_is_utf8() {
+        let units: Vec<u16> = "Fenster 🦀".encode_utf16().collect();
+        let wtf8 = Wtf8String::from_wide(&units);
+
+        assert_eq!(wtf8.as_str(), Some("Fenster 🦀"));
+        assert_eq!(wtf8.as_bytes(), "Fenster 🦀".as_bytes());
+        assert_eq!(wtf8.to_wide(), units);
+    }
+
+    /// Tests that unpaired Surrogates survive the Round Trip
+    #[test]
+    fn test_unpaired_surrogates_round_trip() {
+        let inputs: [&[u16]; 4] = [&[0xD800], &[0xDC00], &[0x41, 0xDC00, 0xD800, 0x42], &[0xD83E, 0xDD80, 0xD83E]];
+        for units in inputs.iter() {
+            assert_eq!(Wtf8String::from_wide(units).to_wide(), units.to_vec());
+        }
+    }
+
+    /// Tests that the explicit Exits report or replace unpaired Surrogates
+    #[test]
+    fn test_string_exits() {
+        let wtf8 = Wtf8String::from_wide(&[0x41, 0xD800, 0x42]);
+
+        assert_eq!(wtf8.to_string_lossy(), "A\u{FFFD}B");
+        let error = wtf8.try_to_string().unwrap_err();
+        assert_eq!(error.position(), 1);
+        assert_eq!(error.surrogate(), 0xD800);
+        assert!(wtf8.clone().into_string().is_err());
+        assert_eq!(Wtf8String::from("valid").try_to_string().unwrap(), "valid");
+    }
+
+    /// Tests that concatenating a high and a low Surrogate joins them like UTF-16 would
+    #[test]
+    fn test_concatenation_joins_surrogates() {
+        let mut wtf8 = Wtf8String::from_wide(&[0x41, 0xD83E]);
+        wtf8.push_wtf8(&Wtf8String::from_wide(&[0xDD80, 0x42]));
+
+        assert_eq!(wtf8.as_str(), Some("A🦀B"));
+    }
+
+    /// Tests the Conversion from and to Null-Terminated Wide Strings
+    #[test]
+    fn test_wide_c_string_conversion() {
+        let wide_string = WideCString::from_vec(vec![0x57, 0xDFFF]).unwrap();
+        let wtf8 = wide_string.to_wtf8();
+
+        assert_eq!(format!("{:?}", wtf8), "\"W\\u{DFFF}\"");
+        assert_eq!(wtf8.to_wide_c_string().unwrap(), wide_string);
+    }
+}
@@ -1,124 +1,381 @@
-#![allow(non_snake_case,non_camel_case_types, dead_code)]
+#![allow(non_snake_case, non_camel_case_types, dead_code, clippy::upper_case_acronyms)]
+#[cfg(windows)]
+use std::mem;
+#[cfg(windows)]
 use std::mem::MaybeUninit;
+#[cfg(windows)]
+use std::ffi::CStr;
+#[cfg(windows)]
+use std::ptr::null_mut;
+#[cfg(windows)]
 use winapi::um::winuser::{LPMSG, MSG};
-use winapi::shared::minwindef::{BOOL, LRESULT, DWORD, LPVOID, HINSTANCE};
+#[cfg(windows)]
+use winapi::shared::minwindef::{LRESULT, LPVOID, HINSTANCE, WPARAM, LPARAM};
+#[cfg(windows)]
 use winapi::shared::windef::{HWND, HMENU};
-use bitflags::bitflags;
-use winapi::ctypes::c_int;
-use std::ffi::CString;
-use std::ptr::null_mut;
+#[cfg(windows)]
+use windings_macros::LayoutMirror;
+#[cfg(windows)]
+use crate::error::{Error, ErrorKind};
+#[cfg(windows)]
 use crate::type_wrappers::type_conversion::convert_c_bool;
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::success::{check, NonNullPolicy, SentinelPolicy};
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::win32_str::{CharacterSet, Win32Str};
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::win_types::BOOL;
+use crate::type_wrappers::type_conversion::win_types::{c_int, DWORD, UINT};
+use crate::type_wrappers::type_conversion::code_page::{CodePage, CodePageError};
+
+// Values of the winuser.h Header, defined here so that the Enums and Bitflags are available on every Host
+const SW_HIDE: c_int = 0;
+const SW_SHOWNORMAL: c_int = 1;
+const SW_SHOWMINIMIZED: c_int = 2;
+const SW_SHOWMAXIMIZED: c_int = 3;
+const SW_MAXIMIZE: c_int = 3;
+const SW_SHOWNOACTIVATE: c_int = 4;
+const SW_SHOW: c_int = 5;
+const SW_MINIMIZE: c_int = 6;
+const SW_SHOWMINNOACTIVE: c_int = 7;
+const SW_SHOWNA: c_int = 8;
+const SW_RESTORE: c_int = 9;
+const SW_SHOWDEFAULT: c_int = 10;
+const SW_FORCEMINIMIZE: c_int = 11;
+
+const WM_CHAR: UINT = 0x0102;
+const WM_DEADCHAR: UINT = 0x0103;
+const WM_SYSCHAR: UINT = 0x0106;
+const WM_SYSDEADCHAR: UINT = 0x0107;
+const WM_IME_CHAR: UINT = 0x0286;
+
+const WS_EX_DLGMODALFRAME: DWORD = 0x00000001;
+const WS_EX_NOPARENTNOTIFY: DWORD = 0x00000004;
+const WS_EX_TOPMOST: DWORD = 0x00000008;
+const WS_EX_ACCEPTFILES: DWORD = 0x00000010;
+const WS_EX_TRANSPARENT: DWORD = 0x00000020;
+const WS_EX_MDICHILD: DWORD = 0x00000040;
+const WS_EX_TOOLWINDOW: DWORD = 0x00000080;
+const WS_EX_WINDOWEDGE: DWORD = 0x00000100;
+const WS_EX_CLIENTEDGE: DWORD = 0x00000200;
+const WS_EX_CONTEXTHELP: DWORD = 0x00000400;
+const WS_EX_RIGHT: DWORD = 0x00001000;
+const WS_EX_LEFT: DWORD = 0x00000000;
+const WS_EX_RTLREADING: DWORD = 0x00002000;
+const WS_EX_LTRREADING: DWORD = 0x00000000;
+const WS_EX_LEFTSCROLLBAR: DWORD = 0x00004000;
+const WS_EX_RIGHTSCROLLBAR: DWORD = 0x00000000;
+const WS_EX_CONTROLPARENT: DWORD = 0x00010000;
+const WS_EX_STATICEDGE: DWORD = 0x00020000;
+const WS_EX_APPWINDOW: DWORD = 0x00040000;
+const WS_EX_OVERLAPPEDWINDOW: DWORD = WS_EX_WINDOWEDGE | WS_EX_CLIENTEDGE;
+const WS_EX_PALETTEWINDOW: DWORD = WS_EX_WINDOWEDGE | WS_EX_TOOLWINDOW | WS_EX_TOPMOST;
+const WS_EX_LAYERED: DWORD = 0x00080000;
+const WS_EX_NOINHERITLAYOUT: DWORD = 0x00100000;
+const WS_EX_NOREDIRECTIONBITMAP: DWORD = 0x00200000;
+const WS_EX_LAYOUTRTL: DWORD = 0x00400000;
+const WS_EX_COMPOSITED: DWORD = 0x02000000;
+const WS_EX_NOACTIVATE: DWORD = 0x08000000;
+
+const WS_OVERLAPPED: DWORD = 0x00000000;
+const WS_POPUP: DWORD = 0x80000000;
+const WS_CHILD: DWORD = 0x40000000;
+const WS_MINIMIZE: DWORD = 0x20000000;
+const WS_VISIBLE: DWORD = 0x10000000;
+const WS_DISABLED: DWORD = 0x08000000;
+const WS_CLIPSIBLINGS: DWORD = 0x04000000;
+const WS_CLIPCHILDREN: DWORD = 0x02000000;
+const WS_MAXIMIZE: DWORD = 0x01000000;
+const WS_CAPTION: DWORD = 0x00C00000;
+const WS_BORDER: DWORD = 0x00800000;
+const WS_DLGFRAME: DWORD = 0x00400000;
+const WS_VSCROLL: DWORD = 0x00200000;
+const WS_HSCROLL: DWORD = 0x00100000;
+const WS_SYSMENU: DWORD = 0x00080000;
+const WS_THICKFRAME: DWORD = 0x00040000;
+const WS_GROUP: DWORD = 0x00020000;
+const WS_TABSTOP: DWORD = 0x00010000;
+const WS_MINIMIZEBOX: DWORD = 0x00020000;
+const WS_MAXIMIZEBOX: DWORD = 0x00010000;
+const WS_TILED: DWORD = WS_OVERLAPPED;
+const WS_ICONIC: DWORD = WS_MINIMIZE;
+const WS_SIZEBOX: DWORD = WS_THICKFRAME;
+const WS_OVERLAPPEDWINDOW: DWORD = WS_OVERLAPPED | WS_CAPTION | WS_SYSMENU | WS_THICKFRAME | WS_MINIMIZEBOX | WS_MAXIMIZEBOX;
+const WS_TILEDWINDOW: DWORD = WS_OVERLAPPEDWINDOW;
+const WS_POPUPWINDOW: DWORD = WS_POPUP | WS_BORDER | WS_SYSMENU;
+const WS_CHILDWINDOW: DWORD = WS_CHILD;
 
-CONST_TO_ENUM!(const_enum N_CMD_SHOW,  winapi::ctypes::c_int {
-    FORCEMINIMIZE = winapi::um::winuser::SW_FORCEMINIMIZE,
-    HIDE = winapi::um::winuser::SW_HIDE,
-    MAXIMIZE = winapi::um::winuser::SW_MAXIMIZE,
-    MINIMIZE = winapi::um::winuser::SW_MINIMIZE,
-    RESTORE = winapi::um::winuser::SW_RESTORE,
-    SHOW = winapi::um::winuser::SW_SHOW,
-    SHOWDEFAULT = winapi::um::winuser::SW_SHOWDEFAULT,
-    SHOWMAXIMIZED = winapi::um::winuser::SW_SHOWMAXIMIZED,
-    SHOWMINIMIZED = winapi::um::winuser::SW_SHOWMINIMIZED,
-    SHOWMINNOACTIVE = winapi::um::winuser::SW_SHOWMINNOACTIVE,
-    SHOWNA = winapi::um::winuser::SW_SHOWNA,
-    SHOWNOACTIVATE = winapi::um::winuser::SW_SHOWNOACTIVATE,
-    SHOWNORMAL = winapi::um::winuser::SW_SHOWNORMAL,
+CONST_TO_ENUM!(const_enum N_CMD_SHOW, c_int {
+    FORCEMINIMIZE = SW_FORCEMINIMIZE,
+    HIDE = SW_HIDE,
+    MAXIMIZE = SW_MAXIMIZE,
+    MINIMIZE = SW_MINIMIZE,
+    RESTORE = SW_RESTORE,
+    SHOW = SW_SHOW,
+    SHOWDEFAULT = SW_SHOWDEFAULT,
+    SHOWMAXIMIZED = SW_SHOWMAXIMIZED,
+    SHOWMINIMIZED = SW_SHOWMINIMIZED,
+    SHOWMINNOACTIVE = SW_SHOWMINNOACTIVE,
+    SHOWNA = SW_SHOWNA,
+    SHOWNOACTIVATE = SW_SHOWNOACTIVATE,
+    SHOWNORMAL = SW_SHOWNORMAL,
 });
 
-bitflags! {
+NAMED_BITFLAGS! {
     pub struct WindowExtendedStyle : DWORD {
-        const ACCEPTFILES = winapi::um::winuser::WS_EX_ACCEPTFILES;
-        const APPWINDOW = winapi::um::winuser::WS_EX_APPWINDOW;
-        const CLIENTEDGE = winapi::um::winuser::WS_EX_CLIENTEDGE;
-        const COMPOSITED = winapi::um::winuser::WS_EX_COMPOSITED;
-        const CONTEXTHELP = winapi::um::winuser::WS_EX_CONTEXTHELP;
-        const CONTROLPARENT = winapi::um::winuser::WS_EX_CONTROLPARENT;
-        const DLGMODALFRAME = winapi::um::winuser::WS_EX_DLGMODALFRAME;
-        const LAYERED = winapi::um::winuser::WS_EX_LAYERED;
-        const LAYOUTRTL = winapi::um::winuser::WS_EX_LAYOUTRTL;
-        const LEFT = winapi::um::winuser::WS_EX_LEFT;
-        const LEFTSCROLLBAR = winapi::um::winuser::WS_EX_LEFTSCROLLBAR;
-        const LTRREADING = winapi::um::winuser::WS_EX_LTRREADING;
-        const MDICHILD = winapi::um::winuser::WS_EX_MDICHILD;
-        const NOACTIVATE = winapi::um::winuser::WS_EX_NOACTIVATE;
-        const NOINHERITLAYOUT = winapi::um::winuser::WS_EX_NOINHERITLAYOUT;
-        const NOPARENTNOTIFY = winapi::um::winuser::WS_EX_NOPARENTNOTIFY;
-        const NOREDIRECTIONBITMAP = winapi::um::winuser::WS_EX_NOREDIRECTIONBITMAP;
-        const OVERLAPPEDWINDOW = winapi::um::winuser::WS_EX_OVERLAPPEDWINDOW;
-        const PALETTEWINDOW = winapi::um::winuser::WS_EX_PALETTEWINDOW;
-        const RIGHT = winapi::um::winuser::WS_EX_RIGHT;
-        const RIGHTSCROLLBAR = winapi::um::winuser::WS_EX_RIGHTSCROLLBAR;
-        const RTLREADING = winapi::um::winuser::WS_EX_RTLREADING;
-        const STATICEDGE = winapi::um::winuser::WS_EX_STATICEDGE;
-        const TOOLWINDOW = winapi::um::winuser::WS_EX_TOOLWINDOW;
-        const TOPMOST = winapi::um::winuser::WS_EX_TOPMOST;
-        const TRANSPARENT = winapi::um::winuser::WS_EX_TRANSPARENT;
-        const WINDOWEDGE = winapi::um::winuser::WS_EX_WINDOWEDGE;
-    }
-}
-
-bitflags! {
+        const ACCEPTFILES = WS_EX_ACCEPTFILES;
+        const APPWINDOW = WS_EX_APPWINDOW;
+        const CLIENTEDGE = WS_EX_CLIENTEDGE;
+        const COMPOSITED = WS_EX_COMPOSITED;
+        const CONTEXTHELP = WS_EX_CONTEXTHELP;
+        const CONTROLPARENT = WS_EX_CONTROLPARENT;
+        const DLGMODALFRAME = WS_EX_DLGMODALFRAME;
+        const LAYERED = WS_EX_LAYERED;
+        const LAYOUTRTL = WS_EX_LAYOUTRTL;
+        const LEFT = WS_EX_LEFT;
+        const LEFTSCROLLBAR = WS_EX_LEFTSCROLLBAR;
+        const LTRREADING = WS_EX_LTRREADING;
+        const MDICHILD = WS_EX_MDICHILD;
+        const NOACTIVATE = WS_EX_NOACTIVATE;
+        const NOINHERITLAYOUT = WS_EX_NOINHERITLAYOUT;
+        const NOPARENTNOTIFY = WS_EX_NOPARENTNOTIFY;
+        const NOREDIRECTIONBITMAP = WS_EX_NOREDIRECTIONBITMAP;
+        const OVERLAPPEDWINDOW = WS_EX_OVERLAPPEDWINDOW;
+        const PALETTEWINDOW = WS_EX_PALETTEWINDOW;
+        const RIGHT = WS_EX_RIGHT;
+        const RIGHTSCROLLBAR = WS_EX_RIGHTSCROLLBAR;
+        const RTLREADING = WS_EX_RTLREADING;
+        const STATICEDGE = WS_EX_STATICEDGE;
+        const TOOLWINDOW = WS_EX_TOOLWINDOW;
+        const TOPMOST = WS_EX_TOPMOST;
+        const TRANSPARENT = WS_EX_TRANSPARENT;
+        const WINDOWEDGE = WS_EX_WINDOWEDGE;
+    }
+}
+
+NAMED_BITFLAGS! {
     pub struct WindowStyle : DWORD {
-        const BORDER = winapi::um::winuser::WS_BORDER;
-        const CAPTION = winapi::um::winuser::WS_CAPTION;
-        const CHILD = winapi::um::winuser::WS_CHILD;
-        const CHILDWINDOW = winapi::um::winuser::WS_CHILDWINDOW;
-        const CLIPCHILDREN = winapi::um::winuser::WS_CLIPCHILDREN;
-        const CLIPSIBLINGS = winapi::um::winuser::WS_CLIPSIBLINGS;
-        const DISABLED = winapi::um::winuser::WS_DISABLED;
-        const DLGFRAME = winapi::um::winuser::WS_DLGFRAME;
-        const GROUP = winapi::um::winuser::WS_GROUP;
-        const HSCROLL = winapi::um::winuser::WS_HSCROLL;
-        const ICONIC = winapi::um::winuser::WS_ICONIC;
-        const MAXIMIZE = winapi::um::winuser::WS_MAXIMIZE;
-        const MAXIMIZEBOX = winapi::um::winuser::WS_MAXIMIZEBOX;
-        const MINIMIZE = winapi::um::winuser::WS_MINIMIZE;
-        const MINIMIZEBOX = winapi::um::winuser::WS_MINIMIZEBOX;
-        const OVERLAPPED = winapi::um::winuser::WS_OVERLAPPED;
-        const OVERLAPPEDWINDOW = winapi::um::winuser::WS_OVERLAPPEDWINDOW;
-        const POPUP = winapi::um::winuser::WS_POPUP;
-        const POPUPWINDOW = winapi::um::winuser::WS_POPUPWINDOW;
-        const SIZEBOX = winapi::um::winuser::WS_SIZEBOX;
-        const SYSMENU = winapi::um::winuser::WS_SYSMENU;
-        const TABSTOP = winapi::um::winuser::WS_TABSTOP;
-        const THICKFRAME = winapi::um::winuser::WS_THICKFRAME;
-        const TILED = winapi::um::winuser::WS_TILED;
-        const TILEDWINDOW = winapi::um::winuser::WS_TILEDWINDOW;
-        const VISIBLE = winapi::um::winuser::WS_VISIBLE;
-        const VSCROLL = winapi::um::winuser::WS_VSCROLL;
+        const BORDER = WS_BORDER;
+        const CAPTION = WS_CAPTION;
+        const CHILD = WS_CHILD;
+        const CHILDWINDOW = WS_CHILDWINDOW;
+        const CLIPCHILDREN = WS_CLIPCHILDREN;
+        const CLIPSIBLINGS = WS_CLIPSIBLINGS;
+        const DISABLED = WS_DISABLED;
+        const DLGFRAME = WS_DLGFRAME;
+        const GROUP = WS_GROUP;
+        const HSCROLL = WS_HSCROLL;
+        const ICONIC = WS_ICONIC;
+        const MAXIMIZE = WS_MAXIMIZE;
+        const MAXIMIZEBOX = WS_MAXIMIZEBOX;
+        const MINIMIZE = WS_MINIMIZE;
+        const MINIMIZEBOX = WS_MINIMIZEBOX;
+        const OVERLAPPED = WS_OVERLAPPED;
+        const OVERLAPPEDWINDOW = WS_OVERLAPPEDWINDOW;
+        const POPUP = WS_POPUP;
+        const POPUPWINDOW = WS_POPUPWINDOW;
+        const SIZEBOX = WS_SIZEBOX;
+        const SYSMENU = WS_SYSMENU;
+        const TABSTOP = WS_TABSTOP;
+        const THICKFRAME = WS_THICKFRAME;
+        const TILED = WS_TILED;
+        const TILEDWINDOW = WS_TILEDWINDOW;
+        const VISIBLE = WS_VISIBLE;
+        const VSCROLL = WS_VSCROLL;
+    }
+}
+
+/// Mirror of the POINT Struct
+#[cfg(windows)]
+#[derive(LayoutMirror, Debug, Copy, Clone, PartialEq, Eq)]
+#[mirror(winapi::shared::windef::POINT)]
+#[repr(C)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Mirror of the MSG Struct
+///
+/// # Note
+///
+/// The Layout is verified against MSG at Compile Time, so a Message can be passed to the Win32 API wherever a MSG is expected.
+#[cfg(windows)]
+#[derive(LayoutMirror, Copy, Clone)]
+#[mirror(winapi::um::winuser::MSG)]
+#[repr(C)]
+pub struct Message {
+    #[mirror(hwnd)]
+    pub window: HWND,
+    pub message: u32,
+    #[mirror(wParam)]
+    pub w_param: WPARAM,
+    #[mirror(lParam)]
+    pub l_param: LPARAM,
+    pub time: u32,
+    #[mirror(pt)]
+    pub point: Point,
+}
+
+#[cfg(windows)]
+impl From<MSG> for Message {
+    #[inline]
+    fn from(msg: MSG) -> Message {
+        // The Layouts are verified by LayoutMirror
+        return unsafe { mem::transmute::<MSG, Message>(msg) };
+    }
+}
+
+#[cfg(windows)]
+impl From<Message> for MSG {
+    #[inline]
+    fn from(message: Message) -> MSG {
+        // The Layouts are verified by LayoutMirror
+        return unsafe { mem::transmute::<Message, MSG>(message) };
+    }
+}
+
+#[cfg(windows)]
+impl Message {
+    /// Returns a Pointer to the Message, that can be passed to the Win32 API as a Pointer to a MSG
+    #[inline]
+    pub fn as_msg_ptr(&self) -> *const MSG {
+        return self as *const Message as *const MSG;
     }
 }
 
+/// Decoder for the Characters of the Character Messages retrieved by GetMessageA
+///
+/// Character Messages of ANSI Windows carry their Character in the active ANSI Code Page.
+/// Characters that need several Bytes arrive as one Message per Byte, only WM_IME_CHAR carries the Lead Byte in the high Byte of wParam.
+/// The Decoder keeps the Bytes of an incomplete Character until its last Byte arrives.
+#[derive(Debug, Clone)]
+pub struct AnsiCharacterDecoder {
+    code_page: CodePage,
+    pending: Vec<u8>,
+}
+
+impl AnsiCharacterDecoder {
+    /// Creates a Decoder for a Code Page
+    ///
+    /// # Arguments
+    ///
+    /// * `code_page` - The Code Page of the Messages, which is the active ANSI Code Page for Messages retrieved by GetMessageA
+    #[inline]
+    pub fn new(code_page: CodePage) -> AnsiCharacterDecoder {
+        return AnsiCharacterDecoder {
+            code_page,
+            pending: Vec::new(),
+        };
+    }
+
+    /// Decodes the wParam of a Character Message
+    ///
+    /// # Arguments
+    ///
+    /// * `w_param` - The wParam of the Message, a single Byte or a Lead and a Trail Byte
+    ///
+    /// # Return
+    ///
+    /// This Function returns None while the Character is incomplete, or an Error if the Bytes are not a Character of the Code Page
+    pub fn decode(&mut self, w_param: usize) -> Result<Option<char>, CodePageError> {
+        let bytes = match w_param > 0xFF {
+            true => {
+                self.pending.clear();
+                vec![(w_param >> 8) as u8, w_param as u8]
+            }
+            false => {
+                self.pending.push(w_param as u8);
+                if self.code_page.is_incomplete(&self.pending) {
+                    return Ok(None);
+                }
+                std::mem::take(&mut self.pending)
+            }
+        };
+        let decoded = self.code_page.decode(&bytes)?;
+        let mut characters = decoded.chars();
+        return match (characters.next(), characters.next()) {
+            (Some(character), None) => Ok(Some(character)),
+            _ => Err(CodePageError::InvalidSequence { position: 0 }),
+        };
+    }
+
+    /// Decodes the Character of a Message
+    ///
+    /// # Return
+    ///
+    /// This Function returns None for Messages that are not Character Messages and while the Character is incomplete.
+    /// It returns `ErrorKind::CodePage` if the Bytes are not a Character of the Code Page.
+    #[cfg(windows)]
+    pub fn decode_message(&mut self, message: &Message) -> Result<Option<char>, Error> {
+        return match message.message {
+            WM_CHAR | WM_DEADCHAR | WM_SYSCHAR | WM_SYSDEADCHAR | WM_IME_CHAR => Ok(self.decode(message.w_param)?),
+            _ => Ok(None),
+        };
+    }
+}
+
+/// # Note
+///
+/// Character Messages retrieved by this Function carry Characters in the active ANSI Code Page, use an `AnsiCharacterDecoder` to convert them
+///
+/// # Return
+///
+/// On Success, returns whether the Message is not WM_QUIT, together with the Message.
+/// On Error, returns the Error Code, as GetMessage returns `-1` instead of a Message.
+#[cfg(windows)]
 #[inline]
-pub fn GetMessageA(h_wnd: HWND, msg_filter_min: u32, msg_filter_max: u32) -> (bool, MSG) {
-    let mut uninit_msg: MaybeUninit<MSG> = MaybeUninit::<MSG>::uninit();
-    let lp_msg: LPMSG = uninit_msg.as_mut_ptr() as LPMSG;
+pub fn GetMessageA(h_wnd: HWND, msg_filter_min: u32, msg_filter_max: u32) -> Result<(bool, Message), Error> {
+    // The Layouts are verified by LayoutMirror, so GetMessage can write the MSG into the Message
+    let mut uninit_message: MaybeUninit<Message> = MaybeUninit::<Message>::uninit();
+    let lp_msg: LPMSG = uninit_message.as_mut_ptr() as LPMSG;
     let value: BOOL;
-    let msg: MSG;
     unsafe {
         value = winapi::um::winuser::GetMessageA(lp_msg, h_wnd, msg_filter_min, msg_filter_max);
-        msg = uninit_msg.assume_init();
     }
-    return (convert_c_bool(value), msg);
+    let value = check::<SentinelPolicy<-1>, _>("GetMessageA", value)?;
+    let message: Message = unsafe { uninit_message.assume_init() };
+    return Ok((convert_c_bool(value), message));
 }
 
+/// # Return
+///
+/// On Success, returns whether the Message is not WM_QUIT, together with the Message.
+/// On Error, returns the Error Code, as GetMessage returns `-1` instead of a Message.
+#[cfg(windows)]
 #[inline]
-pub fn GetMessageW(h_wnd: HWND, msg_filter_min: u32, msg_filter_max: u32) -> (bool, MSG)
+pub fn GetMessageW(h_wnd: HWND, msg_filter_min: u32, msg_filter_max: u32) -> Result<(bool, Message), Error>
 {
-    let mut uninit_msg: MaybeUninit<MSG> = MaybeUninit::<MSG>::uninit();
-    let lp_msg: LPMSG = uninit_msg.as_mut_ptr() as LPMSG;
+    // The Layouts are verified by LayoutMirror, so GetMessage can write the MSG into the Message
+    let mut uninit_message: MaybeUninit<Message> = MaybeUninit::<Message>::uninit();
+    let lp_msg: LPMSG = uninit_message.as_mut_ptr() as LPMSG;
     let value: BOOL;
-    let msg: MSG;
     unsafe {
         value = winapi::um::winuser::GetMessageW(lp_msg, h_wnd, msg_filter_min, msg_filter_max);
-        msg = uninit_msg.assume_init();
     }
-    return (convert_c_bool(value), msg);
+    let value = check::<SentinelPolicy<-1>, _>("GetMessageW", value)?;
+    let message: Message = unsafe { uninit_message.assume_init() };
+    return Ok((convert_c_bool(value), message));
 }
 
+/// Generic Wrapping Function for the GetMessageA and GetMessageW Functions
+///
+/// # Arguments
+///
+/// * `character_set` - The Character Set of the Window Class, which decides the Entry Point and the Encoding of Character Messages
+#[cfg(windows)]
 #[inline]
-pub fn TranslateMessage(msg: &MSG) -> bool {
-    let msg_ptr: *const MSG = msg as *const MSG;
+pub fn get_message(character_set: CharacterSet, h_wnd: HWND, msg_filter_min: u32, msg_filter_max: u32) -> Result<(bool, Message), Error> {
+    return match character_set {
+        CharacterSet::Ansi => GetMessageA(h_wnd, msg_filter_min, msg_filter_max),
+        CharacterSet::Wide => GetMessageW(h_wnd, msg_filter_min, msg_filter_max),
+    };
+}
+
+#[cfg(windows)]
+#[inline]
+pub fn TranslateMessage(message: &Message) -> bool {
+    let msg_ptr: *const MSG = message.as_msg_ptr();
     let value: BOOL;
     unsafe {
         value = winapi::um::winuser::TranslateMessage(msg_ptr);
@@ -126,9 +383,10 @@ pub fn TranslateMessage(msg: &MSG) -> bool {
     return convert_c_bool(value);
 }
 
+#[cfg(windows)]
 #[inline]
-pub fn DispatchMessageA(msg: &MSG) -> isize {
-    let msg_ptr: *const MSG = msg as *const MSG;
+pub fn DispatchMessageA(message: &Message) -> isize {
+    let msg_ptr: *const MSG = message.as_msg_ptr();
     let value: LRESULT;
     unsafe {
         value = winapi::um::winuser::DispatchMessageA(msg_ptr);
@@ -136,9 +394,10 @@ pub fn DispatchMessageA(msg: &MSG) -> isize {
     return value;
 }
 
+#[cfg(windows)]
 #[inline]
-pub fn DispatchMessageW(msg: &MSG) -> isize {
-    let msg_ptr: *const MSG = msg as *const MSG;
+pub fn DispatchMessageW(message: &Message) -> isize {
+    let msg_ptr: *const MSG = message.as_msg_ptr();
     let value: LRESULT;
     unsafe {
         value = winapi::um::winuser::DispatchMessageW(msg_ptr);
@@ -146,6 +405,21 @@ pub fn DispatchMessageW(msg: &MSG) -> isize {
     return value;
 }
 
+/// Generic Wrapping Function for the DispatchMessageA and DispatchMessageW Functions
+///
+/// # Arguments
+///
+/// * `character_set` - The Character Set of the Window Class, which decides the Entry Point
+#[cfg(windows)]
+#[inline]
+pub fn dispatch_message(character_set: CharacterSet, message: &Message) -> isize {
+    return match character_set {
+        CharacterSet::Ansi => DispatchMessageA(message),
+        CharacterSet::Wide => DispatchMessageW(message),
+    };
+}
+
+#[cfg(windows)]
 #[inline]
 pub fn ShowWindow(h_wnd: HWND, n_cmd_show: N_CMD_SHOW) -> bool {
     let n_cmd_show: c_int = n_cmd_show.into();
@@ -156,6 +430,7 @@ pub fn ShowWindow(h_wnd: HWND, n_cmd_show: N_CMD_SHOW) -> bool {
     return convert_c_bool(value);
 }
 
+#[cfg(windows)]
 #[inline]
 pub fn ShowWindowAsync(h_wnd: HWND, n_cmd_show: N_CMD_SHOW) -> bool {
     let n_cmd_show: c_int = n_cmd_show.into();
@@ -166,10 +441,19 @@ pub fn ShowWindowAsync(h_wnd: HWND, n_cmd_show: N_CMD_SHOW) -> bool {
     return convert_c_bool(value);
 }
 
+/// # Note
+///
+/// The Class and Window Names are interpreted in the active ANSI Code Page, not as UTF-8, `create_window_ex_ansi` encodes Rust Strings into it.
+///
+/// # Return
+///
+/// On Success, returns the Handle of the created Window.
+/// On Error, returns the Error Code, as CreateWindowEx returns a Null Handle.
+#[cfg(windows)]
 pub fn CreateWindowExA(
     w_ex_style: WindowExtendedStyle,
-    lp_class_name: CString,
-    lp_window_name: CString,
+    lp_class_name: &CStr,
+    lp_window_name: &CStr,
     dw_style: WindowStyle,
     x: i32,
     y: i32,
@@ -179,7 +463,7 @@ pub fn CreateWindowExA(
     menu: Option<HMENU>,
     instance: HINSTANCE,
     lp_param: Option<LPVOID>
-) -> HWND
+) -> Result<HWND, Error>
 {
     let class_name_ptr: *const i8 = lp_class_name.as_ptr();
     let window_name_ptr: *const i8 = lp_window_name.as_ptr();
@@ -207,6 +491,239 @@ pub fn CreateWindowExA(
             param
         );
     }
-    return window;
+    return check::<NonNullPolicy, _>("CreateWindowExA", window);
+}
+
+/// Wrapping Function for the CreateWindowExA Function, that encodes the Class and Window Names into a Code Page
+///
+/// # Arguments
+///
+/// * `code_page` - The Code Page of the Names, which should be the active ANSI Code Page
+///
+/// # Return
+///
+/// On Success, returns the Handle of the created Window.
+/// On Error, returns the Error Code, or `ErrorKind::CodePage` if one of the Names cannot be encoded
+#[cfg(windows)]
+pub fn create_window_ex_ansi(
+    code_page: CodePage,
+    w_ex_style: WindowExtendedStyle,
+    class_name: &str,
+    window_name: &str,
+    dw_style: WindowStyle,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    wnd_parent: Option<HWND>,
+    menu: Option<HMENU>,
+    instance: HINSTANCE,
+    lp_param: Option<LPVOID>
+) -> Result<HWND, Error>
+{
+    let class_name = code_page.encode_c_string(class_name)?;
+    let window_name = code_page.encode_c_string(window_name)?;
+    return CreateWindowExA(w_ex_style, &class_name, &window_name, dw_style, x, y, width, height, wnd_parent, menu, instance, lp_param);
+}
+
+
+/// Generic Wrapping Function for the CreateWindowExA and CreateWindowExW Functions
+///
+/// The Entry Point is chosen from the Type of the Class and Window Names, see the `win32_str` Module.
+/// The Names can have different Types, like `String` and `&str`, as long as both select the same Character Set.
+///
+/// # Return
+///
+/// On Success, returns the Handle of the created Window.
+/// On Error, returns the Error Code, or `ErrorKind::InvalidString` if one of the Names contains a Null-Character.
+/// If the Names select different Character Sets, this Function returns `ErrorKind::InvalidArgument`.
+#[cfg(windows)]
+pub fn create_window_ex<C: Win32Str + ?Sized, W: Win32Str + ?Sized>(
+    w_ex_style: WindowExtendedStyle,
+    class_name: &C,
+    window_name: &W,
+    dw_style: WindowStyle,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    wnd_parent: Option<HWND>,
+    menu: Option<HMENU>,
+    instance: HINSTANCE,
+    lp_param: Option<LPVOID>
+) -> Result<HWND, Error>
+{
+    if C::CHARACTER_SET != W::CHARACTER_SET {
+        return Err(Error::new(ErrorKind::InvalidArgument("the class and window names select different character sets")));
+    }
+    let class_name = class_name.to_win32_string()?;
+    let window_name = window_name.to_win32_string()?;
+
+    let wnd_parent: HWND = wnd_parent.unwrap_or(null_mut());
+    let menu: HMENU = menu.unwrap_or(null_mut());
+    let param: LPVOID = lp_param.unwrap_or(null_mut());
+
+    let window: HWND;
+    unsafe {
+        window = match C::CHARACTER_SET {
+            CharacterSet::Ansi => winapi::um::winuser::CreateWindowExA(
+                w_ex_style.bits,
+                class_name.as_ansi_ptr().expect("Both Names select the same Character Set"),
+                window_name.as_ansi_ptr().expect("Both Names select the same Character Set"),
+                dw_style.bits,
+                x,
+                y,
+                width,
+                height,
+                wnd_parent,
+                menu,
+                instance,
+                param
+            ),
+            CharacterSet::Wide => winapi::um::winuser::CreateWindowExW(
+                w_ex_style.bits,
+                class_name.as_wide_ptr().expect("Both Names select the same Character Set"),
+                window_name.as_wide_ptr().expect("Both Names select the same Character Set"),
+                dw_style.bits,
+                x,
+                y,
+                width,
+                height,
+                wnd_parent,
+                menu,
+                instance,
+                param
+            ),
+        };
+    }
+    let api = match C::CHARACTER_SET {
+        CharacterSet::Ansi => "CreateWindowExA",
+        CharacterSet::Wide => "CreateWindowExW",
+    };
+    return check::<NonNullPolicy, _>(api, window);
 }
 
+/// Tests for the Decoding of Character Messages
+#[cfg(test)]
+mod test_ansi_character_decoder {
+    use crate::type_wrappers::type_conversion::code_page::{CodePage, CodePageError};
+    use crate::type_wrappers::window::AnsiCharacterDecoder;
+
+    /// Tests that single Byte Characters are decoded from one Message
+    #[test]
+    fn test_single_byte() {
+        let mut decoder = AnsiCharacterDecoder::new(CodePage::Windows1252);
+        assert_eq!(decoder.decode(0x41), Ok(Some('A')));
+        assert_eq!(decoder.decode(0xFC), Ok(Some('ü')));
+        assert_eq!(decoder.decode(0x80), Ok(Some('€')));
+    }
+
+    /// Tests that Characters of several Bytes are decoded once their last Byte arrives
+    #[test]
+    fn test_multi_byte() {
+        let mut decoder = AnsiCharacterDecoder::new(CodePage::ShiftJis);
+        assert_eq!(decoder.decode(0x82), Ok(None));
+        assert_eq!(decoder.decode(0xA0), Ok(Some('あ')));
+        assert_eq!(decoder.decode(0x82A0), Ok(Some('あ')));
+
+        let mut decoder = AnsiCharacterDecoder::new(CodePage::Utf8);
+        assert_eq!(decoder.decode(0xE2), Ok(None));
+        assert_eq!(decoder.decode(0x82), Ok(None));
+        assert_eq!(decoder.decode(0xAC), Ok(Some('€')));
+    }
+
+    /// Tests that invalid Sequences are reported and do not affect the following Characters
+    #[test]
+    fn test_invalid_sequence() {
+        let mut decoder = AnsiCharacterDecoder::new(CodePage::ShiftJis);
+        assert_eq!(decoder.decode(0x82), Ok(None));
+        assert_eq!(decoder.decode(0x20), Err(CodePageError::InvalidSequence { position: 0 }));
+        assert_eq!(decoder.decode(0x41), Ok(Some('A')));
+
+        let mut decoder = AnsiCharacterDecoder::new(CodePage::Utf8);
+        assert_eq!(decoder.decode(0xFF), Err(CodePageError::InvalidSequence { position: 0 }));
+    }
+}
+
+/// Tests for the Constants defined in this Module
+#[cfg(all(test, windows))]
+mod test_constants {
+    use crate::type_wrappers::window::*;
+
+    /// Tests that the Constants match the Definitions of the winuser.h Header in WINAPI
+    #[test]
+    fn test_match_winapi() {
+        assert_eq!(WM_CHAR, winapi::um::winuser::WM_CHAR);
+        assert_eq!(WM_DEADCHAR, winapi::um::winuser::WM_DEADCHAR);
+        assert_eq!(WM_SYSCHAR, winapi::um::winuser::WM_SYSCHAR);
+        assert_eq!(WM_SYSDEADCHAR, winapi::um::winuser::WM_SYSDEADCHAR);
+        assert_eq!(WM_IME_CHAR, winapi::um::winuser::WM_IME_CHAR);
+        assert_eq!(SW_HIDE, winapi::um::winuser::SW_HIDE);
+        assert_eq!(SW_SHOWNORMAL, winapi::um::winuser::SW_SHOWNORMAL);
+        assert_eq!(SW_SHOWMINIMIZED, winapi::um::winuser::SW_SHOWMINIMIZED);
+        assert_eq!(SW_SHOWMAXIMIZED, winapi::um::winuser::SW_SHOWMAXIMIZED);
+        assert_eq!(SW_MAXIMIZE, winapi::um::winuser::SW_MAXIMIZE);
+        assert_eq!(SW_SHOWNOACTIVATE, winapi::um::winuser::SW_SHOWNOACTIVATE);
+        assert_eq!(SW_SHOW, winapi::um::winuser::SW_SHOW);
+        assert_eq!(SW_MINIMIZE, winapi::um::winuser::SW_MINIMIZE);
+        assert_eq!(SW_SHOWMINNOACTIVE, winapi::um::winuser::SW_SHOWMINNOACTIVE);
+        assert_eq!(SW_SHOWNA, winapi::um::winuser::SW_SHOWNA);
+        assert_eq!(SW_RESTORE, winapi::um::winuser::SW_RESTORE);
+        assert_eq!(SW_SHOWDEFAULT, winapi::um::winuser::SW_SHOWDEFAULT);
+        assert_eq!(SW_FORCEMINIMIZE, winapi::um::winuser::SW_FORCEMINIMIZE);
+        assert_eq!(WS_EX_DLGMODALFRAME, winapi::um::winuser::WS_EX_DLGMODALFRAME);
+        assert_eq!(WS_EX_NOPARENTNOTIFY, winapi::um::winuser::WS_EX_NOPARENTNOTIFY);
+        assert_eq!(WS_EX_TOPMOST, winapi::um::winuser::WS_EX_TOPMOST);
+        assert_eq!(WS_EX_ACCEPTFILES, winapi::um::winuser::WS_EX_ACCEPTFILES);
+        assert_eq!(WS_EX_TRANSPARENT, winapi::um::winuser::WS_EX_TRANSPARENT);
+        assert_eq!(WS_EX_MDICHILD, winapi::um::winuser::WS_EX_MDICHILD);
+        assert_eq!(WS_EX_TOOLWINDOW, winapi::um::winuser::WS_EX_TOOLWINDOW);
+        assert_eq!(WS_EX_WINDOWEDGE, winapi::um::winuser::WS_EX_WINDOWEDGE);
+        assert_eq!(WS_EX_CLIENTEDGE, winapi::um::winuser::WS_EX_CLIENTEDGE);
+        assert_eq!(WS_EX_CONTEXTHELP, winapi::um::winuser::WS_EX_CONTEXTHELP);
+        assert_eq!(WS_EX_RIGHT, winapi::um::winuser::WS_EX_RIGHT);
+        assert_eq!(WS_EX_LEFT, winapi::um::winuser::WS_EX_LEFT);
+        assert_eq!(WS_EX_RTLREADING, winapi::um::winuser::WS_EX_RTLREADING);
+        assert_eq!(WS_EX_LTRREADING, winapi::um::winuser::WS_EX_LTRREADING);
+        assert_eq!(WS_EX_LEFTSCROLLBAR, winapi::um::winuser::WS_EX_LEFTSCROLLBAR);
+        assert_eq!(WS_EX_RIGHTSCROLLBAR, winapi::um::winuser::WS_EX_RIGHTSCROLLBAR);
+        assert_eq!(WS_EX_CONTROLPARENT, winapi::um::winuser::WS_EX_CONTROLPARENT);
+        assert_eq!(WS_EX_STATICEDGE, winapi::um::winuser::WS_EX_STATICEDGE);
+        assert_eq!(WS_EX_APPWINDOW, winapi::um::winuser::WS_EX_APPWINDOW);
+        assert_eq!(WS_EX_OVERLAPPEDWINDOW, winapi::um::winuser::WS_EX_OVERLAPPEDWINDOW);
+        assert_eq!(WS_EX_PALETTEWINDOW, winapi::um::winuser::WS_EX_PALETTEWINDOW);
+        assert_eq!(WS_EX_LAYERED, winapi::um::winuser::WS_EX_LAYERED);
+        assert_eq!(WS_EX_NOINHERITLAYOUT, winapi::um::winuser::WS_EX_NOINHERITLAYOUT);
+        assert_eq!(WS_EX_NOREDIRECTIONBITMAP, winapi::um::winuser::WS_EX_NOREDIRECTIONBITMAP);
+        assert_eq!(WS_EX_LAYOUTRTL, winapi::um::winuser::WS_EX_LAYOUTRTL);
+        assert_eq!(WS_EX_COMPOSITED, winapi::um::winuser::WS_EX_COMPOSITED);
+        assert_eq!(WS_EX_NOACTIVATE, winapi::um::winuser::WS_EX_NOACTIVATE);
+        assert_eq!(WS_OVERLAPPED, winapi::um::winuser::WS_OVERLAPPED);
+        assert_eq!(WS_POPUP, winapi::um::winuser::WS_POPUP);
+        assert_eq!(WS_CHILD, winapi::um::winuser::WS_CHILD);
+        assert_eq!(WS_MINIMIZE, winapi::um::winuser::WS_MINIMIZE);
+        assert_eq!(WS_VISIBLE, winapi::um::winuser::WS_VISIBLE);
+        assert_eq!(WS_DISABLED, winapi::um::winuser::WS_DISABLED);
+        assert_eq!(WS_CLIPSIBLINGS, winapi::um::winuser::WS_CLIPSIBLINGS);
+        assert_eq!(WS_CLIPCHILDREN, winapi::um::winuser::WS_CLIPCHILDREN);
+        assert_eq!(WS_MAXIMIZE, winapi::um::winuser::WS_MAXIMIZE);
+        assert_eq!(WS_CAPTION, winapi::um::winuser::WS_CAPTION);
+        assert_eq!(WS_BORDER, winapi::um::winuser::WS_BORDER);
+        assert_eq!(WS_DLGFRAME, winapi::um::winuser::WS_DLGFRAME);
+        assert_eq!(WS_VSCROLL, winapi::um::winuser::WS_VSCROLL);
+        assert_eq!(WS_HSCROLL, winapi::um::winuser::WS_HSCROLL);
+        assert_eq!(WS_SYSMENU, winapi::um::winuser::WS_SYSMENU);
+        assert_eq!(WS_THICKFRAME, winapi::um::winuser::WS_THICKFRAME);
+        assert_eq!(WS_GROUP, winapi::um::winuser::WS_GROUP);
+        assert_eq!(WS_TABSTOP, winapi::um::winuser::WS_TABSTOP);
+        assert_eq!(WS_MINIMIZEBOX, winapi::um::winuser::WS_MINIMIZEBOX);
+        assert_eq!(WS_MAXIMIZEBOX, winapi::um::winuser::WS_MAXIMIZEBOX);
+        assert_eq!(WS_TILED, winapi::um::winuser::WS_TILED);
+        assert_eq!(WS_ICONIC, winapi::um::winuser::WS_ICONIC);
+        assert_eq!(WS_SIZEBOX, winapi::um::winuser::WS_SIZEBOX);
+        assert_eq!(WS_OVERLAPPEDWINDOW, winapi::um::winuser::WS_OVERLAPPEDWINDOW);
+        assert_eq!(WS_TILEDWINDOW, winapi::um::winuser::WS_TILEDWINDOW);
+        assert_eq!(WS_POPUPWINDOW, winapi::um::winuser::WS_POPUPWINDOW);
+        assert_eq!(WS_CHILDWINDOW, winapi::um::winuser::WS_CHILDWINDOW);
+    }
+}
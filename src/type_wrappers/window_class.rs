@@ -1,33 +1,70 @@
 #![allow(non_snake_case, dead_code)]
 
-use bitflags::bitflags;
-use winapi::shared::minwindef::{UINT, WPARAM, LPARAM, HINSTANCE, LRESULT};
+#[cfg(windows)]
+use winapi::shared::minwindef::HINSTANCE;
+#[cfg(windows)]
 use winapi::shared::windef::{HCURSOR, HWND, HICON, HBRUSH};
+#[cfg(windows)]
 use winapi::um::winuser::{WNDCLASSEXA, WNDCLASSEXW};
+#[cfg(windows)]
 use std::ffi::CString;
+#[cfg(windows)]
 use std::mem;
+#[cfg(windows)]
+use std::os::raw::c_char;
+#[cfg(windows)]
 use std::ptr::null_mut;
-use crate::type_wrappers::error_handling_api::GetLastError;
-
-bitflags! {
-    pub struct ClassStyle : winapi::shared::minwindef::UINT {
-        const BYTEALIGNCLIENT = winapi::um::winuser::CS_BYTEALIGNCLIENT;
-        const BYTEALIGNWINDOW = winapi::um::winuser::CS_BYTEALIGNWINDOW;
-        const CLASSDC = winapi::um::winuser::CS_CLASSDC;
-        const DBLCLKS = winapi::um::winuser::CS_DBLCLKS;
-        const DROPSHADOW = winapi::um::winuser::CS_DROPSHADOW;
-        const GLOBALCLASS = winapi::um::winuser::CS_GLOBALCLASS;
-        const HREDRAW = winapi::um::winuser::CS_HREDRAW;
-        const NOCLOSE = winapi::um::winuser::CS_NOCLOSE;
-        const OWNDC = winapi::um::winuser::CS_OWNDC;
-        const PARENTDC = winapi::um::winuser::CS_PARENTDC;
-        const SAVEBITS = winapi::um::winuser::CS_SAVEBITS;
-        const VREDRAW = winapi::um::winuser::CS_VREDRAW;
+#[cfg(windows)]
+use windings_macros::LayoutMirror;
+#[cfg(windows)]
+use crate::error::Error;
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::code_page::CodePage;
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::success::{check, SentinelPolicy};
+#[cfg(windows)]
+use crate::type_wrappers::type_conversion::win32_str::{CharacterSet, Win32Str};
+use crate::type_wrappers::type_conversion::win_types::UINT;
+
+// Values of the winuser.h Header, defined here so that the Bitflags are available on every Host
+const CS_VREDRAW: UINT = 0x0001;
+const CS_HREDRAW: UINT = 0x0002;
+const CS_DBLCLKS: UINT = 0x0008;
+const CS_OWNDC: UINT = 0x0020;
+const CS_CLASSDC: UINT = 0x0040;
+const CS_PARENTDC: UINT = 0x0080;
+const CS_NOCLOSE: UINT = 0x0200;
+const CS_SAVEBITS: UINT = 0x0800;
+const CS_BYTEALIGNCLIENT: UINT = 0x1000;
+const CS_BYTEALIGNWINDOW: UINT = 0x2000;
+const CS_GLOBALCLASS: UINT = 0x4000;
+const CS_DROPSHADOW: UINT = 0x00020000;
+
+NAMED_BITFLAGS! {
+    pub struct ClassStyle : UINT {
+        const BYTEALIGNCLIENT = CS_BYTEALIGNCLIENT;
+        const BYTEALIGNWINDOW = CS_BYTEALIGNWINDOW;
+        const CLASSDC = CS_CLASSDC;
+        const DBLCLKS = CS_DBLCLKS;
+        const DROPSHADOW = CS_DROPSHADOW;
+        const GLOBALCLASS = CS_GLOBALCLASS;
+        const HREDRAW = CS_HREDRAW;
+        const NOCLOSE = CS_NOCLOSE;
+        const OWNDC = CS_OWNDC;
+        const PARENTDC = CS_PARENTDC;
+        const SAVEBITS = CS_SAVEBITS;
+        const VREDRAW = CS_VREDRAW;
     }
 }
 
+#[cfg(windows)]
 type UnsafeCallback = unsafe extern "system" fn(HWND, u32, usize, isize) -> isize;
 
+/// # Note
+///
+/// The Menu and Class Names are interpreted in the active ANSI Code Page, not as UTF-8.
+/// `register_class_ansi` registers a Window Class with Names given as Rust Strings.
+#[cfg(windows)]
 #[derive(Clone)]
 pub struct WindowClassExA {
     pub style: ClassStyle,
@@ -43,36 +80,266 @@ pub struct WindowClassExA {
     pub small_icon: Option<HICON>,
 }
 
-impl Into<WNDCLASSEXA> for WindowClassExA {
+/// Mirror of the WNDCLASSEXA Struct, that borrows the Names of a `WindowClassExA`
+///
+/// # Note
+///
+/// The Layout is verified against WNDCLASSEXA at Compile Time, so it can be passed to RegisterClassExA.
+/// The Name Pointers are only valid while the `WindowClassExA` it was created from is alive.
+#[cfg(windows)]
+#[derive(LayoutMirror)]
+#[mirror(winapi::um::winuser::WNDCLASSEXA)]
+#[repr(C)]
+struct RawWindowClassExA {
+    #[mirror(cbSize)]
+    size: u32,
+    style: u32,
+    #[mirror(lpfnWndProc)]
+    wnd_proc: Option<UnsafeCallback>,
+    #[mirror(cbClsExtra)]
+    class_extra: i32,
+    #[mirror(cbWndExtra)]
+    window_extra: i32,
+    #[mirror(hInstance)]
+    instance: HINSTANCE,
+    #[mirror(hIcon)]
+    icon: HICON,
+    #[mirror(hCursor)]
+    cursor: HCURSOR,
+    #[mirror(hbrBackground)]
+    background_brush: HBRUSH,
+    #[mirror(lpszMenuName)]
+    menu_name: *const c_char,
+    #[mirror(lpszClassName)]
+    class_name: *const c_char,
+    #[mirror(hIconSm)]
+    small_icon: HICON,
+}
+
+/// Mirror of the WNDCLASSEXW Struct
+///
+/// # Note
+///
+/// The Layout is verified against WNDCLASSEXW at Compile Time, so it can be passed to RegisterClassExW.
+#[cfg(windows)]
+#[derive(LayoutMirror)]
+#[mirror(winapi::um::winuser::WNDCLASSEXW)]
+#[repr(C)]
+struct RawWindowClassExW {
+    #[mirror(cbSize)]
+    size: u32,
+    style: u32,
+    #[mirror(lpfnWndProc)]
+    wnd_proc: Option<UnsafeCallback>,
+    #[mirror(cbClsExtra)]
+    class_extra: i32,
+    #[mirror(cbWndExtra)]
+    window_extra: i32,
+    #[mirror(hInstance)]
+    instance: HINSTANCE,
+    #[mirror(hIcon)]
+    icon: HICON,
+    #[mirror(hCursor)]
+    cursor: HCURSOR,
+    #[mirror(hbrBackground)]
+    background_brush: HBRUSH,
+    #[mirror(lpszMenuName)]
+    menu_name: *const u16,
+    #[mirror(lpszClassName)]
+    class_name: *const u16,
+    #[mirror(hIconSm)]
+    small_icon: HICON,
+}
+
+#[cfg(windows)]
+impl RawWindowClassExA {
+    /// Creates the Mirror of the WNDCLASSEXA Struct for a Window Class, with Names in the ANSI Code Page
     #[inline]
-    fn into(self) -> WNDCLASSEXA {
-        return WNDCLASSEXA {
-            cbSize: mem::size_of::<WNDCLASSEXA>() as u32,
+    fn new<S: Win32Str + ?Sized>(class: &WindowClassEx<S>, menu_name: *const c_char, class_name: *const c_char) -> RawWindowClassExA {
+        return RawWindowClassExA {
+            size: mem::size_of::<RawWindowClassExA>() as u32,
+            style: class.style.bits,
+            wnd_proc: class.wnd_proc,
+            class_extra: class.class_extra,
+            window_extra: class.window_extra,
+            instance: class.instance,
+            icon: class.icon.unwrap_or(null_mut()),
+            cursor: class.cursor.unwrap_or(null_mut()),
+            background_brush: class.background_brush.unwrap_or(null_mut()),
+            menu_name,
+            class_name,
+            small_icon: class.small_icon.unwrap_or(null_mut()),
+        };
+    }
+}
+
+#[cfg(windows)]
+impl WindowClassExA {
+    /// Returns the Mirror of the WNDCLASSEXA Struct, whose Names point into this Window Class
+    #[inline]
+    fn to_raw(&self) -> RawWindowClassExA {
+        return RawWindowClassExA {
+            size: mem::size_of::<RawWindowClassExA>() as u32,
             style: self.style.bits,
-            lpfnWndProc: self.wnd_proc,
-            cbClsExtra: self.class_extra,
-            cbWndExtra: self.window_extra,
-            hInstance: self.instance,
-            hIcon: self.icon.unwrap_or(null_mut()),
-            hCursor: self.cursor.unwrap_or(null_mut()),
-            hbrBackground: self.background_brush.unwrap_or(null_mut()),
-            lpszMenuName: self.menu_name.as_ptr(),
-            lpszClassName: self.class_name.as_ptr(),
-            hIconSm: self.small_icon.unwrap_or(null_mut())
-        }
+            wnd_proc: self.wnd_proc,
+            class_extra: self.class_extra,
+            window_extra: self.window_extra,
+            instance: self.instance,
+            icon: self.icon.unwrap_or(null_mut()),
+            cursor: self.cursor.unwrap_or(null_mut()),
+            background_brush: self.background_brush.unwrap_or(null_mut()),
+            menu_name: self.menu_name.as_ptr(),
+            class_name: self.class_name.as_ptr(),
+            small_icon: self.small_icon.unwrap_or(null_mut()),
+        };
     }
 }
 
+#[cfg(windows)]
 #[inline]
-pub fn RegisterClassExA(class: WindowClassExA) -> Result<u16,u32>{
-    let winapi_window_class: WNDCLASSEXA = class.into();
-    let class_ptr = &winapi_window_class as *const WNDCLASSEXA;
+pub fn RegisterClassExA(class: WindowClassExA) -> Result<u16, Error> {
+    // The Class owns the Names, so it outlives the Call
+    let raw_window_class = class.to_raw();
+    let class_ptr = &raw_window_class as *const RawWindowClassExA as *const WNDCLASSEXA;
     let value: u16;
     unsafe {
         value = winapi::um::winuser::RegisterClassExA(class_ptr);
     }
-    return match value {
-        0 => Err(GetLastError()),
-        _ => Ok(value)
+    return check::<SentinelPolicy<0>, _>("RegisterClassExA", value);
+}
+
+/// Window Class that can be registered with either Entry Point
+///
+/// The Entry Point is chosen from the Type of the Names, see the `win32_str` Module.
+/// Using `&str` registers a Unicode Window Class through RegisterClassExW.
+#[cfg(windows)]
+pub struct WindowClassEx<'a, S: Win32Str + ?Sized> {
+    pub style: ClassStyle,
+    pub wnd_proc: Option<UnsafeCallback>,
+    pub class_extra: i32,
+    pub window_extra: i32,
+    pub instance: HINSTANCE,
+    pub icon: Option<HICON>,
+    pub cursor: Option<HCURSOR>,
+    pub background_brush: Option<HBRUSH>,
+    pub menu_name: Option<&'a S>,
+    pub class_name: &'a S,
+    pub small_icon: Option<HICON>,
+}
+
+#[cfg(windows)]
+impl<S: Win32Str + ?Sized> Clone for WindowClassEx<'_, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        return *self;
+    }
+}
+
+#[cfg(windows)]
+impl<S: Win32Str + ?Sized> Copy for WindowClassEx<'_, S> {}
+
+/// Generic Wrapping Function for the RegisterClassExA and RegisterClassExW Functions
+///
+/// # Return
+///
+/// On Success, this Function returns the Class Atom.
+/// On Error, this Function returns the Error Code, or `ErrorKind::InvalidString` if one of the Names contains a Null-Character
+#[cfg(windows)]
+pub fn register_class<S: Win32Str + ?Sized>(class: &WindowClassEx<S>) -> Result<u16, Error> {
+    let class_name = class.class_name.to_win32_string()?;
+    let menu_name = match class.menu_name {
+        Some(menu_name) => Some(menu_name.to_win32_string()?),
+        None => None,
+    };
+
+    let value: u16;
+    match S::CHARACTER_SET {
+        CharacterSet::Ansi => {
+            let window_class = RawWindowClassExA::new(
+                class,
+                menu_name.as_ref().and_then(|name| name.as_ansi_ptr()).unwrap_or(std::ptr::null()),
+                class_name.as_ansi_ptr().expect("Names of the same Type have the same Character Set")
+            );
+            unsafe {
+                value = winapi::um::winuser::RegisterClassExA(&window_class as *const RawWindowClassExA as *const WNDCLASSEXA);
+            }
+        }
+        CharacterSet::Wide => {
+            let window_class = RawWindowClassExW {
+                size: mem::size_of::<RawWindowClassExW>() as u32,
+                style: class.style.bits,
+                wnd_proc: class.wnd_proc,
+                class_extra: class.class_extra,
+                window_extra: class.window_extra,
+                instance: class.instance,
+                icon: class.icon.unwrap_or(null_mut()),
+                cursor: class.cursor.unwrap_or(null_mut()),
+                background_brush: class.background_brush.unwrap_or(null_mut()),
+                menu_name: menu_name.as_ref().and_then(|name| name.as_wide_ptr()).unwrap_or(std::ptr::null()),
+                class_name: class_name.as_wide_ptr().expect("Names of the same Type have the same Character Set"),
+                small_icon: class.small_icon.unwrap_or(null_mut()),
+            };
+            unsafe {
+                value = winapi::um::winuser::RegisterClassExW(&window_class as *const RawWindowClassExW as *const WNDCLASSEXW);
+            }
+        }
+    }
+    let api = match S::CHARACTER_SET {
+        CharacterSet::Ansi => "RegisterClassExA",
+        CharacterSet::Wide => "RegisterClassExW",
     };
-}
\ No newline at end of file
+    return check::<SentinelPolicy<0>, _>(api, value);
+}
+
+/// Wrapping Function for the RegisterClassExA Function, that encodes the Names of the Window Class into a Code Page
+///
+/// # Arguments
+///
+/// * `code_page` - The Code Page of the Names, which should be the active ANSI Code Page
+/// * `class` - The Window Class, with Names given as Rust Strings
+///
+/// # Return
+///
+/// On Success, this Function returns the Class Atom.
+/// On Error, this Function returns the Error Code, or `ErrorKind::CodePage` if one of the Names cannot be encoded
+#[cfg(windows)]
+pub fn register_class_ansi(code_page: CodePage, class: &WindowClassEx<str>) -> Result<u16, Error> {
+    let class_name = code_page.encode_c_string(class.class_name)?;
+    let menu_name = match class.menu_name {
+        Some(menu_name) => Some(code_page.encode_c_string(menu_name)?),
+        None => None,
+    };
+    let window_class = RawWindowClassExA::new(
+        class,
+        menu_name.as_ref().map_or(std::ptr::null(), |name| name.as_ptr()),
+        class_name.as_ptr()
+    );
+    let value: u16;
+    unsafe {
+        value = winapi::um::winuser::RegisterClassExA(&window_class as *const RawWindowClassExA as *const WNDCLASSEXA);
+    }
+    return check::<SentinelPolicy<0>, _>("RegisterClassExA", value);
+}
+
+/// Tests for the Constants defined in this Module
+#[cfg(all(test, windows))]
+mod test_constants {
+    use crate::type_wrappers::window_class::*;
+
+    /// Tests that the Constants match the Definitions of the winuser.h Header in WINAPI
+    #[test]
+    fn test_match_winapi() {
+        assert_eq!(CS_VREDRAW, winapi::um::winuser::CS_VREDRAW);
+        assert_eq!(CS_HREDRAW, winapi::um::winuser::CS_HREDRAW);
+        assert_eq!(CS_DBLCLKS, winapi::um::winuser::CS_DBLCLKS);
+        assert_eq!(CS_OWNDC, winapi::um::winuser::CS_OWNDC);
+        assert_eq!(CS_CLASSDC, winapi::um::winuser::CS_CLASSDC);
+        assert_eq!(CS_PARENTDC, winapi::um::winuser::CS_PARENTDC);
+        assert_eq!(CS_NOCLOSE, winapi::um::winuser::CS_NOCLOSE);
+        assert_eq!(CS_SAVEBITS, winapi::um::winuser::CS_SAVEBITS);
+        assert_eq!(CS_BYTEALIGNCLIENT, winapi::um::winuser::CS_BYTEALIGNCLIENT);
+        assert_eq!(CS_BYTEALIGNWINDOW, winapi::um::winuser::CS_BYTEALIGNWINDOW);
+        assert_eq!(CS_GLOBALCLASS, winapi::um::winuser::CS_GLOBALCLASS);
+        assert_eq!(CS_DROPSHADOW, winapi::um::winuser::CS_DROPSHADOW);
+    }
+}
@@ -0,0 +1,9 @@
+use windings::error_handling_api::{scope, HandlerDecision, PlacementPosition};
+
+// Data created inside of the Scope is dropped before the Handlers are removed
+fn main() {
+    scope(|s| {
+        let state = 0;
+        let _ = s.add_exception_handler(&state, PlacementPosition::EmplaceFirst, |_, _| HandlerDecision::ContinueSearch);
+    });
+}
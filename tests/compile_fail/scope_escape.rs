@@ -0,0 +1,7 @@
+use windings::error_handling_api::scope;
+
+// The Scope cannot be used after its Handlers have been removed
+fn main() {
+    let escaped = scope(|s| s);
+    drop(escaped);
+}
@@ -0,0 +1,10 @@
+use windings::error_handling_api::{scope, HandlerDecision, PlacementPosition};
+
+// The State is borrowed by the Handler until the Scope returns
+fn main() {
+    let mut state = 0;
+    scope(|s| {
+        let _ = s.add_exception_handler(&state, PlacementPosition::EmplaceFirst, |_, _| HandlerDecision::ContinueSearch);
+        state += 1;
+    });
+}
@@ -0,0 +1,13 @@
+use std::cell::Cell;
+use windings::error_handling_api::{scope, HandlerDecision, PlacementPosition};
+
+// Handlers run on the Thread that raised the Exception, so the State has to be shareable between Threads
+fn main() {
+    let state = Cell::new(0);
+    scope(|s| {
+        let _ = s.add_exception_handler(&state, PlacementPosition::EmplaceFirst, |state, _| {
+            state.set(state.get() + 1);
+            return HandlerDecision::ContinueSearch;
+        });
+    });
+}
@@ -0,0 +1,10 @@
+//! # UI Tests
+//!
+//! Compile Tests for the Lifetimes of Scoped Vectored Handlers
+
+/// Tests that Misuse of Handler Scopes is rejected by the Borrow Checker
+#[test]
+fn test_compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,374 @@
+//! # FFI Wrap
+//!
+//! Parsing and Code Generation for the `ffi_wrap` Attribute
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{parenthesized, Attribute, Block, Error, Expr, FnArg, Ident, LitStr, Meta, Pat, PatType, Path, ReturnType, Signature, Token, Type, Visibility};
+
+/// The Names of the Success Conventions, used in Error Messages
+const SUCCESS_CONVENTIONS: &str = "`none`, `hresult`, `ntstatus`, `last_error`, `non_null` or `sentinel(<value>)`";
+
+/// How the Result of an FFI Function is checked
+enum SuccessConvention {
+    /// The Result is not checked
+    None,
+
+    /// The Function returns an HRESULT, negative Values are Errors
+    HResult,
+
+    /// The Function returns an NTSTATUS, negative Values are Errors
+    NtStatus,
+
+    /// The Function returns a BOOL, FALSE is an Error described by GetLastError
+    LastError,
+
+    /// The Function returns a Handle or Pointer, Null is an Error described by GetLastError
+    NonNull,
+
+    /// The Function returns the given Value on Failure, which is described by GetLastError
+    Sentinel(Box<Expr>),
+}
+
+/// The Arguments of the Attribute
+struct WrapArguments {
+    function: Path,
+    success: SuccessConvention,
+    crate_path: Path,
+}
+
+impl Parse for WrapArguments {
+    fn parse(input: ParseStream) -> syn::Result<WrapArguments> {
+        if input.is_empty() || input.peek(Token![crate]) || (input.peek(Ident) && input.peek2(Token![=])) {
+            return Err(input.error("expected the path of the FFI function as the first argument, like `#[ffi_wrap(winapi::um::winbase::ApplicationRecoveryFinished)]`"));
+        }
+        let function: Path = input.parse()?;
+
+        let mut success: Option<SuccessConvention> = None;
+        let mut crate_path: Option<Path> = None;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let key = input.call(Ident::parse_any)?;
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "success" => {
+                    if success.is_some() {
+                        return Err(Error::new(key.span(), "duplicate `success` option"));
+                    }
+                    success = Some(parse_success_convention(input)?);
+                }
+                "crate" => {
+                    if crate_path.is_some() {
+                        return Err(Error::new(key.span(), "duplicate `crate` option"));
+                    }
+                    crate_path = Some(input.call(Path::parse_mod_style)?);
+                }
+                _ => return Err(Error::new(key.span(), format!("unknown option `{}`, expected `success` or `crate`", key))),
+            }
+        }
+
+        return Ok(WrapArguments {
+            function,
+            success: success.unwrap_or(SuccessConvention::None),
+            crate_path: crate_path.unwrap_or_else(|| syn::parse_quote!(::windings)),
+        });
+    }
+}
+
+/// Parses the Value of the `success` Option
+fn parse_success_convention(input: ParseStream) -> syn::Result<SuccessConvention> {
+    let name = input.call(Ident::parse_any)?;
+    return match name.to_string().as_str() {
+        "none" => Ok(SuccessConvention::None),
+        "hresult" => Ok(SuccessConvention::HResult),
+        "ntstatus" => Ok(SuccessConvention::NtStatus),
+        "last_error" => Ok(SuccessConvention::LastError),
+        "non_null" => Ok(SuccessConvention::NonNull),
+        "sentinel" => {
+            if !input.peek(syn::token::Paren) {
+                return Err(Error::new(name.span(), "`sentinel` needs the value returned on failure, like `success = sentinel(-1)`"));
+            }
+            let content;
+            parenthesized!(content in input);
+            Ok(SuccessConvention::Sentinel(Box::new(content.parse()?)))
+        }
+        _ => Err(Error::new(name.span(), format!("unknown success convention `{}`, expected one of {}", name, SUCCESS_CONVENTIONS))),
+    };
+}
+
+/// The Function Signature the Attribute is placed on
+struct WrapperItem {
+    attributes: Vec<Attribute>,
+    visibility: Visibility,
+    signature: Signature,
+}
+
+impl Parse for WrapperItem {
+    fn parse(input: ParseStream) -> syn::Result<WrapperItem> {
+        let attributes = input.call(Attribute::parse_outer)?;
+        let visibility: Visibility = input.parse()?;
+        let signature: Signature = input.parse()?;
+        if input.peek(syn::token::Brace) {
+            let body: Block = input.parse()?;
+            return Err(Error::new_spanned(body, "ffi_wrap generates the body of the wrapper, replace the body with `;`"));
+        }
+        input.parse::<Token![;]>()?;
+
+        return Ok(WrapperItem {
+            attributes,
+            visibility,
+            signature,
+        });
+    }
+}
+
+/// How an Argument is converted before it is passed to the FFI Function
+enum InConversion {
+    /// The Argument is passed as it is
+    None,
+
+    /// The Argument is converted using the ToFfi Trait
+    Type(Type),
+
+    /// The Argument is converted by calling a Function
+    With(Path),
+}
+
+/// The Role of a Parameter of the Wrapper
+enum ParameterKind {
+    /// The Parameter is passed to the FFI Function
+    In(InConversion),
+
+    /// The Parameter is written by the FFI Function and returned by the Wrapper
+    Out {
+        raw_type: Option<Type>,
+        with: Option<Path>,
+        attribute: Box<Attribute>,
+    },
+}
+
+/// A Parameter of the Wrapper
+struct Parameter {
+    name: Ident,
+    declared_type: Type,
+    kind: ParameterKind,
+    stripped: PatType,
+}
+
+/// Reads the Name, Type and Role of a Parameter
+fn parse_parameter(argument: &FnArg) -> syn::Result<Parameter> {
+    let pattern_type = match argument {
+        FnArg::Receiver(receiver) => return Err(Error::new_spanned(receiver, "ffi_wrap cannot wrap methods, remove the `self` parameter")),
+        FnArg::Typed(pattern_type) => pattern_type,
+    };
+    let name = match &*pattern_type.pat {
+        Pat::Ident(pattern) if pattern.by_ref.is_none() && pattern.subpat.is_none() => pattern.ident.clone(),
+        pattern => return Err(Error::new_spanned(pattern, "ffi_wrap parameters have to be plain identifiers")),
+    };
+
+    let mut kind: Option<ParameterKind> = None;
+    let mut stripped = pattern_type.clone();
+    stripped.attrs.clear();
+    for attribute in &pattern_type.attrs {
+        let parsed = if attribute.path().is_ident("ffi") {
+            ParameterKind::In(parse_ffi_attribute(attribute)?)
+        } else if attribute.path().is_ident("out") {
+            parse_out_attribute(attribute)?
+        } else {
+            stripped.attrs.push(attribute.clone());
+            continue;
+        };
+        if kind.is_some() {
+            return Err(Error::new_spanned(attribute, "a parameter can only have one `ffi` or `out` attribute"));
+        }
+        kind = Some(parsed);
+    }
+
+    return Ok(Parameter {
+        name,
+        declared_type: (*pattern_type.ty).clone(),
+        kind: kind.unwrap_or(ParameterKind::In(InConversion::None)),
+        stripped,
+    });
+}
+
+/// Parses `#[ffi(<type>)]` and `#[ffi(with = <function>)]`
+fn parse_ffi_attribute(attribute: &Attribute) -> syn::Result<InConversion> {
+    if !matches!(attribute.meta, Meta::List(_)) {
+        return Err(Error::new_spanned(attribute, "expected the FFI type or a conversion function, like `#[ffi(BOOL)]` or `#[ffi(with = function)]`"));
+    }
+    return attribute.parse_args_with(|input: ParseStream| {
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let key: Ident = input.parse()?;
+            if key != "with" {
+                return Err(Error::new(key.span(), format!("unknown option `{}`, expected `with`", key)));
+            }
+            input.parse::<Token![=]>()?;
+            return Ok(InConversion::With(input.parse()?));
+        }
+        return Ok(InConversion::Type(input.parse()?));
+    });
+}
+
+/// Parses `#[out]`, `#[out(<type>)]` and `#[out(<type>, with = <function>)]`
+fn parse_out_attribute(attribute: &Attribute) -> syn::Result<ParameterKind> {
+    let owned = || Box::new(attribute.clone());
+    return match &attribute.meta {
+        Meta::Path(_) => Ok(ParameterKind::Out { raw_type: None, with: None, attribute: owned() }),
+        Meta::NameValue(_) => Err(Error::new_spanned(attribute, "expected `#[out]`, `#[out(<type>)]` or `#[out(<type>, with = <function>)]`")),
+        Meta::List(list) => {
+            let parser = |input: ParseStream| {
+                if input.peek(Ident) && input.peek2(Token![=]) {
+                    return Err(input.error("`with` needs the FFI type of the out-parameter first, like `#[out(BOOL, with = function)]`"));
+                }
+                let raw_type: Type = input.parse()?;
+                let mut with: Option<Path> = None;
+                if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+                    let key: Ident = input.parse()?;
+                    if key != "with" {
+                        return Err(Error::new(key.span(), format!("unknown option `{}`, expected `with`", key)));
+                    }
+                    input.parse::<Token![=]>()?;
+                    with = Some(input.parse()?);
+                }
+                return Ok(ParameterKind::Out { raw_type: Some(raw_type), with, attribute: owned() });
+            };
+            parser.parse2(list.tokens.clone())
+        }
+    };
+}
+
+/// Expands the Attribute into the complete Wrapper Function
+pub fn expand(arguments: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let arguments: WrapArguments = syn::parse2(arguments)?;
+    let item: WrapperItem = syn::parse2(item)?;
+    let parameters = item.signature.inputs.iter().map(parse_parameter).collect::<syn::Result<Vec<Parameter>>>()?;
+
+    let has_convention = !matches!(arguments.success, SuccessConvention::None);
+    if !has_convention {
+        if let Some(attribute) = parameters.iter().find_map(|parameter| match &parameter.kind {
+            ParameterKind::Out { attribute, .. } => Some(attribute),
+            ParameterKind::In(_) => None,
+        }) {
+            return Err(Error::new_spanned(attribute, "out-parameters are only valid when the FFI function succeeds, add a success convention like `success = hresult`"));
+        }
+    }
+    if has_convention && matches!(item.signature.output, ReturnType::Default) {
+        return Err(Error::new(item.signature.ident.span(), "a wrapper with a success convention returns a `Result`, declare it like `-> Result<(), Error>`"));
+    }
+
+    let crate_path = &arguments.crate_path;
+    let result = Ident::new("result", Span::mixed_site());
+    let mut inputs: Punctuated<FnArg, Token![,]> = Punctuated::new();
+    let mut conversions: Vec<TokenStream> = Vec::new();
+    let mut ffi_arguments: Vec<TokenStream> = Vec::new();
+    let mut out_reads: Vec<TokenStream> = Vec::new();
+    let mut values: Vec<TokenStream> = Vec::new();
+    let checked = Ident::new("checked", Span::mixed_site());
+    let returns_checked = matches!(arguments.success, SuccessConvention::NonNull | SuccessConvention::Sentinel(_));
+    if returns_checked {
+        values.push(quote!(#checked));
+    }
+
+    for parameter in parameters {
+        let name = &parameter.name;
+        let declared_type = &parameter.declared_type;
+        match parameter.kind {
+            ParameterKind::In(conversion) => {
+                inputs.push(FnArg::Typed(parameter.stripped.clone()));
+                match conversion {
+                    InConversion::None => {}
+                    InConversion::Type(ffi_type) => conversions.push(quote_spanned! {ffi_type.span()=>
+                        let #name: #ffi_type = <#declared_type as #crate_path::ffi_conversion::ToFfi<#ffi_type>>::to_ffi(#name);
+                    }),
+                    InConversion::With(function) => conversions.push(quote! {
+                        let #name = #function(#name);
+                    }),
+                }
+                ffi_arguments.push(quote!(#name));
+            }
+            ParameterKind::Out { raw_type, with, .. } => {
+                let uninit = format_ident!("{}_uninit", name, span = Span::mixed_site());
+                let stored_type = raw_type.as_ref().unwrap_or(declared_type);
+                conversions.push(quote! {
+                    let mut #uninit = ::core::mem::MaybeUninit::<#stored_type>::uninit();
+                });
+                ffi_arguments.push(quote!(#uninit.as_mut_ptr()));
+                out_reads.push(match (raw_type, with) {
+                    (None, _) => quote! {
+                        let #name: #declared_type = unsafe { #uninit.assume_init() };
+                    },
+                    (Some(raw_type), None) => quote_spanned! {raw_type.span()=>
+                        let #name: #declared_type = <#declared_type as #crate_path::ffi_conversion::FromFfi<#raw_type>>::from_ffi(unsafe { #uninit.assume_init() });
+                    },
+                    (Some(_), Some(function)) => quote! {
+                        let #name: #declared_type = #function(unsafe { #uninit.assume_init() });
+                    },
+                });
+                values.push(quote!(#name));
+            }
+        }
+    }
+
+    let function = &arguments.function;
+    let call = quote!(unsafe { #function(#(#ffi_arguments),*) });
+    let body = match &arguments.success {
+        SuccessConvention::None => quote! {
+            #(#conversions)*
+            #call
+        },
+        convention => {
+            let policy = match convention {
+                SuccessConvention::HResult => quote!(#crate_path::success::HResultPolicy),
+                SuccessConvention::NtStatus => quote!(#crate_path::success::NtStatusPolicy),
+                SuccessConvention::LastError => quote!(#crate_path::success::BoolPolicy),
+                SuccessConvention::NonNull => quote!(#crate_path::success::NonNullPolicy),
+                SuccessConvention::Sentinel(value) => quote!(#crate_path::success::SentinelPolicy<{ (#value) as i128 }>),
+                SuccessConvention::None => unreachable!(),
+            };
+            let value = match values.len() {
+                1 => values.remove(0),
+                _ => quote!((#(#values),*)),
+            };
+            let api = match function.segments.last() {
+                Some(segment) => LitStr::new(&segment.ident.to_string(), segment.ident.span()),
+                None => return Err(Error::new_spanned(function, "expected the path of the FFI function")),
+            };
+            let binding = match returns_checked {
+                true => quote!(let #checked = ),
+                false => quote!(),
+            };
+            quote! {
+                #(#conversions)*
+                let #result = #call;
+                #binding match #crate_path::success::check::<#policy, _>(#api, #result) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(error) => return ::core::result::Result::Err(::core::convert::From::from(error)),
+                };
+                #(#out_reads)*
+                ::core::result::Result::Ok(#value)
+            }
+        }
+    };
+
+    let attributes = &item.attributes;
+    let visibility = &item.visibility;
+    let mut signature = item.signature.clone();
+    signature.inputs = inputs;
+    return Ok(quote! {
+        #(#attributes)*
+        #[inline]
+        #[allow(unused_unsafe)]
+        #visibility #signature {
+            #body
+        }
+    });
+}
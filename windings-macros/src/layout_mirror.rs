@@ -0,0 +1,131 @@
+//! # Layout Mirror
+//!
+//! Parsing and Code Generation for the `LayoutMirror` Derive
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, LitStr, Path};
+
+/// Returns the single `mirror` Attribute out of a List of Attributes, if there is one
+fn find_mirror_attribute(attributes: &[Attribute]) -> syn::Result<Option<&Attribute>> {
+    let mut found: Option<&Attribute> = None;
+    for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident("mirror")) {
+        if found.is_some() {
+            return Err(Error::new_spanned(attribute, "duplicate `mirror` attribute"));
+        }
+        found = Some(attribute);
+    }
+    return Ok(found);
+}
+
+/// Checks that the Struct has a Representation with a defined Layout
+fn check_representation(input: &DeriveInput) -> syn::Result<()> {
+    for attribute in input.attrs.iter().filter(|attribute| attribute.path().is_ident("repr")) {
+        let mut defined = false;
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") || meta.path.is_ident("transparent") {
+                defined = true;
+            }
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            return Ok(());
+        })?;
+        if defined {
+            return Ok(());
+        }
+    }
+    return Err(Error::new(input.ident.span(), "a layout mirror needs a defined layout, add `#[repr(C)]`"));
+}
+
+/// Expands the Derive into the static Layout Assertions
+///
+/// # Arguments
+///
+/// * `input` - The Struct the Derive is placed on
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new(input.ident.span(), "a layout mirror needs named fields, to match them with the fields of the mirrored struct")),
+        },
+        Data::Enum(data) => return Err(Error::new(data.enum_token.span, "only structs can be layout mirrors")),
+        Data::Union(data) => return Err(Error::new(data.union_token.span, "only structs can be layout mirrors")),
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "a layout mirror can not be generic, its layout has to be checked for a single type"));
+    }
+    let target: Path = match find_mirror_attribute(&input.attrs)? {
+        Some(attribute) => attribute.parse_args()?,
+        None => return Err(Error::new(input.ident.span(), "missing the mirrored struct, add it like `#[mirror(winapi::um::winuser::MSG)]`")),
+    };
+    check_representation(&input)?;
+
+    let name = &input.ident;
+    let target_name = match target.segments.last() {
+        Some(segment) => segment.ident.to_string(),
+        None => return Err(Error::new_spanned(&target, "expected the path of the mirrored struct")),
+    };
+
+    let size_message = LitStr::new(&format!("`{}` and `{}` differ in size", name, target_name), name.span());
+    let align_message = LitStr::new(&format!("`{}` and `{}` differ in alignment", name, target_name), name.span());
+    let mut assertions: Vec<TokenStream> = Vec::new();
+    let mut target_fields: Vec<Ident> = Vec::new();
+    for field in fields {
+        let field_name = field.ident.as_ref().expect("named fields have a name");
+        let target_field: Ident = match find_mirror_attribute(&field.attrs)? {
+            Some(attribute) => attribute.parse_args_with(Ident::parse_any)?,
+            None => field_name.clone(),
+        };
+
+        let offset_message = LitStr::new(
+            &format!("`{}::{}` and `{}::{}` differ in offset", name, field_name, target_name, target_field),
+            field_name.span()
+        );
+        let field_size_message = LitStr::new(
+            &format!("`{}::{}` and `{}::{}` differ in size", name, field_name, target_name, target_field),
+            field_name.span()
+        );
+        target_fields.push(target_field.clone());
+        assertions.push(quote! {
+            assert!(::core::mem::offset_of!(#name, #field_name) == ::core::mem::offset_of!(Target, #target_field), #offset_message);
+            assert!(
+                size_of_pointee(unsafe { ::core::ptr::addr_of!((*mirror).#field_name) }) == size_of_pointee(unsafe { ::core::ptr::addr_of!((*target).#target_field) }),
+                #field_size_message
+            );
+        });
+    }
+
+    // Spanned at the Struct, so the Error names the Fields that are not mirrored
+    let exhaustive_pattern = quote_spanned! { name.span()=>
+        let Target { #(#target_fields: _,)* } = target;
+    };
+
+    return Ok(quote! {
+        #[allow(non_snake_case, unused_unsafe)]
+        const _: () = {
+            type Target = #target;
+
+            const fn size_of_pointee<T>(_pointer: *const T) -> usize {
+                return ::core::mem::size_of::<T>();
+            }
+
+            let mirror = ::core::mem::MaybeUninit::<#name>::uninit();
+            let mirror = mirror.as_ptr();
+            let target = ::core::mem::MaybeUninit::<Target>::uninit();
+            let target = target.as_ptr();
+
+            assert!(::core::mem::size_of::<#name>() == ::core::mem::size_of::<Target>(), #size_message);
+            assert!(::core::mem::align_of::<#name>() == ::core::mem::align_of::<Target>(), #align_message);
+            #(#assertions)*
+
+            // The Pattern fails to compile if a Field of the mirrored Struct is not mirrored, even if it would fit into Padding
+            #[allow(dead_code)]
+            fn _mirrors_every_field(target: Target) {
+                #exhaustive_pattern
+            }
+        };
+    });
+}
@@ -0,0 +1,99 @@
+//! # Windings Macros
+//!
+//! Procedural Macros used by the Windings Crate to wrap FFI Functions of the Win32 API.
+//!
+//! The Macros generate the repetitive Parts of a Type Wrapper: converting Arguments into FFI Types,
+//! allocating Out-Parameters, calling the FFI Function and checking its Result against the Success Convention of the Function.
+//! They also verify at Compile Time, that Structs mirroring FFI Structs have the same Layout as the mirrored Struct.
+
+#![allow(clippy::needless_return)]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+mod ffi_wrap;
+mod layout_mirror;
+
+/// Generates the Body of a Wrapper around an FFI Function
+///
+/// The Attribute is placed on a Function Signature without a Body.
+/// The Parameters of the Signature are passed to the FFI Function in the Order of their Declaration.
+///
+/// # Arguments
+///
+/// * The Path of the FFI Function, like `winapi::um::winbase::ApplicationRecoveryFinished`, always comes first
+/// * `success = <convention>` - How the Result of the FFI Function is checked, `none` if left out
+/// * `crate = <path>` - The Path of the Windings Crate, `::windings` if left out
+///
+/// # Success Conventions
+///
+/// * `none` - The Result is not checked, the Wrapper returns the Return Value of the FFI Function as it is
+/// * `hresult` - The FFI Function returns an `HRESULT` and fails for negative Values
+/// * `ntstatus` - The FFI Function returns an `NTSTATUS` and fails for negative Values
+/// * `last_error` - The FFI Function returns a `BOOL` and fails for `FALSE` with the Error Code of `GetLastError`
+/// * `non_null` - The FFI Function returns a Handle or Pointer and fails for Null with the Error Code of `GetLastError`
+/// * `sentinel(<value>)` - The FFI Function fails if it returns `<value>`, compared in the Width of the Return Type, with the Error Code of `GetLastError`
+///
+/// Every Convention is checked by the matching Policy of the `windings::success` Module, which attributes Errors to the FFI Function by the last Segment of its Path.
+/// The Wrapper returns `Result<T, E>`, where `E` has to implement `From<windings::Error>`.
+/// `T` is `()` without Out-Parameters, the Value of a single Out-Parameter or a Tuple of all Out-Parameters.
+/// For `non_null` and `sentinel` the Return Value is the first Element of that Tuple.
+///
+/// # Parameter Attributes
+///
+/// * No Attribute - The Argument is passed as it is
+/// * `#[ffi(<type>)]` - The Argument is converted into `<type>` using the `ToFfi` Trait
+/// * `#[ffi(with = <function>)]` - The Argument is converted by calling `<function>`
+/// * `#[out]` - The Parameter is an Out-Parameter of its own Type, it is removed from the Signature and returned on Success
+/// * `#[out(<type>)]` - The Parameter is an Out-Parameter of `<type>`, that is converted into the declared Type using the `FromFfi` Trait
+/// * `#[out(<type>, with = <function>)]` - The Parameter is an Out-Parameter of `<type>`, that is converted by calling `<function>`
+///
+/// # Example
+///
+/// ```ignore
+/// #[ffi_wrap(winapi::um::winbase::ApplicationRecoveryInProgress, success = hresult)]
+/// pub fn ApplicationRecoveryInProgress(#[out(BOOL)] cancelled: bool) -> Result<bool, Error>;
+/// ```
+#[proc_macro_attribute]
+pub fn ffi_wrap(arguments: TokenStream, item: TokenStream) -> TokenStream {
+    return ffi_wrap::expand(arguments.into(), item.into())
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into();
+}
+
+/// Verifies at Compile Time, that a Struct has the same Layout as the FFI Struct it mirrors
+///
+/// The Derive generates static Assertions for the Size and Alignment of the Struct and for the Offset and Size of every Field.
+/// It also checks that every Field of the mirrored Struct is mirrored, so an omitted Field cannot hide in Padding.
+/// A Mismatch fails the Build, instead of corrupting Memory when the Struct is passed to an FFI Function in place of the mirrored Struct.
+///
+/// # Attributes
+///
+/// * `#[mirror(<path>)]` - The Path of the mirrored Struct, placed on the Struct
+/// * `#[mirror(<field>)]` - The Name of the mirrored Field, placed on a Field, the Name of the Field itself if left out
+///
+/// # Note
+///
+/// The Struct needs `#[repr(C)]` and named Fields, and the Fields of the mirrored Struct have to be visible.
+/// The Assertions are evaluated for the Target that is built, so both 32-Bit and 64-Bit Targets have to be built to check both Layouts.
+/// `scripts/check-layouts.sh` checks the Crate for `i686-pc-windows-msvc` and `x86_64-pc-windows-msvc`, it runs in CI for every Push and Pull Request.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(LayoutMirror)]
+/// #[mirror(winapi::shared::windef::POINT)]
+/// #[repr(C)]
+/// pub struct Point {
+///     pub x: i32,
+///     pub y: i32,
+/// }
+/// ```
+#[proc_macro_derive(LayoutMirror, attributes(mirror))]
+pub fn layout_mirror(input: TokenStream) -> TokenStream {
+    return syn::parse(input)
+        .and_then(layout_mirror::expand)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into();
+}
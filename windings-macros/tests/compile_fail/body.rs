@@ -0,0 +1,8 @@
+use windings_macros::ffi_wrap;
+
+#[ffi_wrap(ffi::Query, success = hresult)]
+pub fn query() -> Result<(), i32> {
+    return Ok(());
+}
+
+fn main() {}
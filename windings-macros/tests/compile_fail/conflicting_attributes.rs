@@ -0,0 +1,6 @@
+use windings_macros::ffi_wrap;
+
+#[ffi_wrap(ffi::Query, success = hresult)]
+pub fn query(#[ffi(BOOL)] #[out] enabled: bool) -> Result<bool, i32>;
+
+fn main() {}
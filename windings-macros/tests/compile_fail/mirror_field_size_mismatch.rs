@@ -0,0 +1,17 @@
+use windings_macros::LayoutMirror;
+
+#[repr(C)]
+pub struct HEADER {
+    pub size: u32,
+    pub kind: u16,
+}
+
+#[derive(LayoutMirror)]
+#[mirror(HEADER)]
+#[repr(C)]
+pub struct Header {
+    pub size: u32,
+    pub kind: u32,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use windings_macros::LayoutMirror;
+
+#[repr(C)]
+pub struct RECORD {
+    pub id: u64,
+    pub kind: u32,
+    pub flags: u32,
+}
+
+// `flags` fits into the trailing Padding of the Mirror, so only the Field Check catches it
+#[derive(LayoutMirror)]
+#[mirror(RECORD)]
+#[repr(C)]
+pub struct Record {
+    pub id: u64,
+    pub kind: u32,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use windings_macros::LayoutMirror;
+
+#[repr(C)]
+pub struct RECT {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+#[derive(LayoutMirror)]
+#[mirror(RECT)]
+#[repr(C)]
+pub struct Rect {
+    pub top: i32,
+    pub left: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use windings_macros::LayoutMirror;
+
+#[repr(C)]
+pub struct POINT {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(LayoutMirror)]
+#[mirror(POINT)]
+#[repr(C)]
+pub struct Point {
+    pub x: i64,
+    pub y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use windings_macros::LayoutMirror;
+
+#[repr(C)]
+pub struct POINT {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(LayoutMirror)]
+#[mirror(POINT)]
+#[repr(C)]
+pub struct Point {
+    #[mirror(horizontal)]
+    pub x: i32,
+    pub y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use windings_macros::LayoutMirror;
+
+#[repr(C)]
+pub struct POINT {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(LayoutMirror)]
+#[mirror(POINT)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+fn main() {}
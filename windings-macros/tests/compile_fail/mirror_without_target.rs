@@ -0,0 +1,10 @@
+use windings_macros::LayoutMirror;
+
+#[derive(LayoutMirror)]
+#[repr(C)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use windings_macros::ffi_wrap;
+
+#[ffi_wrap(success = hresult)]
+pub fn query() -> Result<(), i32>;
+
+fn main() {}
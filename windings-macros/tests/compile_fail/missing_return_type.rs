@@ -0,0 +1,6 @@
+use windings_macros::ffi_wrap;
+
+#[ffi_wrap(ffi::Query, success = last_error)]
+pub fn query(value: u32);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use windings_macros::ffi_wrap;
+
+#[ffi_wrap(ffi::Query, success = hresult)]
+pub fn query(#[out(with = convert)] enabled: bool) -> Result<bool, i32>;
+
+fn main() {}
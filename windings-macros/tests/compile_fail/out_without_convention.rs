@@ -0,0 +1,6 @@
+use windings_macros::ffi_wrap;
+
+#[ffi_wrap(ffi::Query)]
+pub fn query(#[out] count: u32);
+
+fn main() {}
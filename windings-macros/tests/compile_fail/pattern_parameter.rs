@@ -0,0 +1,6 @@
+use windings_macros::ffi_wrap;
+
+#[ffi_wrap(ffi::Query, success = hresult)]
+pub fn query((low, high): (u32, u32)) -> Result<(), i32>;
+
+fn main() {}
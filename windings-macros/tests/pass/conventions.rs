@@ -0,0 +1,219 @@
+#![allow(non_snake_case)]
+
+use windings_macros::ffi_wrap;
+use fake_windings::Error;
+
+/// Stands in for the Windings Crate, which only builds on Windows
+mod fake_windings {
+    pub mod ffi_conversion {
+        pub trait ToFfi<F> {
+            fn to_ffi(self) -> F;
+        }
+
+        pub trait FromFfi<F> {
+            fn from_ffi(value: F) -> Self;
+        }
+
+        impl ToFfi<i32> for bool {
+            fn to_ffi(self) -> i32 {
+                return self as i32;
+            }
+        }
+
+        impl FromFfi<i32> for bool {
+            fn from_ffi(value: i32) -> bool {
+                return value != 0;
+            }
+        }
+    }
+
+    pub mod error_handling_api {
+        pub fn GetLastError() -> u32 {
+            return 87;
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum Error {
+        Win32(u32),
+        HResult(i32),
+        NtStatus(i32),
+    }
+
+    pub mod success {
+        use super::Error;
+
+        pub trait SuccessPolicy<R> {
+            type Output;
+            fn check<E: FnOnce() -> u32>(result: R, last_error: E) -> Result<Self::Output, Error>;
+        }
+
+        pub struct HResultPolicy;
+        pub struct NtStatusPolicy;
+        pub struct BoolPolicy;
+        pub struct NonNullPolicy;
+        pub struct SentinelPolicy<const SENTINEL: i128>;
+
+        pub trait SentinelValue: Copy + PartialEq {
+            fn from_sentinel(sentinel: i128) -> Self;
+        }
+
+        impl SentinelValue for i32 {
+            fn from_sentinel(sentinel: i128) -> i32 {
+                return sentinel as i32;
+            }
+        }
+
+        impl SentinelValue for u32 {
+            fn from_sentinel(sentinel: i128) -> u32 {
+                return sentinel as u32;
+            }
+        }
+
+        impl SuccessPolicy<i32> for HResultPolicy {
+            type Output = ();
+            fn check<E: FnOnce() -> u32>(result: i32, _last_error: E) -> Result<(), Error> {
+                return if result >= 0 { Ok(()) } else { Err(Error::HResult(result)) };
+            }
+        }
+
+        impl SuccessPolicy<i32> for NtStatusPolicy {
+            type Output = ();
+            fn check<E: FnOnce() -> u32>(status: i32, _last_error: E) -> Result<(), Error> {
+                return if status >= 0 { Ok(()) } else { Err(Error::NtStatus(status)) };
+            }
+        }
+
+        impl SuccessPolicy<i32> for BoolPolicy {
+            type Output = ();
+            fn check<E: FnOnce() -> u32>(result: i32, last_error: E) -> Result<(), Error> {
+                return if result != 0 { Ok(()) } else { Err(Error::Win32(last_error())) };
+            }
+        }
+
+        impl<T> SuccessPolicy<*mut T> for NonNullPolicy {
+            type Output = *mut T;
+            fn check<E: FnOnce() -> u32>(result: *mut T, last_error: E) -> Result<*mut T, Error> {
+                return if !result.is_null() { Ok(result) } else { Err(Error::Win32(last_error())) };
+            }
+        }
+
+        impl<R: SentinelValue, const SENTINEL: i128> SuccessPolicy<R> for SentinelPolicy<SENTINEL> {
+            type Output = R;
+            fn check<E: FnOnce() -> u32>(result: R, last_error: E) -> Result<R, Error> {
+                return if result != R::from_sentinel(SENTINEL) { Ok(result) } else { Err(Error::Win32(last_error())) };
+            }
+        }
+
+        thread_local! {
+            pub static LAST_API: std::cell::Cell<&'static str> = std::cell::Cell::new("");
+        }
+
+        pub fn check<P: SuccessPolicy<R>, R>(api: &'static str, result: R) -> Result<P::Output, Error> {
+            LAST_API.with(|last_api| last_api.set(api));
+            return P::check(result, super::error_handling_api::GetLastError);
+        }
+    }
+}
+
+/// FFI Functions with the Return Conventions of the Win32 API
+mod ffi {
+    pub unsafe fn Query(succeed: i32, enabled: *mut i32, count: *mut u32) -> i32 {
+        if succeed == 0 {
+            return 0x80004005u32 as i32;
+        }
+        *enabled = 1;
+        *count = 3;
+        return 0;
+    }
+
+    pub unsafe fn Check(succeed: i32) -> i32 {
+        return succeed;
+    }
+
+    pub unsafe fn Open(name: *const u8) -> *mut u8 {
+        return name as *mut u8;
+    }
+
+    pub unsafe fn Status(value: i32) -> i32 {
+        return value;
+    }
+
+    pub unsafe fn Index(value: u32) -> u32 {
+        return value;
+    }
+
+    pub unsafe fn Double(value: u32) -> u32 {
+        return value * 2;
+    }
+
+    pub unsafe fn Notify(_value: i32) {}
+}
+
+fn halve(value: u32) -> u32 {
+    return value / 2;
+}
+
+fn pointer(name: Option<&[u8]>) -> *const u8 {
+    return name.map_or(std::ptr::null(), <[u8]>::as_ptr);
+}
+
+#[ffi_wrap(ffi::Query, success = hresult, crate = crate::fake_windings)]
+fn query(#[ffi(i32)] succeed: bool, #[out(i32)] enabled: bool, #[out] count: u32) -> Result<(bool, u32), Error>;
+
+#[ffi_wrap(ffi::Check, success = last_error, crate = crate::fake_windings)]
+fn check(#[ffi(i32)] succeed: bool) -> Result<(), Error>;
+
+#[ffi_wrap(ffi::Open, success = non_null, crate = crate::fake_windings)]
+fn open(#[ffi(with = pointer)] name: Option<&[u8]>) -> Result<*mut u8, Error>;
+
+#[ffi_wrap(ffi::Status, success = ntstatus, crate = crate::fake_windings)]
+fn status(value: i32) -> Result<(), Error>;
+
+#[ffi_wrap(ffi::Status, success = sentinel(-1), crate = crate::fake_windings)]
+fn not_minus_one(value: i32) -> Result<i32, Error>;
+
+#[ffi_wrap(ffi::Index, success = sentinel(-1), crate = crate::fake_windings)]
+fn not_invalid_index(value: u32) -> Result<u32, Error>;
+
+#[ffi_wrap(ffi::Status, success = sentinel(0xFFFFFFFF), crate = crate::fake_windings)]
+fn not_all_bits(value: i32) -> Result<i32, Error>;
+
+/// An Error Type of a Caller, that wraps the Error of Windings
+#[derive(Debug, PartialEq)]
+struct CallerError(Error);
+
+impl From<Error> for CallerError {
+    fn from(error: Error) -> CallerError {
+        return CallerError(error);
+    }
+}
+
+#[ffi_wrap(ffi::Check, success = last_error, crate = crate::fake_windings)]
+fn check_converted(#[ffi(i32)] succeed: bool) -> Result<(), CallerError>;
+
+#[ffi_wrap(ffi::Double, crate = crate::fake_windings)]
+fn double(#[ffi(with = halve)] value: u32) -> u32;
+
+#[ffi_wrap(ffi::Notify, crate = crate::fake_windings)]
+fn notify(#[ffi(i32)] value: bool);
+
+fn main() {
+    assert_eq!(query(true), Ok((true, 3)));
+    assert_eq!(query(false), Err(Error::HResult(0x80004005u32 as i32)));
+    assert_eq!(fake_windings::success::LAST_API.with(|last_api| last_api.get()), "Query");
+    assert_eq!(check(true), Ok(()));
+    assert_eq!(check(false), Err(Error::Win32(87)));
+    assert_eq!(check_converted(false), Err(CallerError(Error::Win32(87))));
+    assert!(open(Some(b"name\0")).is_ok());
+    assert_eq!(open(None), Err(Error::Win32(87)));
+    assert_eq!(status(0x40000000), Ok(()));
+    assert_eq!(status(0xC0000005u32 as i32), Err(Error::NtStatus(0xC0000005u32 as i32)));
+    assert_eq!(not_minus_one(0), Ok(0));
+    assert_eq!(not_minus_one(-1), Err(Error::Win32(87)));
+    assert_eq!(not_invalid_index(0xFFFFFFFE), Ok(0xFFFFFFFE));
+    assert_eq!(not_invalid_index(0xFFFFFFFF), Err(Error::Win32(87)));
+    assert_eq!(not_all_bits(-1), Err(Error::Win32(87)));
+    assert_eq!(double(10), 10);
+    notify(true);
+}
@@ -0,0 +1,53 @@
+#![allow(non_snake_case)]
+
+use windings_macros::LayoutMirror;
+
+/// FFI Structs as they are declared by WINAPI
+mod ffi {
+    #[repr(C)]
+    pub struct POINT {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    #[repr(C)]
+    pub struct MSG {
+        pub hwnd: *mut u8,
+        pub message: u32,
+        pub wParam: usize,
+        pub lParam: isize,
+        pub time: u32,
+        pub pt: POINT,
+    }
+}
+
+#[derive(LayoutMirror)]
+#[mirror(ffi::POINT)]
+#[repr(C)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(LayoutMirror)]
+#[mirror(ffi::MSG)]
+#[repr(C)]
+struct Message {
+    #[mirror(hwnd)]
+    window: *mut u8,
+    message: u32,
+    #[mirror(wParam)]
+    w_param: usize,
+    #[mirror(lParam)]
+    l_param: isize,
+    time: u32,
+    #[mirror(pt)]
+    point: Point,
+}
+
+fn main() {
+    let message = Message { window: std::ptr::null_mut(), message: 18, w_param: 0, l_param: 0, time: 0, point: Point { x: 1, y: 2 } };
+    let mirrored: ffi::MSG = unsafe { std::mem::transmute(message) };
+    assert_eq!(mirrored.message, 18);
+    assert_eq!(mirrored.pt.y, 2);
+}
@@ -0,0 +1,17 @@
+//! # UI Tests
+//!
+//! Compile Tests for the `ffi_wrap` Attribute and the `LayoutMirror` Derive
+
+/// Tests that valid Declarations compile and call the FFI Functions correctly
+#[test]
+fn test_pass() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/pass/*.rs");
+}
+
+/// Tests that malformed Declarations are rejected with descriptive Errors
+#[test]
+fn test_compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}